@@ -223,7 +223,8 @@ fn build_updater() -> release_hub::Result<(release_hub::Updater, String)> {
 
     let updater = UpdaterBuilder::new(APP_NAME, CURRENT_VERSION, config)
         .source(Box::new(source))
-        .timeout(Duration::from_secs(15))
+        .connect_timeout(Duration::from_secs(15))
+        .read_timeout(Duration::from_secs(15))
         .build()?;
 
     Ok((updater, auth_mode))
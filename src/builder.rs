@@ -8,27 +8,65 @@
 // Copyright (c) 2015 - Present - The Tauri Programme within The Commons Conservancy.
 // Licensed under MIT OR MIT/Apache-2.0
 
+#[cfg(target_os = "windows")]
+use crate::windows::{running_process_ids, terminate_process};
 use crate::{
-    Config, EndpointSource, Error, InstallerKind, ReleaseSource, Result, SourceRequest, TargetInfo,
-    Update, extract_path_from_executable,
+    Arch, AssetFilter, Config, EndpointSource, Error, GitLabSource, InstallerKind, OS,
+    ReleaseAsset, ReleaseCache, ReleaseSource, RemoteRelease, Result, SourceRequest, StagedUpdate,
+    SystemInfo, TargetInfo, Update, UpdateChannel, UpdaterLock, extract_path_from_executable,
+};
+use fs_err as fs;
+use futures_util::{Stream, StreamExt, future::try_join_all, stream};
+use http::StatusCode;
+use http::header::{
+    ACCEPT, ACCEPT_RANGES, CONTENT_LENGTH, CONTENT_RANGE, ETAG, IF_RANGE, LAST_MODIFIED, RANGE,
 };
-use http::header::ACCEPT;
 use http::{
     HeaderName,
     header::{HeaderMap, HeaderValue},
 };
 use reqwest::ClientBuilder;
-use semver::Version;
+use semver::{Version, VersionReq};
+use serde::{Deserialize, Serialize, Serializer};
+use sha2::{Digest, Sha256};
+use std::io::Write;
 use std::{
+    convert::Infallible,
     env::current_exe,
     ffi::OsString,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
     path::{Path, PathBuf},
     sync::{Arc, Mutex},
-    time::Duration,
+    time::{Duration, Instant, SystemTime},
 };
+use tempfile::NamedTempFile;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+use tokio_util::sync::CancellationToken;
 use url::Url;
 
 const UPDATER_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"),);
+const MAX_REDIRECTS: usize = 10;
+const CONNECTIVITY_CHECK_URL: &str = "https://api.github.com";
+const CONNECTIVITY_TIMEOUT: Duration = Duration::from_secs(3);
+const CONNECTIVITY_CHECK_HOST: &str = "api.github.com";
+const CONNECTIVITY_CHECK_PORT: u16 = 443;
+/// Multiple of the artifact size that must be free before [`Updater::download_and_install`].
+const DEFAULT_DISK_SPACE_MULTIPLIER: f32 = 2.0;
+
+/// Artifact size above which [`Updater::download_and_install`] streams to disk.
+const DEFAULT_STREAM_TO_DISK_THRESHOLD: u64 = 50 * 1024 * 1024;
+const DEFAULT_RETRY_BASE_DELAY: Duration = Duration::from_secs(1);
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(60);
+/// Largest buffer reserved up front from an advertised `Content-Length`.
+const MAX_PREALLOCATION: u64 = 64 * 1024 * 1024;
+/// Interval between checks of [`Updater::start_background_check`] unless configured.
+const DEFAULT_BACKGROUND_POLL_INTERVAL: Duration = Duration::from_secs(60 * 60);
+/// Shortest interval [`Updater::start_background_check`] polls at.
+const MIN_BACKGROUND_POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// Placeholder marking a mirror URL as an artifact download mirror.
+const MIRROR_FILENAME_PLACEHOLDER: &str = "{filename}";
 
 /// Custom version comparator used to override the default semver `>` update check.
 ///
@@ -80,14 +118,166 @@ fn windows_quote_installer_arg(arg: &OsString) -> String {
     quoted
 }
 
+const NSIS_MAGIC_OFFSET: usize = 0x88;
+const NSIS_MAGIC: [u8; 4] = [0x1F, 0x9D, 0x8C, 0x00];
+const NSIS_SILENT_ARG: &str = "/S";
+
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+pub(crate) fn nsis_installer_args(is_nsis: bool, args: &[OsString]) -> Vec<OsString> {
+    if !is_nsis || args.iter().any(|arg| arg == NSIS_SILENT_ARG) {
+        return args.to_vec();
+    }
+
+    let mut nsis_args = Vec::with_capacity(args.len() + 1);
+    nsis_args.push(OsString::from(NSIS_SILENT_ARG));
+    nsis_args.extend_from_slice(args);
+    nsis_args
+}
+
+/// Returns `true` when a TCP connection to `host:port` opens within `timeout`.
+///
+/// This is lighter than an HTTP request and distinguishes networks that
+/// resolve DNS but block outbound connections.
+pub async fn check_tcp_connectivity(host: &str, port: u16, timeout: Duration) -> bool {
+    matches!(
+        tokio::time::timeout(timeout, tokio::net::TcpStream::connect((host, port))).await,
+        Ok(Ok(_))
+    )
+}
+
+/// Callback invoked by [`Updater::check`] once the version comparison finished.
+pub type CheckCompleteHook = Arc<dyn Fn(CheckResult) + Send + Sync + 'static>;
+
+/// Hook run on verified artifact bytes before they are installed; an error aborts the install.
+pub type BeforeInstallHook = Arc<dyn Fn(&[u8]) -> Result<()> + Send + Sync + 'static>;
+
+/// Hook run on the path of a verified artifact streamed to disk before it is installed.
+pub type BeforeInstallFileHook = Arc<dyn Fn(&Path) -> Result<()> + Send + Sync + 'static>;
+
+/// Hook run with the installation path once an install completed.
+pub type AfterInstallHook = Arc<dyn Fn(&Path) + Send + Sync + 'static>;
+
+/// Outcome passed to the [`UpdaterBuilder::on_check_complete`] hook.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckResult {
+    /// Whether the fetched release is treated as an update.
+    pub update_available: bool,
+    /// Version advertised by the fetched release.
+    pub version: Option<Version>,
+    /// Time spent fetching and comparing the release.
+    pub duration: Duration,
+    /// Message of the error [`Updater::check`] failed with, if any.
+    pub error: Option<String>,
+}
+
+/// Whether the running version must, may, or need not be updated.
+///
+/// Returned by [`Updater::check_update_required`] and [`Updater::update_required`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UpdateRequired {
+    /// The running version is current.
+    NotRequired,
+    /// A newer version is available but installing it is optional.
+    Optional(Version),
+    /// The running version is below the forced minimum; the latest version must be installed.
+    Forced(Version),
+}
+
+/// Progress events reported by [`Updater::install_with_progress`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstallProgress {
+    /// Installation is about to start.
+    Starting,
+    /// Payload extraction progress as a percentage.
+    Extracting(u8),
+    /// Installer progress as a percentage.
+    Installing(u8),
+    /// The installer is finalizing the installation.
+    Finishing,
+    /// Installation completed.
+    Done,
+}
+
+/// Planned filesystem effects of an install, reported by [`Updater::simulate_install`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InstallSimulation {
+    /// Files that do not exist yet and would be created.
+    pub files_to_create: Vec<PathBuf>,
+    /// Existing files that would be overwritten.
+    pub files_to_replace: Vec<PathBuf>,
+    /// Total number of bytes the install would write.
+    pub bytes_to_write: u64,
+    /// Whether the install needs administrator privileges.
+    pub requires_elevation: bool,
+}
+
+impl InstallSimulation {
+    /// Splits planned `(path, size)` writes into created and replaced files.
+    pub(crate) fn from_planned_files(files: Vec<(PathBuf, u64)>, requires_elevation: bool) -> Self {
+        let mut simulation = Self {
+            requires_elevation,
+            ..Self::default()
+        };
+        for (path, size) in files {
+            simulation.bytes_to_write += size;
+            if path.exists() {
+                simulation.files_to_replace.push(path);
+            } else {
+                simulation.files_to_create.push(path);
+            }
+        }
+        simulation
+    }
+}
+
+/// Updater health check produced by [`Updater::diagnose`], suitable for support requests.
+#[derive(Debug, Clone, Serialize)]
+pub struct DiagnosticsReport {
+    /// Application name used for installer and process lookups.
+    pub app_name: String,
+    /// Currently installed version.
+    pub current_version: Version,
+    /// Path the update would be installed to.
+    pub extract_path: PathBuf,
+    /// Whether [`Self::extract_path`] exists.
+    pub extract_path_exists: bool,
+    /// Whether the install location can be written without elevation.
+    pub extract_path_writable: bool,
+    /// Whether a TCP connection to the release source's host could be opened.
+    ///
+    /// The host is picked like [`Updater::check_internet_connectivity`]: the
+    /// GitHub API, the GitLab instance, or the first configured endpoint.
+    pub source_reachable: bool,
+    /// Latest version reported by the release source, if it could be fetched.
+    pub latest_version: Option<Version>,
+    /// Whether the latest release publishes an artifact for the current target.
+    pub asset_available: bool,
+    /// Platform the updater resolves artifacts for.
+    pub system_info: SystemInfo,
+    /// Failures encountered while collecting the report.
+    pub errors: Vec<String>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum InstallAction {
     MacosArchive,
     WindowsExecutableLaunch,
+    WindowsArchiveExtract,
     LinuxAppImageReplace,
+    LinuxArchiveExtract,
     LinuxPackageCommand,
 }
 
+impl InstallAction {
+    /// Returns whether the install writes to the install directory without elevating.
+    fn writes_install_dir(self) -> bool {
+        matches!(
+            self,
+            Self::WindowsArchiveExtract | Self::LinuxAppImageReplace | Self::LinuxArchiveExtract
+        )
+    }
+}
+
 /// Configures and creates an [`Updater`].
 ///
 /// This builder is the main integration point for application code. It merges
@@ -99,13 +289,63 @@ pub struct UpdaterBuilder {
     config: Config,
     target: Option<String>,
     source: Option<Box<dyn ReleaseSource>>,
+    gitlab: Option<(Url, String)>,
+    gitlab_token: Option<String>,
     headers: HeaderMap,
-    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
     proxy: Option<Url>,
     no_proxy: bool,
     executable_path: Option<PathBuf>,
     installer_args: Vec<OsString>,
     version_comparator: Option<VersionComparator>,
+    force_ipv4: bool,
+    force_ipv6: bool,
+    skip_on_offline: bool,
+    required_asset_targets: Vec<(OS, Arch)>,
+    auto_terminate_running: bool,
+    max_asset_size: Option<u64>,
+    mirror_urls: Vec<Url>,
+    post_download_script: Option<PathBuf>,
+    fallback_download_url: Option<Url>,
+    mirrors: Vec<String>,
+    min_forced_version: Option<Version>,
+    minimum_version: Option<Version>,
+    migration_url: Option<Url>,
+    as_admin: bool,
+    prefer_dmg: bool,
+    remove_quarantine: bool,
+    verify_codesign: bool,
+    codesign_team_id: Option<String>,
+    verify_authenticode: bool,
+    expected_signer: Option<String>,
+    silent_install: bool,
+    install_with_sudo: bool,
+    pinned_certificates: Vec<Vec<u8>>,
+    pinned_fingerprints: Vec<String>,
+    root_certificates: Vec<String>,
+    etag_cache: bool,
+    checksum_sha256: Option<String>,
+    verify_checksum: bool,
+    allow_prerelease: bool,
+    channel: UpdateChannel,
+    target_version: Option<Version>,
+    version_requirement: Option<VersionReq>,
+    stream_to_disk_threshold: u64,
+    disk_space_multiplier: f32,
+    max_retries: u32,
+    retry_base_delay: Duration,
+    background_poll_interval: Duration,
+    backup_retention: Option<Duration>,
+    skipped_versions: Vec<Version>,
+    skipped_versions_file: Option<PathBuf>,
+    asset_name_glob: Option<String>,
+    temp_dir: Option<PathBuf>,
+    on_check_complete: Option<CheckCompleteHook>,
+    asset_filter: Option<AssetFilter>,
+    before_install: Option<BeforeInstallHook>,
+    before_install_file: Option<BeforeInstallFileHook>,
+    after_install: Option<AfterInstallHook>,
 }
 
 impl UpdaterBuilder {
@@ -119,13 +359,63 @@ impl UpdaterBuilder {
             config,
             target: None,
             source: None,
+            gitlab: None,
+            gitlab_token: None,
             headers: HeaderMap::new(),
-            timeout: None,
+            connect_timeout: None,
+            read_timeout: None,
             proxy: None,
             no_proxy: false,
             executable_path: None,
             installer_args: Vec::new(),
             version_comparator: None,
+            force_ipv4: false,
+            force_ipv6: false,
+            skip_on_offline: false,
+            required_asset_targets: Vec::new(),
+            auto_terminate_running: false,
+            max_asset_size: None,
+            mirror_urls: Vec::new(),
+            post_download_script: None,
+            fallback_download_url: None,
+            mirrors: Vec::new(),
+            min_forced_version: None,
+            minimum_version: None,
+            migration_url: None,
+            as_admin: false,
+            prefer_dmg: false,
+            remove_quarantine: true,
+            verify_codesign: false,
+            codesign_team_id: None,
+            verify_authenticode: false,
+            expected_signer: None,
+            silent_install: true,
+            install_with_sudo: false,
+            pinned_certificates: Vec::new(),
+            pinned_fingerprints: Vec::new(),
+            root_certificates: Vec::new(),
+            etag_cache: false,
+            checksum_sha256: None,
+            verify_checksum: false,
+            allow_prerelease: false,
+            channel: UpdateChannel::Stable,
+            target_version: None,
+            version_requirement: None,
+            stream_to_disk_threshold: DEFAULT_STREAM_TO_DISK_THRESHOLD,
+            disk_space_multiplier: DEFAULT_DISK_SPACE_MULTIPLIER,
+            max_retries: 0,
+            retry_base_delay: DEFAULT_RETRY_BASE_DELAY,
+            background_poll_interval: DEFAULT_BACKGROUND_POLL_INTERVAL,
+            backup_retention: None,
+            skipped_versions: Vec::new(),
+            skipped_versions_file: None,
+            asset_name_glob: None,
+            temp_dir: None,
+            on_check_complete: None,
+            asset_filter: None,
+            before_install: None,
+            before_install_file: None,
+            after_install: None,
         }
     }
 
@@ -146,6 +436,32 @@ impl UpdaterBuilder {
         self
     }
 
+    /// Fetches the release manifest from `url`, replacing [`Config::endpoints`].
+    ///
+    /// The manifest schema is documented on [`EndpointSource`]. It is fetched
+    /// with the updater's headers, proxy, timeout and TLS settings. A source
+    /// set through [`Self::source`] or [`Self::gitlab`] takes precedence.
+    pub fn endpoint(mut self, url: Url) -> Self {
+        self.config.endpoints = vec![url];
+        self
+    }
+
+    /// Fetches releases from a GitLab project instead of [`Config::endpoints`].
+    ///
+    /// `base_url` is the instance root, such as `https://gitlab.com`, and
+    /// `project_id` is the numeric project ID or the `group/project` path.
+    /// A source set through [`Self::source`] takes precedence.
+    pub fn gitlab(mut self, base_url: Url, project_id: impl Into<String>) -> Self {
+        self.gitlab = Some((base_url, project_id.into()));
+        self
+    }
+
+    /// Sets the access token used for the GitLab project configured by [`Self::gitlab`].
+    pub fn gitlab_token(mut self, token: impl Into<String>) -> Self {
+        self.gitlab_token = Some(token.into());
+        self
+    }
+
     /// Overrides the default version comparison logic.
     ///
     /// By default, `release-hub` treats `remote.version > current_version` as
@@ -159,6 +475,73 @@ impl UpdaterBuilder {
         self
     }
 
+    /// Registers a callback invoked whenever [`Updater::check`] completes.
+    ///
+    /// The hook runs before `check` returns, which lets applications emit
+    /// analytics events without restructuring the calling code. Failed checks
+    /// are reported too, with [`CheckResult::error`] set.
+    pub fn on_check_complete<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(CheckResult) + Send + Sync + 'static,
+    {
+        self.on_check_complete = Some(Arc::new(hook));
+        self
+    }
+
+    /// Picks the update artifact with a custom predicate among the assets matching the target.
+    ///
+    /// Use it to prefer, for example, a statically linked or FIPS build. The
+    /// filter runs after the OS and architecture match of the GitHub and
+    /// GitLab sources, the first accepted asset wins, and
+    /// [`Error::AssetNotFound`] is returned when none is accepted. Without a
+    /// filter the built-in selection is used.
+    pub fn asset_filter<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(&ReleaseAsset) -> bool + Send + Sync + 'static,
+    {
+        self.asset_filter = Some(Arc::new(filter));
+        self
+    }
+
+    /// Registers a hook run on the verified artifact bytes right before they are installed.
+    ///
+    /// Use it to check business constraints before anything is written. When
+    /// the hook returns an error the install is aborted and the error is
+    /// returned. Artifacts streamed to disk are read into memory for the hook
+    /// unless [`Self::before_install_file`] is registered.
+    pub fn before_install<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&[u8]) -> Result<()> + Send + Sync + 'static,
+    {
+        self.before_install = Some(Arc::new(hook));
+        self
+    }
+
+    /// Registers a hook run on the path of a verified artifact streamed to disk.
+    ///
+    /// Installs from a file, such as [`Updater::install_from_file`] or large
+    /// downloads in [`Updater::download_and_install`], call it instead of
+    /// [`Self::before_install`], so the artifact is not read into memory. An
+    /// error aborts the install like the bytes hook.
+    pub fn before_install_file<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&Path) -> Result<()> + Send + Sync + 'static,
+    {
+        self.before_install_file = Some(Arc::new(hook));
+        self
+    }
+
+    /// Registers a hook run with [`Updater::extract_path`] after an install completed.
+    ///
+    /// Windows installers that exit the process on success never reach it.
+    pub fn after_install<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&Path) + Send + Sync + 'static,
+    {
+        self.after_install = Some(Arc::new(hook));
+        self
+    }
+
     /// Overrides the executable path used to derive the install target.
     pub fn executable_path<P: AsRef<Path>>(mut self, p: P) -> Self {
         self.executable_path.replace(p.as_ref().into());
@@ -192,9 +575,24 @@ impl UpdaterBuilder {
         self
     }
 
-    /// Sets a timeout for release-fetch and download HTTP requests.
-    pub fn timeout(mut self, timeout: Duration) -> Self {
-        self.timeout = Some(timeout);
+    /// Sets both [`Self::connect_timeout`] and [`Self::read_timeout`] to `timeout`.
+    #[deprecated(note = "use `connect_timeout` and `read_timeout`")]
+    pub fn timeout(self, timeout: Duration) -> Self {
+        self.connect_timeout(timeout).read_timeout(timeout)
+    }
+
+    /// Limits how long establishing a connection for release-fetch and download requests may take.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Limits how long release-fetch and download requests may wait for each read.
+    ///
+    /// The limit restarts whenever data arrives, so large downloads are not
+    /// cut off while they keep making progress.
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = Some(timeout);
         self
     }
 
@@ -210,284 +608,3553 @@ impl UpdaterBuilder {
         self
     }
 
-    /// Appends a single Windows installer argument.
-    pub fn installer_arg<S>(mut self, arg: S) -> Self
-    where
-        S: Into<OsString>,
-    {
-        self.installer_args.push(arg.into());
+    /// Forces artifact downloads over IPv4 by binding to the unspecified IPv4 address.
+    ///
+    /// This is a debugging workaround for dual-stack networks with poor IPv6
+    /// routes. It does not affect the GitHub API calls made through `octocrab`.
+    pub fn force_ipv4(mut self, force: bool) -> Self {
+        self.force_ipv4 = force;
         self
     }
 
-    /// Appends multiple Windows installer arguments.
-    pub fn installer_args<I, S>(mut self, args: I) -> Self
-    where
-        I: IntoIterator<Item = S>,
-        S: Into<OsString>,
-    {
-        self.installer_args.extend(args.into_iter().map(Into::into));
+    /// Forces artifact downloads over IPv6 by binding to the unspecified IPv6 address.
+    ///
+    /// Like [`Self::force_ipv4`], this only affects artifact downloads.
+    pub fn force_ipv6(mut self, force: bool) -> Self {
+        self.force_ipv6 = force;
         self
     }
 
-    /// Clears builder-provided Windows installer arguments.
-    pub fn clear_installer_args(mut self) -> Self {
-        self.installer_args.clear();
+    /// Makes [`Updater::update`] return `Ok(false)` immediately when offline.
+    ///
+    /// When enabled, [`Updater::network_connectivity_check`] probes the
+    /// configured release source's host before the release check instead of
+    /// waiting for the request timeout.
+    pub fn skip_on_offline(mut self, skip: bool) -> Self {
+        self.skip_on_offline = skip;
         self
     }
 
-    /// Builds an [`Updater`] from the accumulated configuration.
+    /// Sets the `(OS, Arch)` pairs every release must provide an artifact for.
     ///
-    /// This validates the static config, resolves the effective target and
-    /// install path, and materializes either the custom release source or the
-    /// default endpoint-backed source.
-    pub fn build(self) -> Result<Updater> {
-        self.config.validate()?;
+    /// Used by [`Updater::validate_release_assets`], typically from CI scripts
+    /// that check a release before publishing it.
+    pub fn required_asset_targets(mut self, pairs: Vec<(OS, Arch)>) -> Self {
+        self.required_asset_targets = pairs;
+        self
+    }
 
-        if self.source.is_none() && self.config.endpoints.is_empty() {
-            return Err(Error::Network("no endpoints configured".into()));
-        }
+    /// Terminates running instances of the application before a Windows install.
+    ///
+    /// Defaults to `false`. When enabled, every process other than the current
+    /// one whose executable image lives inside the install directory
+    /// ([`Updater::extract_path`]) is terminated before the installer launches.
+    /// Image paths are compared case-insensitively, so programs installed
+    /// elsewhere are never matched, whatever their name.
+    pub fn auto_terminate_running(mut self, terminate: bool) -> Self {
+        self.auto_terminate_running = terminate;
+        self
+    }
 
-        let target = match self.target {
-            Some(target) => target,
-            None => TargetInfo::from_system(crate::SystemInfo::current()?).target,
-        };
-        let source = match self.source {
-            Some(source) => Arc::<dyn ReleaseSource>::from(source),
-            None => Arc::new(EndpointSource::new(self.config.endpoints.clone())),
-        };
+    /// Rejects update artifacts larger than `max_bytes`.
+    ///
+    /// Sources that report asset sizes, such as [`crate::GitHubSource`], reject
+    /// oversized artifacts during [`Updater::check`], and downloads fail with
+    /// [`Error::AssetTooLarge`] before reading the body when the server's
+    /// `Content-Length` exceeds the limit.
+    pub fn max_asset_size(mut self, max_bytes: u64) -> Self {
+        self.max_asset_size = Some(max_bytes);
+        self
+    }
 
-        let executable_path = self.executable_path.unwrap_or(current_exe()?);
-        let extract_path = if cfg!(target_os = "linux") {
-            executable_path
-        } else {
-            extract_path_from_executable(&executable_path)?
-        };
-        let mut installer_args = self
-            .config
-            .windows
-            .as_ref()
-            .map(|windows| windows.installer_args.clone())
-            .unwrap_or_default();
-        installer_args.extend(self.installer_args);
+    /// Sets the mirrors of [`Updater::mirror_urls`]; templates added with [`Self::mirror`] follow them.
+    ///
+    /// URLs containing a `{filename}` placeholder are download mirrors, the
+    /// others are manifest mirrors for [`Updater::check_with_mirror_fallback`].
+    pub fn mirror_urls(mut self, urls: Vec<Url>) -> Self {
+        self.mirror_urls = urls;
+        self
+    }
 
-        Ok(Updater {
-            app_name: self.app_name,
-            current_version: self.current_version,
-            config: self.config,
-            target,
-            source,
-            headers: self.headers,
-            timeout: self.timeout,
-            proxy: self.proxy,
-            no_proxy: self.no_proxy,
-            extract_path,
-            installer_args,
-            version_comparator: self.version_comparator,
-            latest_release_version: Mutex::new(None),
-        })
+    /// Runs `path` on each downloaded artifact before [`Updater::download_and_install`] installs it.
+    ///
+    /// The script receives the artifact path as its first argument; use it
+    /// for virus scans, checksum checks, or notarisation. A non-zero exit
+    /// aborts the install.
+    pub fn post_download_script(mut self, path: PathBuf) -> Self {
+        self.post_download_script = Some(path);
+        self
     }
-}
 
-/// Updater instance capable of checking, downloading and installing updates.
-///
-/// Instances are cheap to reuse and keep the last successfully observed remote
-/// version for introspection through [`Self::latest_version`].
-pub struct Updater {
-    /// Application name used by platform backends and staging paths.
-    pub app_name: String,
-    /// Current application version.
-    pub current_version: Version,
-    /// Static updater configuration.
-    pub config: Config,
-    /// Selected target string.
-    pub target: String,
-    source: Arc<dyn ReleaseSource>,
-    /// HTTP headers propagated to update downloads.
-    pub headers: HeaderMap,
-    /// Optional download timeout.
-    pub timeout: Option<Duration>,
-    /// Optional proxy configuration.
-    pub proxy: Option<Url>,
-    /// Whether proxy configuration should be ignored.
-    pub no_proxy: bool,
-    /// Derived installation target path.
-    pub extract_path: PathBuf,
-    /// Windows installer arguments propagated from config and builder overrides.
-    pub installer_args: Vec<OsString>,
-    /// Optional custom version comparator.
-    pub version_comparator: Option<VersionComparator>,
-    latest_release_version: Mutex<Option<Version>>,
-}
+    /// Sets a direct download URL, such as your own CDN, used when GitHub is unreachable.
+    ///
+    /// When [`Updater::download_with_progress`] fails with a transport error (not an HTTP
+    /// 4xx), it retries once from this URL with the same client settings.
+    /// Credential headers such as `Authorization` are dropped when the URL is
+    /// on another host. The fallback bytes are still verified against the
+    /// update's signature.
+    pub fn fallback_download_url(mut self, url: Url) -> Self {
+        self.fallback_download_url = Some(url);
+        self
+    }
 
-impl Updater {
-    /// Returns the latest remote version observed by the last successful [`Self::check`] call.
-    pub fn latest_version(&self) -> Option<Version> {
-        self.latest_release_version.lock().ok()?.clone()
+    /// Adds a download mirror tried when the primary artifact download fails.
+    ///
+    /// `url_template` may contain `{version}`, replaced with the release
+    /// version, and `{filename}`, replaced with the artifact filename, as in
+    /// `https://mirror.example.com/app/{version}/{filename}`. Mirrors are tried
+    /// in the order they were added after the primary URL fails with an HTTP
+    /// error or a network error, stopping at the first success. Bytes from a
+    /// mirror are verified against the update's signature and checksum like
+    /// any other download, and credential headers are never sent to a mirror.
+    ///
+    /// The template is appended to [`Updater::mirror_urls`].
+    /// [`Self::build`] returns [`Error::InvalidMirrorTemplate`] when it is not
+    /// a valid URL or lacks `{filename}`, and
+    /// [`Error::InsecureTransportProtocol`] when it does not use `https`
+    /// unless [`Config::dangerous_insecure_transport_protocol`] is set.
+    pub fn mirror(mut self, url_template: &str) -> Self {
+        self.mirrors.push(url_template.to_owned());
+        self
     }
 
-    /// Fetches release metadata and returns an [`Update`] when a newer version is available.
+    /// Sets the minimum version below which [`Updater::update_required`] reports a forced update.
     ///
-    /// The returned [`Update`] is already narrowed to the current target and
-    /// contains the resolved installer URL, signature, and install strategy.
-    pub async fn check(&self) -> Result<Option<Update>> {
-        let request = SourceRequest::new(self.target.clone());
-        let release = self.source.fetch(&request).await?;
-        let mut headers = release.download_headers.clone();
-        headers.extend(self.headers.clone());
-        if let Ok(mut latest_release_version) = self.latest_release_version.lock() {
-            *latest_release_version = Some(release.version.clone());
-        }
+    /// Returns [`Error::Semver`] when `version` is not a valid semantic version.
+    pub fn min_forced_version(mut self, version: &str) -> Result<Self> {
+        self.min_forced_version = Some(Version::parse(version)?);
+        Ok(self)
+    }
 
-        let has_update = if let Some(comparator) = &self.version_comparator {
-            comparator(self.current_version.clone(), release.clone())
-        } else {
-            release.version > self.current_version
-        };
-        if !has_update {
-            return Ok(None);
-        }
+    /// Refuses to update installs older than `version`.
+    ///
+    /// [`Updater::check`] fails with [`Error::CurrentVersionTooOld`] when the
+    /// running version is below `version`, so releases that drop support for
+    /// old configurations can require an upgrade through an intermediate
+    /// version first. Returns [`Error::Semver`] when `version` is not a valid
+    /// semantic version.
+    pub fn minimum_version(mut self, version: &str) -> Result<Self> {
+        self.minimum_version = Some(Version::parse(version)?);
+        Ok(self)
+    }
 
-        Ok(Some(Update {
-            current_version: self.current_version.clone(),
-            version: release.version.clone(),
-            date: release.pub_date,
-            body: release.notes.clone(),
-            raw_json: serde_json::to_value(&release)?,
-            download_url: release.download_url(&self.target)?.clone(),
-            signature: release.signature(&self.target)?.clone(),
-            pubkey: self.config.pubkey.clone(),
-            target: self.target.clone(),
-            installer_kind: InstallerKind::from_path(Path::new(
-                release.download_url(&self.target)?.path(),
-            ))?,
-            headers,
-            timeout: self.timeout,
-            proxy: self.proxy.clone(),
-            no_proxy: self.no_proxy,
-            dangerous_accept_invalid_certs: self.config.dangerous_accept_invalid_certs,
-            dangerous_accept_invalid_hostnames: self.config.dangerous_accept_invalid_hostnames,
-            extract_path: self.extract_path.clone(),
-            app_name: self.app_name.clone(),
-            installer_args: self.installer_args.clone(),
-        }))
+    /// Links migration docs from the [`Error::CurrentVersionTooOld`] message.
+    pub fn migration_url(mut self, url: Url) -> Self {
+        self.migration_url = Some(url);
+        self
     }
 
-    /// Convenience helper that checks for an update and downloads/installs it when present.
+    /// Makes [`Updater::relaunch`] restart the application with administrator privileges.
     ///
-    /// Returns `Ok(true)` when an update was found and installed, or `Ok(false)`
-    /// when the current version is already up to date.
-    pub async fn update<C: FnMut(usize)>(&self, on_chunk: C) -> Result<bool> {
-        if let Some(update) = self.check().await? {
-            update.download_and_install(on_chunk).await?;
-            Ok(true)
-        } else {
-            Ok(false)
-        }
+    /// Defaults to `false`. See [`Updater::relaunch_as_admin`] for how each
+    /// platform elevates.
+    pub fn relaunch_as_admin(mut self, as_admin: bool) -> Self {
+        self.as_admin = as_admin;
+        self
     }
 
-    /// Downloads the updater package for an [`Update`] and returns it as bytes.
-    pub async fn download<C: FnMut(usize)>(&self, update: &Update, on_chunk: C) -> Result<Vec<u8>> {
-        update.download(on_chunk).await
+    /// Sets the Minisign public key used to verify downloaded artifacts.
+    ///
+    /// `key` is the contents of a `minisign.pub` file: an `untrusted comment`
+    /// line followed by the base64-encoded key. It replaces [`Config::pubkey`]
+    /// and is validated immediately, returning [`Error::Minisign`] when it
+    /// cannot be decoded. With a key configured, [`Updater::install`] only
+    /// accepts bytes whose signature was verified by [`Updater::download_with_progress`].
+    pub fn public_key(mut self, key: &str) -> Result<Self> {
+        minisign_verify::PublicKey::decode(key)?;
+        self.config.pubkey = key.to_owned();
+        Ok(self)
     }
 
-    /// Installs artifact bytes previously returned by [`Updater::download`].
-    pub fn install(&self, bytes: impl AsRef<[u8]>) -> Result<()> {
-        self.install_inner(bytes.as_ref())
+    /// Sets the expected SHA-256 digest, as hex, of the downloaded artifact.
+    ///
+    /// [`Updater::download_with_progress`] and the other `Updater` download helpers hash
+    /// the bytes and return [`Error::ChecksumMismatch`] before anything is
+    /// installed. Hex digits are compared case-insensitively.
+    pub fn checksum_sha256(mut self, hex: &str) -> Self {
+        self.checksum_sha256 = Some(hex.trim().to_ascii_lowercase());
+        self
     }
 
-    /// Relaunches the application using the current platform backend.
+    /// Verifies downloads against a checksum file published alongside the artifact.
     ///
-    /// Relaunch support is currently implemented on macOS and Windows.
-    pub fn relaunch(&self) -> Result<()> {
-        self.relaunch_inner()
+    /// When enabled, every [`Updater`] download method, such as
+    /// [`Updater::download_with_progress`], looks for a release asset named
+    /// `<artifact>.sha256sum` or `<artifact>.sha256`, reads the hex digest
+    /// from its first whitespace-separated token (GNU coreutils format), and
+    /// returns [`Error::ChecksumMismatch`] when the download does not match.
+    /// A missing sidecar fails with [`Error::ChecksumSidecarNotFound`].
+    pub fn verify_checksum(mut self, verify_checksum: bool) -> Self {
+        self.verify_checksum = verify_checksum;
+        self
     }
 
-    /// Convenience helper that downloads and installs a specific [`Update`].
-    pub async fn download_and_install<C: FnMut(usize)>(
-        &self,
-        update: &Update,
-        on_chunk: C,
-    ) -> Result<()> {
-        update.download_and_install(on_chunk).await
+    /// Prefers `.dmg` disk images over `.app.zip` archives when a release ships both.
+    ///
+    /// Defaults to `false`, so `.app.zip` is chosen when available and `.dmg`
+    /// is the fallback. Disk images are installed by mounting them with
+    /// `hdiutil` and copying the `.app` bundle out with `ditto`.
+    pub fn prefer_dmg(mut self, prefer_dmg: bool) -> Self {
+        self.prefer_dmg = prefer_dmg;
+        self
     }
-}
+
+    /// Removes the `com.apple.quarantine` attribute from the installed app on macOS.
+    ///
+    /// Defaults to `true`. A bundle extracted from a quarantined download
+    /// would otherwise trigger a Gatekeeper prompt on its first launch. The
+    /// attribute is removed recursively with `xattr`, and a missing attribute
+    /// is not an error.
+    pub fn remove_quarantine(mut self, remove_quarantine: bool) -> Self {
+        self.remove_quarantine = remove_quarantine;
+        self
+    }
+
+    /// Verifies the code signature of the installed app bundle on macOS.
+    ///
+    /// Defaults to `false`. When enabled, the new bundle is checked with
+    /// `codesign --verify --deep --strict` in the temporary directory before
+    /// it replaces the installed one. A failed check leaves the installation
+    /// and the bundle's quarantine attribute untouched and returns
+    /// [`Error::SignatureVerificationFailed`].
+    pub fn verify_codesign(mut self, verify_codesign: bool) -> Self {
+        self.verify_codesign = verify_codesign;
+        self
+    }
+
+    /// Requires the installed app to be signed by the Apple team `team_id`.
+    ///
+    /// Implies [`Self::verify_codesign`]. The signature must chain to a
+    /// trusted anchor and its leaf certificate must carry `team_id` as the
+    /// organizational unit.
+    pub fn codesign_team_id(mut self, team_id: &str) -> Self {
+        self.verify_codesign = true;
+        self.codesign_team_id = Some(team_id.to_owned());
+        self
+    }
+
+    /// Verifies the Authenticode signature of Windows installers before running them.
+    ///
+    /// Defaults to `false`. When enabled, `.exe` and `.msi` installers are
+    /// checked with `WinVerifyTrust`; an unsigned or invalidly signed
+    /// installer is deleted and [`Error::SignatureVerificationFailed`] is
+    /// returned. Portable ZIP archives are not checked.
+    pub fn verify_authenticode(mut self, verify_authenticode: bool) -> Self {
+        self.verify_authenticode = verify_authenticode;
+        self
+    }
+
+    /// Requires Windows installers to be signed by `subject`.
+    ///
+    /// Implies [`Self::verify_authenticode`]. `subject` is compared with the
+    /// simple display name of the signing certificate, usually its common
+    /// name, such as `Example Corp`.
+    pub fn expected_signer(mut self, subject: &str) -> Self {
+        self.verify_authenticode = true;
+        self.expected_signer = Some(subject.to_owned());
+        self
+    }
+
+    /// Runs Windows installers without their UI.
+    ///
+    /// Defaults to `true`: NSIS installers receive `/S` and `.msi` packages
+    /// are installed with `msiexec.exe /i <package> /quiet /norestart`. Other
+    /// `.exe` installers get no extra flags, so they may still show a wizard.
+    /// Set to `false` to let the user step through the installer.
+    pub fn silent_install(mut self, silent_install: bool) -> Self {
+        self.silent_install = silent_install;
+        self
+    }
+
+    /// Installs `.deb`, `.rpm` and `.snap` packages through `sudo -A` instead of `pkexec`.
+    ///
+    /// Defaults to `false`. `sudo -A` prompts through the program named by
+    /// `SUDO_ASKPASS`, which suits desktops without a polkit agent.
+    pub fn install_with_sudo(mut self, install_with_sudo: bool) -> Self {
+        self.install_with_sudo = install_with_sudo;
+        self
+    }
+
+    /// Caches the latest release between checks and revalidates it with conditional requests.
+    ///
+    /// Defaults to `false`. When enabled, [`crate::GitHubSource`] sends the
+    /// `ETag` and `Last-Modified` of the previous response as `If-None-Match`
+    /// and `If-Modified-Since`, and a `304 Not Modified` answer reuses the
+    /// cached release without downloading the body or signature again. GitHub
+    /// does not count such answers against the API rate limit. The cache is
+    /// kept in memory and only covers lookups of the latest stable release.
+    pub fn enable_etag_cache(mut self, enable: bool) -> Self {
+        self.etag_cache = enable;
+        self
+    }
+
+    /// Trusts the root certificates in `pem` in addition to the system roots.
+    ///
+    /// Use this behind an intercepting proxy whose internal CA is not in the
+    /// system store. `pem` may hold a bundle of several certificates, and each
+    /// call appends to the certificates added before. They apply to every
+    /// request of the updater: endpoint manifests, GitHub and GitLab API
+    /// calls, signatures, downloads and connectivity checks. Only an Octocrab
+    /// client passed to [`crate::GitHubSource::with_client`] keeps its own
+    /// TLS settings.
+    ///
+    /// Fails when `pem` contains no certificate.
+    pub fn add_root_certificate_pem(mut self, pem: &str) -> Result<Self> {
+        crate::tls::parse_pem_bundle(pem)?;
+        self.root_certificates.push(pem.to_owned());
+        Ok(self)
+    }
+
+    /// Reads a PEM file and trusts its certificates like [`Self::add_root_certificate_pem`].
+    pub fn add_root_certificate_file(self, path: &Path) -> Result<Self> {
+        let pem = fs::read_to_string(path)?;
+        self.add_root_certificate_pem(&pem)
+    }
+
+    /// Trusts only `der_bytes`, a DER-encoded certificate, for the updater's connections.
+    ///
+    /// The certificate replaces the built-in and system roots, so a server
+    /// must present a chain ending in a pinned certificate; a CA the system
+    /// trusts can no longer vouch for an impostor. Hostnames are still
+    /// checked and the `dangerous_accept_invalid_certs` option is overridden.
+    /// Call this again to pin backup certificates. Pin a CA you control
+    /// rather than a short-lived leaf, or updates fail once it rotates.
+    ///
+    /// Pins apply to every request of the updater, like
+    /// [`Self::add_root_certificate_pem`]: endpoint manifests, GitHub and
+    /// GitLab API calls, signatures, downloads and connectivity checks. Pin
+    /// the certificates of every host involved, such as both `api.github.com`
+    /// and the asset CDN.
+    pub fn pin_certificate(mut self, der_bytes: Vec<u8>) -> Self {
+        self.pinned_certificates.push(der_bytes);
+        self
+    }
+
+    /// Only connects to servers whose leaf certificate has the SHA-256 fingerprint `sha256_hex`.
+    ///
+    /// Colons and whitespace are ignored, so `AB:CD:...` and `abcd...` are
+    /// equivalent. The fingerprint is the only check: chains, expiry and
+    /// hostnames are not validated, which suits self-signed servers but ties
+    /// updates to that exact certificate, so every renewal needs a new pin
+    /// shipped before it is deployed. Call this again to accept several
+    /// certificates. Takes precedence over [`Self::pin_certificate`] and
+    /// has the same scope.
+    pub fn pin_server_certificate_fingerprint(mut self, sha256_hex: &str) -> Self {
+        self.pinned_fingerprints.push(
+            sha256_hex
+                .chars()
+                .filter(|c| *c != ':' && !c.is_whitespace())
+                .collect::<String>()
+                .to_ascii_lowercase(),
+        );
+        self
+    }
+
+    /// Considers pre-releases when looking for the latest release.
+    ///
+    /// GitHub's "latest release" endpoint never returns pre-releases, so
+    /// [`crate::GitHubSource`] instead lists recent releases and picks the most
+    /// recently published one. Whether it counts as an update is still decided
+    /// by semver precedence, where `1.0.0-beta.2 < 1.0.0`.
+    pub fn allow_prerelease(mut self, allow_prerelease: bool) -> Self {
+        self.allow_prerelease = allow_prerelease;
+        self
+    }
+
+    /// Considers stable releases plus the pre-releases of the channel named `channel`.
+    ///
+    /// Shorthand for [`Self::channel`] with [`UpdateChannel::from`]. For
+    /// example, `"beta"` accepts `v2.0.0-beta.1` but ignores
+    /// `v2.0.0-nightly.20260421`.
+    pub fn allow_prerelease_channel(self, channel: &str) -> Self {
+        self.channel(UpdateChannel::from(channel))
+    }
+
+    /// Follows `channel` as well as the stable releases.
+    ///
+    /// GitHub sources then list recent releases and pick the highest version
+    /// among stable releases and the releases of the channel, such as
+    /// `nightly-v1.2.3-20250101` or `v1.3.0-nightly.1` for
+    /// [`UpdateChannel::Nightly`]. [`Updater::check`] returns `Ok(None)` when
+    /// none exists. Other sources ignore the channel.
+    pub fn channel(mut self, channel: UpdateChannel) -> Self {
+        self.channel = channel;
+        self
+    }
+
+    /// Pins updates to the release of exactly `version` instead of the latest one.
+    ///
+    /// [`Updater::check`] then reports an update whenever the pinned version
+    /// differs from the current one, including deliberate downgrades, and
+    /// fails with [`Error::VersionNotFound`] when no such release exists.
+    pub fn target_version(mut self, version: &str) -> Result<Self> {
+        self.target_version = Some(Version::parse(version)?);
+        Ok(self)
+    }
+
+    /// Only reports updates whose version satisfies `requirement`.
+    ///
+    /// Use this to keep a fleet on a compatible series, such as
+    /// `>=1.2.0, <2.0.0`. With [`Self::allow_prerelease`], pre-releases are
+    /// matched by their release version, so `1.3.0-beta.1` satisfies `^1.0.0`.
+    pub fn version_requirement(mut self, requirement: VersionReq) -> Self {
+        self.version_requirement = Some(requirement);
+        self
+    }
+
+    /// Sets the artifact size above which [`Updater::download_and_install`]
+    /// streams the download to a temporary file instead of memory.
+    ///
+    /// Defaults to 50 MiB. The size comes from a `HEAD` request; artifacts of
+    /// unknown size are downloaded into memory.
+    pub fn stream_to_disk_threshold(mut self, bytes: u64) -> Self {
+        self.stream_to_disk_threshold = bytes;
+        self
+    }
+
+    /// Sets how many times the artifact size must be free on the install volume.
+    ///
+    /// Defaults to `2.0`, room for the download and for its extraction.
+    /// [`Updater::download_and_install`] fails with
+    /// [`Error::InsufficientDiskSpace`] before downloading when less is free.
+    /// [`Self::build`] rejects negative and non-finite multipliers with
+    /// [`Error::InvalidConfig`].
+    pub fn disk_space_multiplier(mut self, multiplier: f32) -> Self {
+        self.disk_space_multiplier = multiplier;
+        self
+    }
+
+    /// Retries downloads up to `max_retries` times after transient failures.
+    ///
+    /// Connection errors, timeouts, `429` and `500`–`503` responses are
+    /// retried after [`Self::retry_base_delay`] doubled per attempt, capped at
+    /// 60 seconds. Other client errors such as `404` fail immediately. Once
+    /// retries are exhausted the download fails with
+    /// [`Error::MaxRetriesExceeded`]. Defaults to `0`, which disables retries.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the delay before the first download retry. Defaults to one second.
+    pub fn retry_base_delay(mut self, delay: Duration) -> Self {
+        self.retry_base_delay = delay;
+        self
+    }
+
+    /// Sets the interval of [`Updater::start_background_check`]. Defaults to one hour.
+    ///
+    /// [`Self::build`] fails with [`Error::InvalidConfig`] for a zero interval.
+    pub fn background_poll_interval(mut self, interval: Duration) -> Self {
+        self.background_poll_interval = interval;
+        self
+    }
+
+    /// Removes the installation preserved for [`Updater::rollback`] once it is older than `retention`.
+    ///
+    /// Expired backups are cleaned up when the updater is built, and
+    /// [`Self::build`] fails when one cannot be removed. By default the
+    /// backup is kept until the next update replaces it.
+    pub fn backup_retention(mut self, retention: Duration) -> Self {
+        self.backup_retention = Some(retention);
+        self
+    }
+
+    /// Ignores releases of `version` in [`Updater::check`].
+    ///
+    /// Returns [`Error::Semver`] when `version` is not a valid semantic version.
+    pub fn skip_version(mut self, version: &str) -> Result<Self> {
+        self.skipped_versions.push(Version::parse(version)?);
+        Ok(self)
+    }
+
+    /// Persists skipped versions in `path`, one version per line.
+    ///
+    /// Versions listed in the file are ignored by [`Updater::check`] in
+    /// addition to those passed to [`Self::skip_version`]. The file is read on
+    /// every check and written by [`Updater::skip_current_release`], so the
+    /// list survives restarts. A missing file means no version is skipped.
+    pub fn skipped_versions_file(mut self, path: PathBuf) -> Self {
+        self.skipped_versions_file = Some(path);
+        self
+    }
+
+    /// Restricts GitHub and GitLab asset selection to names matching the glob `pattern`.
+    ///
+    /// Assets that do not match are skipped before the OS and architecture
+    /// are detected, which helps when names carry build numbers or dates,
+    /// such as `myapp-*-x86_64-windows.exe`. See
+    /// [`SourceRequest::asset_name_glob`] for the supported syntax;
+    /// [`Self::build`] fails with [`Error::InvalidConfig`] on a malformed pattern.
+    pub fn asset_name_glob(mut self, pattern: &str) -> Self {
+        self.asset_name_glob = Some(pattern.to_owned());
+        self
+    }
+
+    /// Creates temporary files and directories in `path` instead of the system temp directory.
+    ///
+    /// Downloads streamed to disk, installer staging directories and extracted
+    /// archives are placed here, which helps when the system temp directory is
+    /// small or mounted `noexec`. The directory is created when missing. On
+    /// macOS the extracted bundle is renamed into place, or copied with
+    /// `ditto` when `path` is on another volume than the installation.
+    pub fn temp_dir(mut self, path: PathBuf) -> Self {
+        self.temp_dir = Some(path);
+        self
+    }
+
+    /// Appends a single Windows installer argument.
+    pub fn installer_arg<S>(mut self, arg: S) -> Self
+    where
+        S: Into<OsString>,
+    {
+        self.installer_args.push(arg.into());
+        self
+    }
+
+    /// Appends multiple Windows installer arguments.
+    pub fn installer_args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<OsString>,
+    {
+        self.installer_args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    /// Clears builder-provided Windows installer arguments.
+    pub fn clear_installer_args(mut self) -> Self {
+        self.installer_args.clear();
+        self
+    }
+
+    /// Builds an [`Updater`] from the accumulated configuration.
+    ///
+    /// This validates the static config, resolves the effective target and
+    /// install path, and materializes either the custom release source or the
+    /// default endpoint-backed source.
+    pub fn build(self) -> Result<Updater> {
+        self.config.validate()?;
+
+        let mut mirror_urls = self.mirror_urls;
+        for template in self.mirrors {
+            match Url::parse(&template) {
+                Ok(url) if is_download_mirror(&url) => mirror_urls.push(url),
+                _ => return Err(Error::InvalidMirrorTemplate(template)),
+            }
+        }
+        crate::config::validate_endpoints(
+            &mirror_urls,
+            self.config.dangerous_insecure_transport_protocol,
+        )?;
+
+        let local_address = match (self.force_ipv4, self.force_ipv6) {
+            (true, true) => return Err(Error::ConflictingNetworkConfig),
+            (true, false) => Some(IpAddr::V4(Ipv4Addr::UNSPECIFIED)),
+            (false, true) => Some(IpAddr::V6(Ipv6Addr::UNSPECIFIED)),
+            (false, false) => None,
+        };
+
+        if let Some(pattern) = &self.asset_name_glob {
+            crate::source::assets::asset_name_pattern(pattern)?;
+        }
+
+        if self.background_poll_interval.is_zero() {
+            return Err(Error::InvalidConfig(
+                "`background_poll_interval` must be greater than zero".into(),
+            ));
+        }
+
+        if !self.disk_space_multiplier.is_finite() || self.disk_space_multiplier < 0.0 {
+            return Err(Error::InvalidConfig(format!(
+                "`disk_space_multiplier` must be a finite, non-negative number, got {}",
+                self.disk_space_multiplier
+            )));
+        }
+
+        if self.source.is_none() && self.gitlab.is_none() && self.config.endpoints.is_empty() {
+            return Err(Error::Network("no endpoints configured".into()));
+        }
+
+        let target = match self.target {
+            Some(target) => target,
+            None => TargetInfo::from_system(crate::SystemInfo::current()?).target,
+        };
+        let source_config = match (&self.source, self.gitlab) {
+            (Some(_), _) => SourceConfig::Custom,
+            (None, Some((base_url, project_id))) => SourceConfig::GitLab {
+                base_url,
+                project_id,
+                token: self.gitlab_token,
+            },
+            (None, None) => SourceConfig::Endpoints,
+        };
+        let gitlab = match source_config.clone() {
+            SourceConfig::GitLab {
+                base_url,
+                project_id,
+                token,
+            } => Some(gitlab_source(base_url, project_id, token)?),
+            _ => None,
+        };
+        let custom_source = self.source;
+
+        let executable_path = self.executable_path.unwrap_or(current_exe()?);
+        let extract_path = if cfg!(target_os = "linux") {
+            executable_path
+        } else {
+            extract_path_from_executable(&executable_path)?
+        };
+        if let Some(retention) = self.backup_retention {
+            crate::rollback::prune_backup(&extract_path, retention)?;
+        }
+        if let Some(temp_dir) = &self.temp_dir {
+            fs::create_dir_all(temp_dir)?;
+        }
+        let mut installer_args = self
+            .config
+            .windows
+            .as_ref()
+            .map(|windows| windows.installer_args.clone())
+            .unwrap_or_default();
+        installer_args.extend(self.installer_args);
+
+        let mut updater = Updater {
+            app_name: self.app_name,
+            current_version: self.current_version,
+            config: self.config,
+            target,
+            source: Arc::new(EndpointSource::new(Vec::new())),
+            source_config,
+            headers: self.headers,
+            connect_timeout: self.connect_timeout,
+            read_timeout: self.read_timeout,
+            proxy: self.proxy,
+            no_proxy: self.no_proxy,
+            local_address,
+            skip_on_offline: self.skip_on_offline,
+            required_asset_targets: self.required_asset_targets,
+            auto_terminate_running: self.auto_terminate_running,
+            max_asset_size: self.max_asset_size,
+            mirror_urls,
+            post_download_script: self.post_download_script,
+            fallback_download_url: self.fallback_download_url,
+            min_forced_version: self.min_forced_version,
+            minimum_version: self.minimum_version,
+            migration_url: self.migration_url,
+            as_admin: self.as_admin,
+            prefer_dmg: self.prefer_dmg,
+            remove_quarantine: self.remove_quarantine,
+            verify_codesign: self.verify_codesign,
+            codesign_team_id: self.codesign_team_id,
+            verify_authenticode: self.verify_authenticode,
+            expected_signer: self.expected_signer,
+            silent_install: self.silent_install,
+            install_with_sudo: self.install_with_sudo,
+            pinned_certificates: self.pinned_certificates,
+            pinned_fingerprints: self.pinned_fingerprints,
+            root_certificates: self.root_certificates,
+            etag_cache: self.etag_cache,
+            checksum_sha256: self.checksum_sha256.clone(),
+            verify_checksum: self.verify_checksum,
+            allow_prerelease: self.allow_prerelease,
+            channel: self.channel,
+            target_version: self.target_version,
+            version_requirement: self.version_requirement,
+            stream_to_disk_threshold: self.stream_to_disk_threshold,
+            disk_space_multiplier: self.disk_space_multiplier,
+            max_retries: self.max_retries,
+            retry_base_delay: self.retry_base_delay,
+            background_poll_interval: self.background_poll_interval,
+            backup_retention: self.backup_retention,
+            skipped_versions: self.skipped_versions,
+            skipped_versions_file: self.skipped_versions_file,
+            asset_name_glob: self.asset_name_glob,
+            temp_dir: self.temp_dir,
+            extract_path,
+            installer_args,
+            version_comparator: self.version_comparator,
+            on_check_complete: self.on_check_complete,
+            asset_filter: self.asset_filter,
+            before_install: self.before_install,
+            before_install_file: self.before_install_file,
+            after_install: self.after_install,
+            latest_release: Mutex::new(None),
+            release_cache: ReleaseCache::default(),
+            verified_digest: Mutex::new(None),
+        };
+        // Releases are fetched with the same transport settings as downloads.
+        updater.source = match (custom_source, gitlab) {
+            (Some(mut source), _) => {
+                source.set_http_client(updater.client_builder()?.build()?);
+                Arc::from(source)
+            }
+            (None, Some(gitlab)) => Arc::new(gitlab.client(updater.source_client()?)),
+            (None, None) => Arc::new(updater.endpoint_source(updater.config.endpoints.clone())?),
+        };
+        Ok(updater)
+    }
+}
+
+/// Updater instance capable of checking, downloading and installing updates.
+///
+/// Instances are cheap to reuse and keep the last successfully observed remote
+/// version for introspection through [`Self::latest_version`].
+pub struct Updater {
+    /// Application name used by platform backends and staging paths.
+    pub app_name: String,
+    /// Current application version.
+    pub current_version: Version,
+    /// Static updater configuration.
+    pub config: Config,
+    /// Selected target string.
+    pub target: String,
+    source: Arc<dyn ReleaseSource>,
+    source_config: SourceConfig,
+    /// HTTP headers propagated to update downloads.
+    pub headers: HeaderMap,
+    /// Optional limit on establishing a connection.
+    pub connect_timeout: Option<Duration>,
+    /// Optional limit on waiting for each read of a response, including download bodies.
+    pub read_timeout: Option<Duration>,
+    /// Optional proxy configuration.
+    pub proxy: Option<Url>,
+    /// Whether proxy configuration should be ignored.
+    pub no_proxy: bool,
+    /// Local address downloads are bound to when IPv4 or IPv6 is forced.
+    pub local_address: Option<IpAddr>,
+    /// Whether [`Self::update`] skips the check when the network is unreachable.
+    pub skip_on_offline: bool,
+    /// `(OS, Arch)` pairs checked by [`Self::validate_release_assets`].
+    pub required_asset_targets: Vec<(OS, Arch)>,
+    /// Whether running app instances are terminated before a Windows install.
+    pub auto_terminate_running: bool,
+    /// Maximum accepted artifact size in bytes.
+    pub max_asset_size: Option<u64>,
+    /// Mirrors set with [`UpdaterBuilder::mirror_urls`] and [`UpdaterBuilder::mirror`].
+    ///
+    /// URLs containing a `{filename}` placeholder are download mirrors tried by
+    /// [`Self::download_with_progress`]; the others are manifest mirrors for
+    /// [`Self::check_with_mirror_fallback`].
+    pub mirror_urls: Vec<Url>,
+    /// Script run on downloaded artifacts before installation.
+    pub post_download_script: Option<PathBuf>,
+    /// Direct download URL retried once when the primary download hits a network error.
+    pub fallback_download_url: Option<Url>,
+    /// Minimum version below which [`Self::update_required`] reports a forced update.
+    pub min_forced_version: Option<Version>,
+    /// Oldest version [`Self::check`] accepts upgrading from, set with [`UpdaterBuilder::minimum_version`].
+    pub minimum_version: Option<Version>,
+    /// Migration guide linked from [`Error::CurrentVersionTooOld`].
+    pub migration_url: Option<Url>,
+    /// Whether [`Self::relaunch`] elevates through [`Self::relaunch_as_admin`].
+    pub as_admin: bool,
+    /// Whether macOS `.dmg` images are preferred over `.app.zip` archives.
+    pub prefer_dmg: bool,
+    /// Whether macOS installs drop the `com.apple.quarantine` attribute.
+    pub remove_quarantine: bool,
+    /// Whether macOS installs are checked with `codesign --verify`.
+    pub verify_codesign: bool,
+    /// Team identifier the installed app must be signed by, set with [`UpdaterBuilder::codesign_team_id`].
+    pub codesign_team_id: Option<String>,
+    /// Whether Windows installers are checked with `WinVerifyTrust` before running.
+    pub verify_authenticode: bool,
+    /// Signer Windows installers must be signed by, set with [`UpdaterBuilder::expected_signer`].
+    pub expected_signer: Option<String>,
+    /// Whether Windows installers run without their UI.
+    pub silent_install: bool,
+    /// Whether Linux packages are installed through `sudo` instead of `pkexec`.
+    pub install_with_sudo: bool,
+    /// DER certificates added with [`UpdaterBuilder::pin_certificate`].
+    pub pinned_certificates: Vec<Vec<u8>>,
+    /// SHA-256 fingerprints added with [`UpdaterBuilder::pin_server_certificate_fingerprint`].
+    pub pinned_fingerprints: Vec<String>,
+    /// PEM root certificates added with [`UpdaterBuilder::add_root_certificate_pem`].
+    pub root_certificates: Vec<String>,
+    /// Whether release lookups are revalidated with `ETag` and `Last-Modified`.
+    pub etag_cache: bool,
+    /// Expected lowercase hex SHA-256 digest of downloaded artifacts.
+    pub checksum_sha256: Option<String>,
+    /// Whether downloads are verified against a published checksum sidecar asset.
+    pub verify_checksum: bool,
+    /// Whether pre-releases of every channel are considered when looking for the latest release.
+    pub allow_prerelease: bool,
+    /// Release track selected with [`UpdaterBuilder::channel`].
+    pub channel: UpdateChannel,
+    /// Version pinned with [`UpdaterBuilder::target_version`].
+    pub target_version: Option<Version>,
+    /// Requirement new versions must satisfy to be reported as updates.
+    pub version_requirement: Option<VersionReq>,
+    /// Artifact size above which [`Self::download_and_install`] streams to disk.
+    pub stream_to_disk_threshold: u64,
+    /// Multiple of the artifact size that must be free on the install volume.
+    pub disk_space_multiplier: f32,
+    /// Number of times transient download failures are retried.
+    pub max_retries: u32,
+    /// Delay before the first download retry, doubled for each further attempt.
+    pub retry_base_delay: Duration,
+    /// Interval configured with [`UpdaterBuilder::background_poll_interval`].
+    pub background_poll_interval: Duration,
+    /// Age after which the installation kept for [`Self::rollback`] is removed.
+    pub backup_retention: Option<Duration>,
+    /// Versions ignored by [`Self::check`], set with [`UpdaterBuilder::skip_version`].
+    pub skipped_versions: Vec<Version>,
+    /// File persisting versions skipped with [`Self::skip_current_release`].
+    pub skipped_versions_file: Option<PathBuf>,
+    /// Glob asset names must match, set with [`UpdaterBuilder::asset_name_glob`].
+    pub asset_name_glob: Option<String>,
+    /// Directory temporary files are created in, set with [`UpdaterBuilder::temp_dir`].
+    pub temp_dir: Option<PathBuf>,
+    /// Derived installation target path.
+    pub extract_path: PathBuf,
+    /// Windows installer arguments propagated from config and builder overrides.
+    pub installer_args: Vec<OsString>,
+    /// Optional custom version comparator.
+    pub version_comparator: Option<VersionComparator>,
+    /// Optional hook invoked after each completed check.
+    pub on_check_complete: Option<CheckCompleteHook>,
+    /// Optional predicate choosing among the assets matching the target.
+    pub asset_filter: Option<AssetFilter>,
+    /// Optional hook run on artifact bytes before installation.
+    pub before_install: Option<BeforeInstallHook>,
+    /// Optional hook run on the path of an artifact streamed to disk before installation.
+    pub before_install_file: Option<BeforeInstallFileHook>,
+    /// Optional hook run with the installation path after installation.
+    pub after_install: Option<AfterInstallHook>,
+    latest_release: Mutex<Option<crate::RemoteRelease>>,
+    /// Release and response validators kept when [`Self::etag_cache`] is enabled.
+    release_cache: ReleaseCache,
+    /// SHA-256 of the last artifact whose signature passed verification.
+    verified_digest: Mutex<Option<String>>,
+}
+
+/// Creates the source for a project configured with [`UpdaterBuilder::gitlab`].
+fn gitlab_source(base_url: Url, project_id: String, token: Option<String>) -> Result<GitLabSource> {
+    match token {
+        Some(token) => GitLabSource::with_token(base_url, project_id, token),
+        None => Ok(GitLabSource::new(base_url, project_id)),
+    }
+}
+
+/// Release source an [`Updater`] was built with, kept so [`Updater::load`] restores it.
+#[derive(Clone, Serialize, Deserialize)]
+enum SourceConfig {
+    /// [`Config::endpoints`] through [`EndpointSource`].
+    Endpoints,
+    /// A project configured with [`UpdaterBuilder::gitlab`].
+    GitLab {
+        base_url: Url,
+        project_id: String,
+        token: Option<String>,
+    },
+    /// A source set through [`UpdaterBuilder::source`], which cannot be saved.
+    Custom,
+}
+
+/// Serializable configuration snapshot of an [`Updater`].
+///
+/// Hooks, the version comparator, custom release sources, and the last
+/// observed release are not captured.
+#[derive(Serialize, Deserialize)]
+struct UpdaterSnapshot {
+    app_name: String,
+    current_version: Version,
+    config: Config,
+    target: String,
+    source: SourceConfig,
+    headers: Vec<(String, String)>,
+    connect_timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
+    proxy: Option<Url>,
+    no_proxy: bool,
+    local_address: Option<IpAddr>,
+    skip_on_offline: bool,
+    required_asset_targets: Vec<(OS, Arch)>,
+    auto_terminate_running: bool,
+    max_asset_size: Option<u64>,
+    mirror_urls: Vec<Url>,
+    post_download_script: Option<PathBuf>,
+    fallback_download_url: Option<Url>,
+    min_forced_version: Option<Version>,
+    minimum_version: Option<Version>,
+    migration_url: Option<Url>,
+    as_admin: bool,
+    prefer_dmg: bool,
+    remove_quarantine: bool,
+    verify_codesign: bool,
+    codesign_team_id: Option<String>,
+    verify_authenticode: bool,
+    expected_signer: Option<String>,
+    silent_install: bool,
+    install_with_sudo: bool,
+    pinned_certificates: Vec<Vec<u8>>,
+    pinned_fingerprints: Vec<String>,
+    root_certificates: Vec<String>,
+    etag_cache: bool,
+    checksum_sha256: Option<String>,
+    verify_checksum: bool,
+    allow_prerelease: bool,
+    channel: UpdateChannel,
+    target_version: Option<Version>,
+    version_requirement: Option<VersionReq>,
+    stream_to_disk_threshold: u64,
+    disk_space_multiplier: f32,
+    max_retries: u32,
+    retry_base_delay: Duration,
+    background_poll_interval: Duration,
+    backup_retention: Option<Duration>,
+    skipped_versions: Vec<Version>,
+    skipped_versions_file: Option<PathBuf>,
+    asset_name_glob: Option<String>,
+    temp_dir: Option<PathBuf>,
+    extract_path: PathBuf,
+    installer_args: Vec<String>,
+}
+
+/// Serializes the snapshot written by [`Updater::save`], without credentials.
+impl Serialize for Updater {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.snapshot(false).serialize(serializer)
+    }
+}
+
+/// Returns whether the header carries credentials that [`Updater::save`] leaves out.
+fn is_credential_header(name: &HeaderName, value: &HeaderValue) -> bool {
+    value.is_sensitive() || CREDENTIAL_HEADERS.contains(&name.as_str())
+}
+
+impl Updater {
+    fn snapshot(&self, include_secrets: bool) -> UpdaterSnapshot {
+        let source = match &self.source_config {
+            SourceConfig::GitLab {
+                base_url,
+                project_id,
+                token,
+            } => SourceConfig::GitLab {
+                base_url: base_url.clone(),
+                project_id: project_id.clone(),
+                token: token.clone().filter(|_| include_secrets),
+            },
+            source => source.clone(),
+        };
+        let mut proxy = self.proxy.clone();
+        if let Some(proxy) = proxy.as_mut().filter(|_| !include_secrets) {
+            let _ = proxy.set_username("");
+            let _ = proxy.set_password(None);
+        }
+        UpdaterSnapshot {
+            app_name: self.app_name.clone(),
+            current_version: self.current_version.clone(),
+            config: self.config.clone(),
+            target: self.target.clone(),
+            source,
+            headers: self
+                .headers
+                .iter()
+                .filter(|(name, value)| include_secrets || !is_credential_header(name, value))
+                .filter_map(|(name, value)| {
+                    Some((name.to_string(), value.to_str().ok()?.to_owned()))
+                })
+                .collect(),
+            connect_timeout: self.connect_timeout,
+            read_timeout: self.read_timeout,
+            proxy,
+            no_proxy: self.no_proxy,
+            local_address: self.local_address,
+            skip_on_offline: self.skip_on_offline,
+            required_asset_targets: self.required_asset_targets.clone(),
+            auto_terminate_running: self.auto_terminate_running,
+            max_asset_size: self.max_asset_size,
+            mirror_urls: self.mirror_urls.clone(),
+            post_download_script: self.post_download_script.clone(),
+            fallback_download_url: self.fallback_download_url.clone(),
+            min_forced_version: self.min_forced_version.clone(),
+            minimum_version: self.minimum_version.clone(),
+            migration_url: self.migration_url.clone(),
+            as_admin: self.as_admin,
+            prefer_dmg: self.prefer_dmg,
+            remove_quarantine: self.remove_quarantine,
+            verify_codesign: self.verify_codesign,
+            codesign_team_id: self.codesign_team_id.clone(),
+            verify_authenticode: self.verify_authenticode,
+            expected_signer: self.expected_signer.clone(),
+            silent_install: self.silent_install,
+            install_with_sudo: self.install_with_sudo,
+            pinned_certificates: self.pinned_certificates.clone(),
+            pinned_fingerprints: self.pinned_fingerprints.clone(),
+            root_certificates: self.root_certificates.clone(),
+            etag_cache: self.etag_cache,
+            checksum_sha256: self.checksum_sha256.clone(),
+            verify_checksum: self.verify_checksum,
+            allow_prerelease: self.allow_prerelease,
+            channel: self.channel.clone(),
+            target_version: self.target_version.clone(),
+            version_requirement: self.version_requirement.clone(),
+            stream_to_disk_threshold: self.stream_to_disk_threshold,
+            disk_space_multiplier: self.disk_space_multiplier,
+            max_retries: self.max_retries,
+            retry_base_delay: self.retry_base_delay,
+            background_poll_interval: self.background_poll_interval,
+            backup_retention: self.backup_retention,
+            skipped_versions: self.skipped_versions.clone(),
+            skipped_versions_file: self.skipped_versions_file.clone(),
+            asset_name_glob: self.asset_name_glob.clone(),
+            temp_dir: self.temp_dir.clone(),
+            extract_path: self.extract_path.clone(),
+            installer_args: self
+                .installer_args
+                .iter()
+                .map(|arg| arg.to_string_lossy().into_owned())
+                .collect(),
+        }
+    }
+    /// Writes a JSON configuration snapshot of this updater to `path`.
+    ///
+    /// The file is written to a temporary sibling and renamed into place, so a
+    /// crash never leaves a partial snapshot. Hooks and the version comparator
+    /// are not saved; see [`Self::load`]. Credentials, namely authorization
+    /// and token headers, proxy user info, and the GitLab token, are left out
+    /// unless saved with [`Self::save_with_secrets`].
+    pub fn save(&self, path: &Path) -> Result<()> {
+        self.write_snapshot(path, false)
+    }
+
+    /// Like [`Self::save`], but also writes credentials to `path` in plain text.
+    pub fn save_with_secrets(&self, path: &Path) -> Result<()> {
+        self.write_snapshot(path, true)
+    }
+
+    fn write_snapshot(&self, path: &Path, include_secrets: bool) -> Result<()> {
+        let dir = path
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        let mut file = tempfile::NamedTempFile::new_in(dir)?;
+        serde_json::to_writer_pretty(&mut file, &self.snapshot(include_secrets))?;
+        file.persist(path).map_err(|error| error.error)?;
+        Ok(())
+    }
+
+    /// Recreates an updater from a snapshot written by [`Self::save`].
+    ///
+    /// This restores configuration, not state: hooks and the version
+    /// comparator are `None` and no release has been observed yet. Releases
+    /// come from the same endpoints or GitLab project as before; snapshots of
+    /// updaters with a custom [`ReleaseSource`] fail with
+    /// [`Error::InvalidConfig`] and are loaded with [`Self::load_with_source`].
+    pub fn load(path: &Path) -> Result<Self> {
+        let mut updater = Self::read_snapshot(path)?;
+        updater.source = match updater.source_config.clone() {
+            SourceConfig::Endpoints => {
+                Arc::new(updater.endpoint_source(updater.config.endpoints.clone())?)
+            }
+            SourceConfig::GitLab {
+                base_url,
+                project_id,
+                token,
+            } => Arc::new(
+                gitlab_source(base_url, project_id, token)?.client(updater.source_client()?),
+            ),
+            SourceConfig::Custom => {
+                return Err(Error::InvalidConfig(
+                    "the saved updater used a custom release source; load it with `Updater::load_with_source`".into(),
+                ));
+            }
+        };
+        Ok(updater)
+    }
+
+    /// Recreates an updater from a snapshot written by [`Self::save`], fetching
+    /// releases from `source` instead of the saved one.
+    pub fn load_with_source(path: &Path, mut source: Box<dyn ReleaseSource>) -> Result<Self> {
+        let mut updater = Self::read_snapshot(path)?;
+        source.set_http_client(updater.client_builder()?.build()?);
+        updater.source = Arc::from(source);
+        updater.source_config = SourceConfig::Custom;
+        Ok(updater)
+    }
+
+    fn read_snapshot(path: &Path) -> Result<Self> {
+        let snapshot: UpdaterSnapshot = serde_json::from_slice(&fs::read(path)?)?;
+        let mut headers = HeaderMap::new();
+        for (name, value) in snapshot.headers {
+            headers.append(HeaderName::try_from(name)?, HeaderValue::try_from(value)?);
+        }
+
+        Ok(Self {
+            source: Arc::new(EndpointSource::new(Vec::new())),
+            source_config: snapshot.source,
+            app_name: snapshot.app_name,
+            current_version: snapshot.current_version,
+            config: snapshot.config,
+            target: snapshot.target,
+            headers,
+            connect_timeout: snapshot.connect_timeout,
+            read_timeout: snapshot.read_timeout,
+            proxy: snapshot.proxy,
+            no_proxy: snapshot.no_proxy,
+            local_address: snapshot.local_address,
+            skip_on_offline: snapshot.skip_on_offline,
+            required_asset_targets: snapshot.required_asset_targets,
+            auto_terminate_running: snapshot.auto_terminate_running,
+            max_asset_size: snapshot.max_asset_size,
+            mirror_urls: snapshot.mirror_urls,
+            post_download_script: snapshot.post_download_script,
+            fallback_download_url: snapshot.fallback_download_url,
+            min_forced_version: snapshot.min_forced_version,
+            minimum_version: snapshot.minimum_version,
+            migration_url: snapshot.migration_url,
+            as_admin: snapshot.as_admin,
+            prefer_dmg: snapshot.prefer_dmg,
+            remove_quarantine: snapshot.remove_quarantine,
+            verify_codesign: snapshot.verify_codesign,
+            codesign_team_id: snapshot.codesign_team_id,
+            verify_authenticode: snapshot.verify_authenticode,
+            expected_signer: snapshot.expected_signer,
+            silent_install: snapshot.silent_install,
+            install_with_sudo: snapshot.install_with_sudo,
+            pinned_certificates: snapshot.pinned_certificates,
+            pinned_fingerprints: snapshot.pinned_fingerprints,
+            root_certificates: snapshot.root_certificates,
+            etag_cache: snapshot.etag_cache,
+            checksum_sha256: snapshot.checksum_sha256,
+            verify_checksum: snapshot.verify_checksum,
+            allow_prerelease: snapshot.allow_prerelease,
+            channel: snapshot.channel,
+            target_version: snapshot.target_version,
+            version_requirement: snapshot.version_requirement,
+            stream_to_disk_threshold: snapshot.stream_to_disk_threshold,
+            disk_space_multiplier: snapshot.disk_space_multiplier,
+            max_retries: snapshot.max_retries,
+            retry_base_delay: snapshot.retry_base_delay,
+            background_poll_interval: snapshot.background_poll_interval,
+            backup_retention: snapshot.backup_retention,
+            skipped_versions: snapshot.skipped_versions,
+            skipped_versions_file: snapshot.skipped_versions_file,
+            asset_name_glob: snapshot.asset_name_glob,
+            temp_dir: snapshot.temp_dir,
+            extract_path: snapshot.extract_path,
+            installer_args: snapshot
+                .installer_args
+                .into_iter()
+                .map(OsString::from)
+                .collect(),
+            version_comparator: None,
+            on_check_complete: None,
+            asset_filter: None,
+            before_install: None,
+            before_install_file: None,
+            after_install: None,
+            latest_release: Mutex::new(None),
+            release_cache: ReleaseCache::default(),
+            verified_digest: Mutex::new(None),
+        })
+    }
+}
+
+impl AsRef<Version> for Updater {
+    fn as_ref(&self) -> &Version {
+        &self.current_version
+    }
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+struct UnconfiguredSource;
+
+#[cfg(any(test, feature = "test-utils"))]
+impl ReleaseSource for UnconfiguredSource {
+    fn fetch<'a>(&'a self, _request: &'a SourceRequest) -> crate::SourceFuture<'a> {
+        Box::pin(async { Err(Error::NotConfigured) })
+    }
+}
+
+/// Placeholder updater for tests and scaffolding.
+///
+/// The default instance has an empty app name, version `0.0.0`, and no release
+/// source; [`Updater::check`] always returns [`Error::NotConfigured`]. It is
+/// only available with the `test-utils` feature to prevent production use.
+#[cfg(any(test, feature = "test-utils"))]
+impl Default for Updater {
+    fn default() -> Self {
+        Self {
+            app_name: String::new(),
+            current_version: Version::new(0, 0, 0),
+            config: Config::default(),
+            target: String::new(),
+            source: Arc::new(UnconfiguredSource),
+            source_config: SourceConfig::Custom,
+            headers: HeaderMap::new(),
+            connect_timeout: None,
+            read_timeout: None,
+            proxy: None,
+            no_proxy: false,
+            local_address: None,
+            skip_on_offline: false,
+            required_asset_targets: Vec::new(),
+            auto_terminate_running: false,
+            max_asset_size: None,
+            mirror_urls: Vec::new(),
+            post_download_script: None,
+            fallback_download_url: None,
+            min_forced_version: None,
+            minimum_version: None,
+            migration_url: None,
+            as_admin: false,
+            prefer_dmg: false,
+            remove_quarantine: true,
+            verify_codesign: false,
+            codesign_team_id: None,
+            verify_authenticode: false,
+            expected_signer: None,
+            silent_install: true,
+            install_with_sudo: false,
+            pinned_certificates: Vec::new(),
+            pinned_fingerprints: Vec::new(),
+            root_certificates: Vec::new(),
+            etag_cache: false,
+            checksum_sha256: None,
+            verify_checksum: false,
+            allow_prerelease: false,
+            channel: UpdateChannel::Stable,
+            target_version: None,
+            version_requirement: None,
+            stream_to_disk_threshold: DEFAULT_STREAM_TO_DISK_THRESHOLD,
+            disk_space_multiplier: DEFAULT_DISK_SPACE_MULTIPLIER,
+            max_retries: 0,
+            retry_base_delay: DEFAULT_RETRY_BASE_DELAY,
+            background_poll_interval: DEFAULT_BACKGROUND_POLL_INTERVAL,
+            backup_retention: None,
+            skipped_versions: Vec::new(),
+            skipped_versions_file: None,
+            asset_name_glob: None,
+            temp_dir: None,
+            extract_path: PathBuf::new(),
+            installer_args: Vec::new(),
+            version_comparator: None,
+            on_check_complete: None,
+            asset_filter: None,
+            before_install: None,
+            before_install_file: None,
+            after_install: None,
+            latest_release: Mutex::new(None),
+            release_cache: ReleaseCache::default(),
+            verified_digest: Mutex::new(None),
+        }
+    }
+}
+
+impl Updater {
+    /// Returns the latest remote version observed by the last successful [`Self::check`] call.
+    pub fn latest_version(&self) -> Option<Version> {
+        self.latest_release
+            .lock()
+            .ok()?
+            .as_ref()
+            .map(|release| release.version.clone())
+    }
+
+    /// Returns the raw tag name, such as `v1.2.3`, observed by the last successful [`Self::check`] call.
+    ///
+    /// Unlike [`Self::latest_version`], this preserves the original tag
+    /// formatting. Sources without release tags, such as endpoint manifests,
+    /// return `None`.
+    pub fn latest_version_tag(&self) -> Option<String> {
+        self.latest_release.lock().ok()?.as_ref()?.tag_name.clone()
+    }
+
+    /// Returns the web page of the release observed by the last successful [`Self::check`] call.
+    ///
+    /// GitHub sources report the release's `html_url`; endpoint manifests
+    /// report an optional `html_url` field.
+    pub fn release_page_url(&self) -> Option<Url> {
+        self.latest_release.lock().ok()?.as_ref()?.html_url.clone()
+    }
+
+    /// Opens [`Self::release_page_url`] in the default browser.
+    ///
+    /// Requires the `open-browser` feature. Fails with an I/O `NotFound`
+    /// error when no release page has been observed yet.
+    #[cfg(feature = "open-browser")]
+    pub fn open_release_page(&self) -> Result<()> {
+        let url = self.release_page_url().ok_or_else(|| {
+            Error::Io(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "no release page URL has been observed",
+            ))
+        })?;
+        webbrowser::open(url.as_str())?;
+        Ok(())
+    }
+
+    /// Returns `true` when the application runs inside a Snap, detected by the `SNAP` variable.
+    ///
+    /// Snap-confined applications are updated by `snapd`; use this to skip
+    /// self-updates or to prefer `.snap` artifacts.
+    pub fn is_snap_environment() -> bool {
+        std::env::var_os("SNAP").is_some()
+    }
+
+    /// Returns `true` when `bytes` look like a Nullsoft (NSIS) self-extracting installer.
+    ///
+    /// Detection checks the NSIS magic signature at offset `0x88` and falls back
+    /// to searching for the `Nullsoft` marker embedded in the PE resources. NSIS
+    /// installers are launched with `/S` so they run silently.
+    pub fn is_nsis_installer(bytes: &[u8]) -> bool {
+        bytes
+            .get(NSIS_MAGIC_OFFSET..NSIS_MAGIC_OFFSET + NSIS_MAGIC.len())
+            .is_some_and(|magic| magic == NSIS_MAGIC)
+            || bytes.windows(8).any(|window| window == b"Nullsoft")
+    }
+
+    /// Checks that `release` provides an artifact for every required target.
+    ///
+    /// Returns the validated pairs, or [`Error::MissingRequiredAssets`] listing
+    /// the pairs without an artifact. Single-target (dynamic) releases are
+    /// treated as covering every target.
+    pub fn validate_release_assets(&self, release: &RemoteRelease) -> Result<Vec<(OS, Arch)>> {
+        let missing = self
+            .required_asset_targets
+            .iter()
+            .filter(|(os, arch)| {
+                let target = TargetInfo::from_system(SystemInfo {
+                    os: os.clone(),
+                    arch: arch.clone(),
+                    distro: None,
+                })
+                .target;
+                release.download_url(&target).is_err()
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if missing.is_empty() {
+            Ok(self.required_asset_targets.clone())
+        } else {
+            Err(Error::MissingRequiredAssets(missing))
+        }
+    }
+
+    /// Returns whether `version` satisfies [`Self::version_requirement`], if any.
+    fn satisfies_requirement(&self, version: &Version) -> bool {
+        let Some(requirement) = &self.version_requirement else {
+            return true;
+        };
+        if self.allow_prerelease && !version.pre.is_empty() {
+            let mut release = version.clone();
+            release.pre = semver::Prerelease::EMPTY;
+            return requirement.matches(&release);
+        }
+        requirement.matches(version)
+    }
+
+    fn source_request(&self) -> SourceRequest {
+        let mut request = SourceRequest::new(self.target.clone())
+            .prefer_dmg(self.prefer_dmg)
+            .allow_prerelease(self.allow_prerelease)
+            .channel(self.channel.clone());
+        if self.etag_cache {
+            request = request.release_cache(self.release_cache.clone());
+        }
+        if let Some(version) = &self.target_version {
+            request = request.version(version.clone());
+        }
+        if let Some(filter) = &self.asset_filter {
+            request = request.asset_filter(filter.clone());
+        }
+        if let Some(pattern) = &self.asset_name_glob {
+            request = request.asset_name_glob(pattern.clone());
+        }
+        match self.max_asset_size {
+            Some(max_bytes) => request.max_asset_size(max_bytes),
+            None => request,
+        }
+    }
+
+    async fn fetch_release(&self) -> Result<RemoteRelease> {
+        self.fetch_release_from(self.source.as_ref()).await
+    }
+
+    async fn fetch_release_from(&self, source: &dyn ReleaseSource) -> Result<RemoteRelease> {
+        let release = source.fetch(&self.source_request()).await?;
+        if let Ok(mut latest_release) = self.latest_release.lock() {
+            *latest_release = Some(release.clone());
+        }
+        Ok(release)
+    }
+
+    /// Fetches the latest release notes without resolving a downloadable artifact.
+    ///
+    /// Unlike [`Self::check`], this succeeds on platforms without a published
+    /// artifact, which makes it suitable for cross-platform "what's new" views.
+    pub async fn download_release_notes_only(&self) -> Result<Option<String>> {
+        self.source.fetch_notes(&self.source_request()).await
+    }
+
+    /// Fetches up to `n` of the most recent releases for the configured target, newest first.
+    ///
+    /// GitHub releases are listed page by page, up to ten pages; drafts,
+    /// pre-releases outside [`UpdaterBuilder::channel`] unless
+    /// [`UpdaterBuilder::allow_prerelease`] is set, and releases without an
+    /// artifact for this target are skipped. Other sources only report their
+    /// latest release.
+    pub async fn list_releases(&self, n: usize) -> Result<Vec<RemoteRelease>> {
+        self.source.fetch_releases(&self.source_request(), n).await
+    }
+
+    /// Returns the latest release when it is older than the running version.
+    ///
+    /// This detects canary or beta installs that are ahead of the latest
+    /// published release, which [`Self::check`] reports as `Ok(None)`.
+    /// Use [`Self::check_for_downgrade_strict`] to treat this as an error.
+    pub async fn check_for_downgrade(&self) -> Result<Option<RemoteRelease>> {
+        let release = self.fetch_release().await?;
+        Ok((release.version < self.current_version).then_some(release))
+    }
+
+    /// Fails with [`Error::DowngradeDetected`] when the running version is
+    /// ahead of the latest published release.
+    ///
+    /// This is the strict form of [`Self::check_for_downgrade`] for workflows
+    /// that treat running ahead of the latest release as an error.
+    pub async fn check_for_downgrade_strict(&self) -> Result<()> {
+        match self.check_for_downgrade().await? {
+            Some(release) => Err(Error::DowngradeDetected {
+                installed: self.current_version.clone(),
+                latest: release.version,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Classifies the latest release against a forced-update threshold.
+    ///
+    /// Returns [`UpdateRequired::Forced`] when the running version is below
+    /// `min_forced`, [`UpdateRequired::Optional`] when a newer release is
+    /// available, and [`UpdateRequired::NotRequired`] otherwise.
+    pub async fn check_update_required(&self, min_forced: &Version) -> Result<UpdateRequired> {
+        let release = self.fetch_release().await?;
+        Ok(if self.current_version < *min_forced {
+            UpdateRequired::Forced(release.version)
+        } else if self.current_version < release.version {
+            UpdateRequired::Optional(release.version)
+        } else {
+            UpdateRequired::NotRequired
+        })
+    }
+
+    /// Like [`Self::check_update_required`], using the threshold configured with
+    /// [`UpdaterBuilder::min_forced_version`].
+    ///
+    /// Without a configured threshold, updates are never reported as forced.
+    pub async fn update_required(&self) -> Result<UpdateRequired> {
+        match &self.min_forced_version {
+            Some(min_forced) => self.check_update_required(min_forced).await,
+            None => self.check_update_required(&Version::new(0, 0, 0)).await,
+        }
+    }
+
+    /// Fetches release metadata and returns an [`Update`] when a newer version is available.
+    ///
+    /// The returned [`Update`] is already narrowed to the current target and
+    /// contains the resolved installer URL, signature, and install strategy.
+    /// With [`UpdaterBuilder::target_version`], the pinned release is returned
+    /// whenever it differs from the current version. Releases skipped with
+    /// [`UpdaterBuilder::skip_version`] or [`Self::skip_current_release`]
+    /// return `Ok(None)`, as does an [`UpdaterBuilder::channel`] without
+    /// releases. Fails with [`Error::CurrentVersionTooOld`] when the
+    /// running version is below [`UpdaterBuilder::minimum_version`].
+    /// An exhausted GitHub rate limit fails with [`Error::RateLimitExceeded`],
+    /// which carries the time the limit resets.
+    ///
+    /// When the update would be installed by writing to the install directory
+    /// directly, as AppImages, Linux archives and Windows portable archives
+    /// are, [`Self::check_install_permissions`] runs before it is returned.
+    pub async fn check(&self) -> Result<Option<Update>> {
+        let result = self.check_from(self.source.as_ref()).await;
+        if let Ok(Some(update)) = &result
+            && update.install_action().writes_install_dir()
+        {
+            self.check_install_permissions()?;
+        }
+        result
+    }
+
+    /// Checks that the directory containing [`Self::extract_path`] is writable.
+    ///
+    /// A probe file is created in the directory and deleted again. Fails with
+    /// [`Error::InsufficientPrivileges`] when the directory is read-only or
+    /// not writable by the current user. Other failures, such as a directory
+    /// that does not exist yet, are not permission problems and pass. Always
+    /// passes on macOS, where a read-only bundle is replaced through an
+    /// AppleScript administrator prompt.
+    pub fn check_install_permissions(&self) -> Result<()> {
+        if cfg!(target_os = "macos") {
+            return Ok(());
+        }
+        let Some(dir) = self.extract_path.parent() else {
+            return Ok(());
+        };
+        match tempfile::Builder::new()
+            .prefix(".release-hub-probe-")
+            .tempfile_in(dir)
+        {
+            Err(error)
+                if matches!(
+                    error.kind(),
+                    std::io::ErrorKind::PermissionDenied | std::io::ErrorKind::ReadOnlyFilesystem
+                ) =>
+            {
+                Err(Error::InsufficientPrivileges)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Adds the release observed by the last successful [`Self::check`] to the skipped versions file.
+    ///
+    /// Call this when the user dismisses an update so later checks stay quiet
+    /// about that version. Fails with an I/O `NotFound` error when no release
+    /// has been observed, and `InvalidInput` when
+    /// [`UpdaterBuilder::skipped_versions_file`] is not configured.
+    pub fn skip_current_release(&self) -> Result<()> {
+        let version = self.latest_version().ok_or_else(|| {
+            Error::Io(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "no release has been observed",
+            ))
+        })?;
+        let path = self.skipped_versions_file.as_ref().ok_or_else(|| {
+            Error::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "no skipped versions file is configured",
+            ))
+        })?;
+
+        let mut versions = read_skipped_versions(path)?;
+        if versions.contains(&version) {
+            return Ok(());
+        }
+        versions.push(version);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let contents: String = versions
+            .iter()
+            .map(|version| format!("{version}\n"))
+            .collect();
+        fs::write(path, contents)?;
+        Ok(())
+    }
+
+    /// Deletes the skipped versions file, so only [`Self::skipped_versions`] stay ignored.
+    pub fn clear_skipped_versions(&self) -> Result<()> {
+        match &self.skipped_versions_file {
+            Some(path) => match fs::remove_file(path) {
+                Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(()),
+                result => Ok(result?),
+            },
+            None => Ok(()),
+        }
+    }
+
+    fn is_skipped(&self, version: &Version) -> Result<bool> {
+        if self.skipped_versions.contains(version) {
+            return Ok(true);
+        }
+        match &self.skipped_versions_file {
+            Some(path) => Ok(read_skipped_versions(path)?.contains(version)),
+            None => Ok(false),
+        }
+    }
+
+    /// Runs [`Self::check`] only on the first launch, as recorded by `flag_file`.
+    ///
+    /// When `flag_file` exists, returns `Ok(None)` without contacting the
+    /// release source. Otherwise the check runs and, once it succeeds, the
+    /// current Unix timestamp is written to `flag_file` (creating parent
+    /// directories) so later launches skip it. A failed check leaves the flag
+    /// absent and is retried on the next launch.
+    pub async fn check_on_first_run(&self, flag_file: &Path) -> Result<Option<Update>> {
+        if flag_file.exists() {
+            return Ok(None);
+        }
+
+        let update = self.check().await?;
+        if let Some(parent) = flag_file.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let checked_at = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        fs::write(flag_file, checked_at.to_string())?;
+        Ok(update)
+    }
+
+    /// Runs [`Self::check`], falling back to manifest mirrors on network errors.
+    ///
+    /// When the configured source fails with [`Error::GitHub`],
+    /// [`Error::RateLimitExceeded`], [`Error::Reqwest`] or [`Error::Network`], each URL in `manifest_mirrors`
+    /// is tried in order as an endpoint manifest and the first success is
+    /// returned. If every mirror fails, the last error is returned. Pass
+    /// [`Self::mirror_urls`] to use the mirrors configured with
+    /// [`UpdaterBuilder::mirror_urls`]; download mirrors among them are skipped.
+    pub async fn check_with_mirror_fallback(
+        &self,
+        manifest_mirrors: &[Url],
+    ) -> Result<Option<Update>> {
+        let mut result = self.check().await;
+        for mirror in manifest_mirrors
+            .iter()
+            .filter(|url| !is_download_mirror(url))
+        {
+            match &result {
+                Err(
+                    Error::GitHub(_)
+                    | Error::RateLimitExceeded { .. }
+                    | Error::Reqwest(_)
+                    | Error::Network(_),
+                ) => {}
+                _ => break,
+            }
+            let source = self.endpoint_source(vec![mirror.clone()])?;
+            result = self.check_from(&source).await;
+        }
+        result
+    }
+
+    async fn check_from(&self, source: &dyn ReleaseSource) -> Result<Option<Update>> {
+        let started = Instant::now();
+        let result = self.resolve_update(source).await;
+        if let Some(hook) = &self.on_check_complete {
+            let (update_available, version, error) = match &result {
+                Ok((version, update)) => (update.is_some(), version.clone(), None),
+                Err(error) => (false, None, Some(error.to_string())),
+            };
+            hook(CheckResult {
+                update_available,
+                version,
+                duration: started.elapsed(),
+                error,
+            });
+        }
+        result.map(|(_, update)| update)
+    }
+
+    /// Fetches the release from `source` and builds the update it offers, if
+    /// any, alongside the version the release advertises.
+    async fn resolve_update(
+        &self,
+        source: &dyn ReleaseSource,
+    ) -> Result<(Option<Version>, Option<Update>)> {
+        if let Some(minimum) = &self.minimum_version
+            && self.current_version < *minimum
+        {
+            return Err(Error::CurrentVersionTooOld {
+                current: self.current_version.clone(),
+                minimum: minimum.clone(),
+                migration_url: self.migration_url.as_ref().map(Url::to_string),
+            });
+        }
+        let release = match self.fetch_release_from(source).await {
+            Err(Error::NoMatchingRelease) => return Ok((None, None)),
+            result => result?,
+        };
+        let mut headers = release.download_headers.clone();
+        headers.extend(self.headers.clone());
+
+        let is_candidate = if self.target_version.is_some() {
+            // Pinned releases are installed even when they are older.
+            release.version != self.current_version
+        } else if let Some(comparator) = &self.version_comparator {
+            comparator(self.current_version.clone(), release.clone())
+        } else {
+            release.version > self.current_version
+        };
+        let has_update = is_candidate
+            && self.satisfies_requirement(&release.version)
+            && !self.is_skipped(&release.version)?;
+        if !has_update {
+            return Ok((Some(release.version), None));
+        }
+
+        let update = Update {
+            current_version: self.current_version.clone(),
+            version: release.version.clone(),
+            date: release.pub_date,
+            body: release.notes.clone(),
+            raw_json: serde_json::to_value(&release)?,
+            download_url: release.download_url(&self.target)?.clone(),
+            signature: release.signature(&self.target)?.clone(),
+            pubkey: self.config.pubkey.clone(),
+            target: self.target.clone(),
+            installer_kind: InstallerKind::for_target(
+                Path::new(release.download_url(&self.target)?.path()),
+                &self.target,
+            )?,
+            headers,
+            connect_timeout: self.connect_timeout,
+            read_timeout: self.read_timeout,
+            proxy: self.proxy.clone(),
+            no_proxy: self.no_proxy,
+            local_address: self.local_address,
+            auto_terminate_running: self.auto_terminate_running,
+            max_asset_size: self.max_asset_size,
+            asset_size: release.asset_size(&self.target),
+            remove_quarantine: self.remove_quarantine,
+            verify_codesign: self.verify_codesign,
+            codesign_team_id: self.codesign_team_id.clone(),
+            verify_authenticode: self.verify_authenticode,
+            expected_signer: self.expected_signer.clone(),
+            silent_install: self.silent_install,
+            install_with_sudo: self.install_with_sudo,
+            pinned_certificates: self.pinned_certificates.clone(),
+            pinned_fingerprints: self.pinned_fingerprints.clone(),
+            root_certificates: self.root_certificates.clone(),
+            dangerous_accept_invalid_certs: self.config.dangerous_accept_invalid_certs,
+            dangerous_accept_invalid_hostnames: self.config.dangerous_accept_invalid_hostnames,
+            extract_path: self.extract_path.clone(),
+            app_name: self.app_name.clone(),
+            installer_args: self.installer_args.clone(),
+            temp_dir: self.temp_dir.clone(),
+        };
+        Ok((Some(release.version), Some(update)))
+    }
+
+    /// Runs [`Self::check`] and converts any panic into [`Error::InternalPanic`].
+    ///
+    /// Some platform APIs can panic in constrained environments such as JNI
+    /// hosts. The future is polled under [`std::panic::catch_unwind`] with
+    /// `AssertUnwindSafe`, so state touched by the panicking future (such as
+    /// the latest-release cache) may be left partially updated. Panics are
+    /// still reported by the panic hook, and nothing is caught when the
+    /// binary is built with `panic = "abort"`.
+    #[cfg(feature = "catch-panic")]
+    pub async fn check_without_panicking(&self) -> Result<Option<Update>> {
+        use futures_util::FutureExt;
+
+        std::panic::AssertUnwindSafe(self.check())
+            .catch_unwind()
+            .await
+            .unwrap_or_else(|payload| {
+                let message = payload
+                    .downcast_ref::<&str>()
+                    .map(|message| message.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_else(|| "unknown panic".into());
+                Err(Error::InternalPanic(message))
+            })
+    }
+
+    /// Checks for an update and writes a human-readable diagnostics summary to `out`.
+    ///
+    /// Intended for `--check-update` style CLI flags, which typically pass
+    /// [`std::io::stdout`]. The summary covers the current version, release
+    /// source, detected OS and architecture, target, check outcome, selected
+    /// artifact and its size, install path, and whether that path is
+    /// writable. Errors from [`Self::check`] are written and then returned.
+    pub async fn check_and_print_summary<W: Write>(&self, out: &mut W) -> Result<()> {
+        let result = self.check().await;
+        writeln!(out, "{}", self.summary(&result))?;
+        result.map(|_| ())
+    }
+
+    fn summary(&self, result: &Result<Option<Update>>) -> String {
+        let source = self
+            .source
+            .describe()
+            .unwrap_or_else(|| "custom release source".to_string());
+        let system = match SystemInfo::current() {
+            Ok(system) => format!("{:?} {:?}", system.os, system.arch),
+            Err(error) => format!("unknown ({error})"),
+        };
+        let install_dir = self.extract_path.parent().unwrap_or(&self.extract_path);
+        let mut lines = vec![
+            format!("App:              {}", self.app_name),
+            format!("Current version:  {}", self.current_version),
+            format!("Release source:   {source}"),
+            format!("Detected system:  {system}"),
+            format!("Target:           {}", self.target),
+        ];
+        match result {
+            Ok(Some(update)) => {
+                let artifact = update
+                    .download_url
+                    .path_segments()
+                    .and_then(|mut segments| segments.rfind(|segment| !segment.is_empty()))
+                    .unwrap_or_default();
+                lines.push(format!(
+                    "Status:           update available ({})",
+                    update.version
+                ));
+                lines.push(format!("Artifact:         {artifact}"));
+                lines.push(match update.asset_size {
+                    Some(size) => format!("Artifact size:    {size} bytes"),
+                    None => "Artifact size:    unknown".to_string(),
+                });
+                lines.push(format!("Download URL:     {}", update.download_url));
+            }
+            Ok(None) => lines.push("Status:           up to date".to_string()),
+            Err(error) => lines.push(format!("Status:           check failed: {error}")),
+        }
+        lines.push(format!("Install path:     {}", self.extract_path.display()));
+        lines.push(format!(
+            "Install writable: {}",
+            if crate::utils::is_dir_writable(install_dir) {
+                "yes"
+            } else {
+                "no"
+            }
+        ));
+        lines.join("\n")
+    }
+
+    /// Checks many updaters with at most `concurrency` checks in flight.
+    ///
+    /// Results are yielded as `(app_name, latest_version)` pairs in completion
+    /// order, where the version is `Some` when an update is available. This
+    /// bounds concurrent connections for dashboards that track many components.
+    pub fn check_all_streaming(
+        updaters: Vec<Updater>,
+        concurrency: usize,
+    ) -> impl Stream<Item = (String, Result<Option<Version>>)> {
+        stream::iter(updaters)
+            .map(|updater| async move {
+                let result = updater
+                    .check()
+                    .await
+                    .map(|update| update.map(|update| update.version));
+                (updater.app_name, result)
+            })
+            .buffer_unordered(concurrency.max(1))
+    }
+
+    /// Returns `true` when the update server can be reached.
+    ///
+    /// Sends a `HEAD` request to the host of the configured release source,
+    /// such as the GitLab instance or `https://api.github.com`, falling back
+    /// to the first configured endpoint, with a three-second connect timeout
+    /// and the updater's proxy and TLS settings. Any HTTP response, including
+    /// `4xx`, counts as online.
+    pub async fn network_connectivity_check(&self) -> bool {
+        let Some(url) = self.connectivity_url() else {
+            return false;
+        };
+        match self
+            .client_builder()
+            .and_then(|request| Ok(request.connect_timeout(CONNECTIVITY_TIMEOUT).build()?))
+        {
+            Ok(client) => client.head(url).send().await.is_ok(),
+            Err(_) => false,
+        }
+    }
+
+    /// Returns the URL connectivity checks probe: the release source's host,
+    /// else the first configured endpoint, else `https://api.github.com`.
+    fn connectivity_url(&self) -> Option<Url> {
+        self.source
+            .connectivity_url()
+            .or_else(|| self.config.endpoints.first().cloned())
+            .or_else(|| Url::parse(CONNECTIVITY_CHECK_URL).ok())
+    }
+
+    /// Returns `true` when a TCP connection to the update server can be opened.
+    ///
+    /// Connects to the host and port of the configured release source, falling
+    /// back to the first configured endpoint and then `api.github.com:443`,
+    /// with a three-second timeout. See [`check_tcp_connectivity`].
+    pub async fn check_internet_connectivity(&self) -> bool {
+        let endpoint = self
+            .connectivity_url()
+            .and_then(|url| Some((url.host_str()?.to_owned(), url.port_or_known_default()?)));
+        let (host, port) = endpoint
+            .unwrap_or_else(|| (CONNECTIVITY_CHECK_HOST.to_owned(), CONNECTIVITY_CHECK_PORT));
+        check_tcp_connectivity(&host, port, CONNECTIVITY_TIMEOUT).await
+    }
+
+    fn client_builder(&self) -> Result<ClientBuilder> {
+        ClientSettings {
+            accept_invalid_certs: self.config.dangerous_accept_invalid_certs,
+            accept_invalid_hostnames: self.config.dangerous_accept_invalid_hostnames,
+            connect_timeout: self.connect_timeout,
+            read_timeout: self.read_timeout,
+            local_address: self.local_address,
+            no_proxy: self.no_proxy,
+            proxy: self.proxy.as_ref(),
+            root_certificates: &self.root_certificates,
+            pinned_certificates: &self.pinned_certificates,
+            pinned_fingerprints: &self.pinned_fingerprints,
+        }
+        .builder()
+    }
+
+    /// Builds an [`EndpointSource`] that fetches `endpoints` with the updater's
+    /// headers and transport settings.
+    fn endpoint_source(&self, endpoints: Vec<Url>) -> Result<EndpointSource> {
+        Ok(EndpointSource::with_client(
+            endpoints,
+            self.source_client()?,
+        ))
+    }
+
+    /// Builds the client release sources use, carrying the updater's headers
+    /// and transport settings.
+    fn source_client(&self) -> Result<reqwest::Client> {
+        Ok(self
+            .client_builder()?
+            .default_headers(self.headers.clone())
+            .build()?)
+    }
+
+    /// Collects a [`DiagnosticsReport`] describing the updater's environment.
+    ///
+    /// Failures while fetching the latest release or resolving its artifact
+    /// are recorded in [`DiagnosticsReport::errors`] instead of aborting, so
+    /// the report is as complete as possible. An error is only returned when
+    /// the platform itself cannot be determined.
+    pub async fn diagnose(&self) -> Result<DiagnosticsReport> {
+        let system_info = match SystemInfo::from_target(&self.target) {
+            Some(system_info) => system_info,
+            None => SystemInfo::current()?,
+        };
+        let mut errors = Vec::new();
+
+        let install_dir = if self.extract_path.is_dir() {
+            Some(self.extract_path.as_path())
+        } else {
+            self.extract_path.parent()
+        };
+        let extract_path_writable = install_dir.is_some_and(crate::utils::is_dir_writable);
+
+        let source_reachable = self.check_internet_connectivity().await;
+
+        let (latest_version, asset_available) = match self.fetch_release().await {
+            Ok(release) => {
+                let asset_available = match release.download_url(&self.target) {
+                    Ok(_) => true,
+                    Err(err) => {
+                        errors.push(err.to_string());
+                        false
+                    }
+                };
+                (Some(release.version), asset_available)
+            }
+            Err(err) => {
+                errors.push(err.to_string());
+                (None, false)
+            }
+        };
+
+        Ok(DiagnosticsReport {
+            app_name: self.app_name.clone(),
+            current_version: self.current_version.clone(),
+            extract_path: self.extract_path.clone(),
+            extract_path_exists: self.extract_path.exists(),
+            extract_path_writable,
+            source_reachable,
+            latest_version,
+            asset_available,
+            system_info,
+            errors,
+        })
+    }
+
+    /// Convenience helper that checks for an update and downloads/installs it when present.
+    ///
+    /// Returns `Ok(true)` when an update was found and installed, or `Ok(false)`
+    /// when the current version is already up to date. With
+    /// [`UpdaterBuilder::skip_on_offline`] enabled, `Ok(false)` is also returned
+    /// when the update server is unreachable.
+    pub async fn update<C: FnMut(usize)>(&self, on_chunk: C) -> Result<bool> {
+        self.update_with_lock(None, on_chunk).await
+    }
+
+    /// Runs [`Self::update`] while holding an exclusive lock shared across processes.
+    ///
+    /// The lock from [`Self::acquire_lock`] is taken before checking, so a
+    /// main app and its helpers never install concurrently. When another
+    /// process holds the lock this returns `Ok(false)` immediately. The lock
+    /// is released once the update finishes or fails.
+    pub async fn check_and_update_atomic<C: FnMut(usize)>(&self, on_chunk: C) -> Result<bool> {
+        let lock = match self.acquire_lock() {
+            Ok(lock) => lock,
+            Err(Error::LockAlreadyHeld) => return Ok(false),
+            Err(error) => return Err(error),
+        };
+        self.update_with_lock(Some(&lock), on_chunk).await
+    }
+
+    /// Runs [`Self::update`], installing without relocking when `lock` is already held.
+    async fn update_with_lock<C: FnMut(usize)>(
+        &self,
+        lock: Option<&UpdaterLock>,
+        on_chunk: C,
+    ) -> Result<bool> {
+        if self.skip_on_offline && !self.network_connectivity_check().await {
+            return Ok(false);
+        }
+
+        let Some(update) = self.check().await? else {
+            return Ok(false);
+        };
+        match lock {
+            Some(_) => self.install_update(&update, on_chunk).await?,
+            None => self.download_and_install(&update, on_chunk).await?,
+        }
+        Ok(true)
+    }
+
+    /// Runs a never-ending check/install loop for supervised self-updating daemons.
+    ///
+    /// Each iteration sleeps for `interval`, checks for an update, and when one
+    /// is found downloads and installs it before calling `on_update` (for
+    /// example to notify a systemd watchdog). Iterations are skipped while
+    /// [`Self::check_internet_connectivity`] reports the server unreachable.
+    /// Failed checks and installs are passed to `on_error` and the loop
+    /// continues. Requires a Tokio runtime with the timer enabled.
+    pub async fn auto_update_loop<F, E>(
+        self,
+        interval: Duration,
+        on_update: F,
+        on_error: E,
+    ) -> Infallible
+    where
+        F: Fn() + Send + Sync + 'static,
+        E: Fn(Error) + Send + Sync + 'static,
+    {
+        loop {
+            tokio::time::sleep(interval).await;
+            self.auto_update_once(&on_update, &on_error).await;
+        }
+    }
+
+    /// Runs [`Self::auto_update_loop`] until `token` is cancelled.
+    ///
+    /// Cancellation interrupts the sleep or an in-flight check, download, or
+    /// install future; an install that already started on a platform backend
+    /// runs to completion.
+    pub async fn auto_update_loop_cancellable<F, E>(
+        self,
+        interval: Duration,
+        on_update: F,
+        on_error: E,
+        token: CancellationToken,
+    ) where
+        F: Fn() + Send + Sync + 'static,
+        E: Fn(Error) + Send + Sync + 'static,
+    {
+        while token
+            .run_until_cancelled(tokio::time::sleep(interval))
+            .await
+            .is_some()
+        {
+            if token
+                .run_until_cancelled(self.auto_update_once(&on_update, &on_error))
+                .await
+                .is_none()
+            {
+                break;
+            }
+        }
+    }
+
+    /// Spawns a task that runs [`Self::check`] once and passes a found update to `on_update`.
+    ///
+    /// The handle resolves to the result of the check, after `on_update` has
+    /// completed. Must be called from within a Tokio runtime.
+    pub fn check_with_callback<F, Fut>(self, on_update: F) -> JoinHandle<Result<()>>
+    where
+        F: Fn(Update) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send,
+    {
+        tokio::spawn(async move {
+            if let Some(update) = self.check().await? {
+                on_update(update).await;
+            }
+            Ok(())
+        })
+    }
+
+    /// Spawns a task that checks for updates every [`Self::background_poll_interval`] without installing them.
+    ///
+    /// The first check runs immediately. Each update found is published on the
+    /// returned [`watch::Receiver`], which starts out as `None`; failed checks
+    /// are passed to `on_error` and retried on the next tick. Checks use the
+    /// updater's configured timeout, proxy and headers. The task stops once
+    /// every receiver is dropped, or when the handle is aborted. A zero
+    /// interval is raised to one second. Must be called from within a Tokio
+    /// runtime.
+    pub fn start_background_check<E>(
+        self,
+        on_error: E,
+    ) -> (JoinHandle<()>, watch::Receiver<Option<Update>>)
+    where
+        E: Fn(Error) + Send + 'static,
+    {
+        let interval = self
+            .background_poll_interval
+            .max(MIN_BACKGROUND_POLL_INTERVAL);
+        let (sender, receiver) = watch::channel(None);
+        let handle = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                match self.check().await {
+                    Ok(Some(update)) => {
+                        if sender.send(Some(update)).is_err() {
+                            break;
+                        }
+                    }
+                    Ok(None) => {}
+                    Err(error) => on_error(error),
+                }
+                if sender.is_closed() {
+                    break;
+                }
+            }
+        });
+        (handle, receiver)
+    }
+
+    async fn auto_update_once<F: Fn(), E: Fn(Error)>(&self, on_update: &F, on_error: &E) {
+        if !self.check_internet_connectivity().await {
+            return;
+        }
+
+        match self.check().await {
+            Ok(Some(update)) => match self.download_and_install(&update, |_| {}).await {
+                Ok(()) => on_update(),
+                Err(error) => on_error(error),
+            },
+            Ok(None) => {}
+            Err(error) => on_error(error),
+        }
+    }
+
+    /// Downloads the updater package for an [`Update`] and returns it as bytes.
+    ///
+    /// The chunk callback receives the length of each chunk as it arrives.
+    #[deprecated(note = "use `download_with_progress`, which also reports the total size")]
+    pub async fn download<C: FnMut(usize)>(
+        &self,
+        update: &Update,
+        mut on_chunk: C,
+    ) -> Result<Vec<u8>> {
+        self.download_with_progress(update, |chunk, _| on_chunk(chunk))
+            .await
+    }
+
+    /// Downloads the updater package for an [`Update`] and returns it as bytes.
+    ///
+    /// The callback receives the length of each chunk together with the
+    /// expected total size from the `Content-Length` header, if known.
+    /// When the download fails, the download mirrors of [`Self::mirror_urls`]
+    /// are tried in order. If those fail with a
+    /// transport error too and [`UpdaterBuilder::fallback_download_url`] is
+    /// configured, it is retried once from the fallback URL. Signature
+    /// verification applies to every source.
+    pub async fn download_with_progress<C: FnMut(usize, Option<u64>)>(
+        &self,
+        update: &Update,
+        on_chunk: C,
+    ) -> Result<Vec<u8>> {
+        let bytes = self
+            .download_with_fallback(update, on_chunk)
+            .await
+            .map_err(signature_error)?;
+        self.verify_download(update, sha256_hex(&bytes)).await?;
+        Ok(bytes)
+    }
+
+    /// Like [`Self::download_with_progress`], but stops when `cancel` is cancelled.
+    ///
+    /// Cancellation interrupts the download, including retries and mirror
+    /// fallbacks, drops the bytes received so far and returns
+    /// [`Error::Cancelled`].
+    pub async fn download_cancellable<C: FnMut(usize, Option<u64>)>(
+        &self,
+        update: &Update,
+        on_chunk: C,
+        cancel: CancellationToken,
+    ) -> Result<Vec<u8>> {
+        cancel
+            .run_until_cancelled(self.download_with_progress(update, on_chunk))
+            .await
+            .unwrap_or(Err(Error::Cancelled))
+    }
+
+    async fn download_with_fallback<C: FnMut(usize, Option<u64>)>(
+        &self,
+        update: &Update,
+        on_chunk: C,
+    ) -> Result<Vec<u8>> {
+        let mut progress = AttemptProgress::new(on_chunk);
+        let mut result = self.download_with_retries(update, &mut progress).await;
+        for mirror in self
+            .mirror_urls
+            .iter()
+            .filter(|url| is_download_mirror(url))
+        {
+            match &result {
+                Err(error) if should_use_mirror(error) => {}
+                _ => break,
+            }
+            let mut mirrored = retarget_download(update, mirror_download_url(mirror, update)?);
+            strip_credentials(&mut mirrored.headers);
+            result = self.download_with_retries(&mirrored, &mut progress).await;
+        }
+        let result = match (result, &self.fallback_download_url) {
+            (Err(error), Some(fallback_url)) if should_use_fallback(&error) => {
+                let fallback = retarget_download(update, fallback_url.clone());
+                self.download_with_retries(&fallback, &mut progress).await
+            }
+            (result, _) => result,
+        };
+        result.map_err(download_status_error)
+    }
+
+    /// Downloads `update`, retrying transient failures with exponential backoff.
+    async fn download_with_retries<C: FnMut(usize, Option<u64>)>(
+        &self,
+        update: &Update,
+        progress: &mut AttemptProgress<C>,
+    ) -> Result<Vec<u8>> {
+        let mut attempt = 0;
+        loop {
+            match update.download_attempt(progress.attempt()).await {
+                Err(error) if self.max_retries > 0 && is_transient(&error) => {
+                    if attempt == self.max_retries {
+                        return Err(Error::MaxRetriesExceeded(self.max_retries));
+                    }
+                    let delay = self
+                        .retry_base_delay
+                        .saturating_mul(2u32.saturating_pow(attempt))
+                        .min(MAX_RETRY_DELAY);
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Downloads the updater package for an [`Update`] into `partial`, resuming
+    /// an interrupted download.
+    ///
+    /// See [`Update::download_resume`]. Checksum verification matches
+    /// [`Self::download_with_progress`].
+    pub async fn download_resume<C: FnMut(usize, Option<u64>)>(
+        &self,
+        update: &Update,
+        partial: &Path,
+        on_chunk: C,
+    ) -> Result<Vec<u8>> {
+        let bytes = update
+            .download_resume(partial, on_chunk)
+            .await
+            .map_err(signature_error)?;
+        self.verify_download(update, sha256_hex(&bytes)).await?;
+        Ok(bytes)
+    }
+
+    /// Downloads the updater package for an [`Update`] into a temporary file.
+    ///
+    /// Chunks are written to disk as they arrive instead of being buffered in
+    /// memory, which keeps peak memory low for large artifacts. Signature and
+    /// checksum verification match [`Self::download_with_progress`], and the
+    /// file name ends with the artifact name. The file is deleted when the
+    /// returned handle is dropped; install it with [`Self::install_from_file`].
+    pub async fn download_to_file<C: FnMut(usize, Option<u64>)>(
+        &self,
+        update: &Update,
+        on_chunk: C,
+    ) -> Result<NamedTempFile> {
+        let (file, digest) = update
+            .download_to_file(on_chunk)
+            .await
+            .map_err(signature_error)?;
+        self.verify_download(update, digest).await?;
+        Ok(file)
+    }
+
+    /// Downloads the updater package for an [`Update`] and returns it with the redirect chain.
+    ///
+    /// See [`Update::download_with_redirect_history`]. Checksum verification
+    /// matches [`Self::download_with_progress`].
+    pub async fn download_with_redirect_history<C: FnMut(usize)>(
+        &self,
+        update: &Update,
+        on_chunk: C,
+    ) -> Result<(Vec<u8>, Vec<Url>)> {
+        let (bytes, history) = update
+            .download_with_redirect_history(on_chunk)
+            .await
+            .map_err(signature_error)?;
+        self.verify_download(update, sha256_hex(&bytes)).await?;
+        Ok((bytes, history))
+    }
+
+    /// Downloads the updater package for an [`Update`] using concurrent range requests.
+    ///
+    /// See [`Update::download_with_multipart_acceleration`]. Checksum
+    /// verification matches [`Self::download_with_progress`].
+    pub async fn download_with_multipart_acceleration<C: FnMut(usize)>(
+        &self,
+        update: &Update,
+        parts: u8,
+        on_chunk: C,
+    ) -> Result<Vec<u8>> {
+        let bytes = update
+            .download_with_multipart_acceleration(parts, on_chunk)
+            .await
+            .map_err(signature_error)?;
+        self.verify_download(update, sha256_hex(&bytes)).await?;
+        Ok(bytes)
+    }
+
+    /// Runs the checksum checks shared by every download path and records the
+    /// artifact with SHA-256 `digest` as verified once they pass.
+    ///
+    /// The signature has already been checked by then. The digest is compared
+    /// with [`Self::checksum_sha256`], the digest published by the release
+    /// manifest and, with [`UpdaterBuilder::verify_checksum`], the release's
+    /// checksum sidecar, so [`Self::install`] only accepts artifacts that
+    /// passed all of them.
+    async fn verify_download(&self, update: &Update, digest: String) -> Result<()> {
+        self.verify_checksum_digest(update, &digest)?;
+        if self.verify_checksum {
+            let expected = self.fetch_checksum_sidecar(update).await?;
+            check_digest(&digest, &expected)?;
+        }
+        self.mark_verified_digest(digest);
+        Ok(())
+    }
+
+    /// Records `bytes` as having passed signature verification.
+    #[cfg(feature = "delta")]
+    pub(crate) fn mark_verified(&self, bytes: &[u8]) {
+        self.mark_verified_digest(sha256_hex(bytes));
+    }
+
+    /// Records the artifact with SHA-256 `digest` as having passed signature verification.
+    fn mark_verified_digest(&self, digest: String) {
+        if let Ok(mut verified) = self.verified_digest.lock() {
+            *verified = Some(digest);
+        }
+    }
+
+    /// Fails unless no public key is configured or `bytes` passed signature verification.
+    pub(crate) fn ensure_verified(&self, bytes: &[u8]) -> Result<()> {
+        if self.config.pubkey.is_empty() {
+            return Ok(());
+        }
+        self.ensure_verified_digest(&sha256_hex(bytes))
+    }
+
+    /// Fails unless the artifact with SHA-256 `digest` passed signature verification.
+    fn ensure_verified_digest(&self, digest: &str) -> Result<()> {
+        let verified = self
+            .verified_digest
+            .lock()
+            .ok()
+            .and_then(|verified| verified.clone());
+        if verified.as_deref() == Some(digest) {
+            Ok(())
+        } else {
+            Err(Error::SignatureVerificationFailed)
+        }
+    }
+
+    /// Compares the SHA-256 digest of `bytes` with [`Self::checksum_sha256`]
+    /// and with the digest the release manifest published for `update`, if any.
+    pub(crate) fn verify_checksum(&self, update: &Update, bytes: &[u8]) -> Result<()> {
+        self.verify_checksum_digest(update, &sha256_hex(bytes))
+    }
+
+    /// Like [`Self::verify_checksum`], for an artifact whose digest is already known.
+    fn verify_checksum_digest(&self, update: &Update, actual: &str) -> Result<()> {
+        if let Some(expected) = &self.checksum_sha256 {
+            check_digest(actual, expected)?;
+        }
+        if let Some(expected) = self.manifest_sha256(update) {
+            check_digest(actual, &expected)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the SHA-256 digest the last checked release published for `update`'s artifact.
+    fn manifest_sha256(&self, update: &Update) -> Option<String> {
+        let release = self.latest_release.lock().ok()?;
+        let release = release.as_ref()?;
+        if release.download_url(&update.target).ok()? != &update.download_url {
+            return None;
+        }
+        release
+            .sha256(&update.target)
+            .map(|digest| digest.trim().to_ascii_lowercase())
+    }
+
+    /// Fetches the `.sha256sum` or `.sha256` sidecar of `update`'s artifact and
+    /// returns its digest.
+    async fn fetch_checksum_sidecar(&self, update: &Update) -> Result<String> {
+        let release = self.observed_release().await?;
+        let artifact_name = release
+            .assets
+            .iter()
+            .find(|asset| asset.url == update.download_url)
+            .map_or(update.artifact_name(), |asset| asset.name.as_str());
+        let sidecar = [".sha256sum", ".sha256"]
+            .iter()
+            .find_map(|suffix| release.find_asset_by_name(&format!("{artifact_name}{suffix}")))
+            .ok_or(Error::ChecksumSidecarNotFound)?;
+
+        let contents = self.fetch_release_asset(&release, sidecar, |_| {}).await?;
+        // GNU coreutils format: `<hex digest>  <file name>`.
+        String::from_utf8_lossy(&contents)
+            .split_whitespace()
+            .next()
+            .map(str::to_ascii_lowercase)
+            .ok_or(Error::InvalidUpdaterFormat)
+    }
+
+    /// Downloads a supplementary asset, such as a portable build, from the latest release.
+    ///
+    /// The release observed by the last [`Self::check`] is used, or fetched
+    /// when none has been observed yet. The asset is looked up with
+    /// [`crate::RemoteRelease::find_asset_by_name`] and downloaded with the
+    /// same headers, proxy, timeout, and TLS settings as the installer, and
+    /// `on_chunk` receives the length of each chunk as it arrives.
+    /// Supplementary assets have no detached signature, so the bytes are not
+    /// verified. Returns [`Error::AssetNotFound`] when the release has no
+    /// asset with that name.
+    pub async fn download_asset_by_name<C: FnMut(usize)>(
+        &self,
+        name: &str,
+        on_chunk: C,
+    ) -> Result<Vec<u8>> {
+        let release = self.observed_release().await?;
+        let asset = release
+            .find_asset_by_name(name)
+            .ok_or(Error::AssetNotFound)?;
+        self.fetch_release_asset(&release, asset, on_chunk).await
+    }
+
+    /// Returns the release observed by the last check, fetching it when none was observed.
+    async fn observed_release(&self) -> Result<RemoteRelease> {
+        let cached = self
+            .latest_release
+            .lock()
+            .ok()
+            .and_then(|release| release.clone());
+        match cached {
+            Some(release) => Ok(release),
+            None => self.fetch_release().await,
+        }
+    }
+
+    /// Streams `asset`, reporting the length of each chunk to `on_chunk` as it arrives.
+    async fn fetch_release_asset<C: FnMut(usize)>(
+        &self,
+        release: &RemoteRelease,
+        asset: &ReleaseAsset,
+        mut on_chunk: C,
+    ) -> Result<Vec<u8>> {
+        if let (Some(limit), Some(actual)) = (self.max_asset_size, asset.size)
+            && actual > limit
+        {
+            return Err(Error::AssetTooLarge { limit, actual });
+        }
+
+        let mut headers = release.download_headers.clone();
+        headers.extend(self.headers.clone());
+        if !headers.contains_key(ACCEPT) {
+            headers.insert(ACCEPT, HeaderValue::from_static("application/octet-stream"));
+        }
+        let response = self
+            .client_builder()?
+            .build()?
+            .get(asset.url.clone())
+            .headers(headers)
+            .send()
+            .await?;
+        if !response.status().is_success() {
+            return Err(Error::Network(format!(
+                "Download request failed with status: {}",
+                response.status()
+            )));
+        }
+
+        let mut bytes = Vec::new();
+        let mut chunks = response.bytes_stream();
+        while let Some(chunk) = chunks.next().await {
+            let chunk = chunk?;
+            if let Some(limit) = self.max_asset_size {
+                let actual = (bytes.len() + chunk.len()) as u64;
+                if actual > limit {
+                    return Err(Error::AssetTooLarge { limit, actual });
+                }
+            }
+            bytes.extend_from_slice(&chunk);
+            on_chunk(chunk.len());
+        }
+        Ok(bytes)
+    }
+
+    /// Downloads and verifies `update` into `dest_dir` for a separate install step.
+    ///
+    /// The artifact is written to `{dest_dir}/{artifact_name}` rather than a
+    /// temporary file, so deployment pipelines can inspect or archive it. The
+    /// directory is created when missing. When the staged file's size differs
+    /// from [`Update::asset_size`], it is removed and the call fails with
+    /// [`Error::DownloadSizeMismatch`].
+    pub async fn download_and_stage<C: FnMut(usize)>(
+        &self,
+        update: &Update,
+        mut on_chunk: C,
+        dest_dir: &Path,
+    ) -> Result<StagedUpdate> {
+        let bytes = self
+            .download_with_progress(update, |chunk, _| on_chunk(chunk))
+            .await?;
+        fs::create_dir_all(dest_dir)?;
+        let path = dest_dir.join(update.artifact_name());
+        fs::write(&path, &bytes)?;
+
+        let written = fs::metadata(&path)?.len();
+        if let Err(error) = update.check_download_size(written) {
+            let _ = fs::remove_file(&path);
+            return Err(error);
+        }
+
+        Ok(StagedUpdate {
+            path,
+            update: update.clone(),
+        })
+    }
+
+    /// Installs artifact bytes previously returned by [`Updater::download_with_progress`].
+    ///
+    /// When a public key is configured, bytes that did not pass signature
+    /// verification in this updater are refused with
+    /// [`Error::SignatureVerificationFailed`]. The
+    /// [`UpdaterBuilder::before_install`] and [`UpdaterBuilder::after_install`]
+    /// hooks run around the platform install.
+    pub fn install(&self, bytes: impl AsRef<[u8]>) -> Result<()> {
+        self.ensure_verified(bytes.as_ref())?;
+        self.run_before_install(bytes.as_ref())?;
+        self.install_inner(bytes.as_ref())?;
+        self.run_after_install();
+        Ok(())
+    }
+
+    /// Reads an artifact staged on disk and installs it with [`Self::install`].
+    ///
+    /// Artifacts staged by another process are refused when a public key is
+    /// configured; install them with [`StagedUpdate::install`], which
+    /// re-verifies the signature.
+    pub fn install_from_path(&self, path: &Path) -> Result<()> {
+        self.install(fs::read(path)?)
+    }
+
+    /// Installs an artifact written by [`Self::download_to_file`].
+    ///
+    /// Unlike [`Self::install_from_path`], the file is hashed and, on Linux,
+    /// copied into place in chunks rather than read into memory. The
+    /// installer is picked from the file name like [`InstallerKind::from_path`],
+    /// so packages and archives are installed rather than copied over the
+    /// executable. Files that did not pass signature verification are refused
+    /// when a public key is configured.
+    pub fn install_from_file(&self, file: &Path) -> Result<()> {
+        if !self.config.pubkey.is_empty() {
+            self.ensure_verified_digest(&sha256_file(file)?)?;
+        }
+        self.run_before_install_file(file)?;
+        self.install_file_inner(file)?;
+        self.run_after_install();
+        Ok(())
+    }
+
+    /// Reports which files installing `bytes` would create or replace, without writing anything.
+    ///
+    /// On macOS the ZIP entries are mapped into the app bundle at
+    /// [`Self::extract_path`]. On Windows, portable ZIPs are mapped into the
+    /// install directory, while installer executables report no files since
+    /// they run out of process and are always elevated. On Linux the artifact
+    /// replaces the executable in place, like an AppImage. Elevation is
+    /// required when the destination directory is not writable.
+    pub fn simulate_install(&self, bytes: &[u8]) -> Result<InstallSimulation> {
+        self.simulate_install_inner(bytes)
+    }
+
+    /// Installs artifact bytes while reporting coarse progress events.
+    ///
+    /// `.msi` packages are installed in process with Windows Installer's
+    /// passive progress UI and also report [`InstallProgress::Installing`]
+    /// percentages and [`InstallProgress::Finishing`]. Other backends report
+    /// only [`InstallProgress::Starting`] and [`InstallProgress::Done`]; `.exe`
+    /// installers run out of process, and on success the process exits before
+    /// `Done` is delivered. The callback is `Send` so it can forward events to
+    /// another thread.
+    pub fn install_with_progress<F>(
+        &self,
+        bytes: impl AsRef<[u8]>,
+        mut on_progress: F,
+    ) -> Result<()>
+    where
+        F: FnMut(InstallProgress) + Send,
+    {
+        self.ensure_verified(bytes.as_ref())?;
+        self.run_before_install(bytes.as_ref())?;
+        on_progress(InstallProgress::Starting);
+        self.install_inner_with_progress(bytes.as_ref(), &mut on_progress)?;
+        self.run_after_install();
+        on_progress(InstallProgress::Done);
+        Ok(())
+    }
+
+    /// Runs the [`UpdaterBuilder::before_install`] hook on `bytes`.
+    pub(crate) fn run_before_install(&self, bytes: &[u8]) -> Result<()> {
+        match &self.before_install {
+            Some(hook) => hook(bytes),
+            None => Ok(()),
+        }
+    }
+
+    /// Runs the [`UpdaterBuilder::before_install_file`] hook on the artifact stored at `path`.
+    ///
+    /// Without one, the [`UpdaterBuilder::before_install`] hook runs on the
+    /// file read into memory.
+    fn run_before_install_file(&self, path: &Path) -> Result<()> {
+        match (&self.before_install_file, &self.before_install) {
+            (Some(hook), _) => hook(path),
+            (None, Some(hook)) => hook(&fs::read(path)?),
+            (None, None) => Ok(()),
+        }
+    }
+
+    /// Runs the [`UpdaterBuilder::after_install`] hook.
+    pub(crate) fn run_after_install(&self) {
+        if let Some(hook) = &self.after_install {
+            hook(&self.extract_path);
+        }
+    }
+
+    /// Returns the IDs of other running processes whose executable lives inside
+    /// [`Self::extract_path`].
+    ///
+    /// Only implemented on Windows, where running instances block installers.
+    pub fn detect_running_processes(&self) -> Result<Vec<u32>> {
+        running_process_ids(&self.extract_path)
+    }
+
+    /// Terminates the given processes.
+    ///
+    /// Returns [`Error::ProcessTerminationFailed`] for the first process that
+    /// could not be terminated. Only implemented on Windows.
+    pub fn terminate_running_processes(&self, pids: &[u32]) -> Result<()> {
+        pids.iter().try_for_each(|pid| terminate_process(*pid))
+    }
+
+    /// Relaunches the application using the current platform backend.
+    ///
+    /// Relaunch support is implemented on macOS, Windows, and Linux, where the
+    /// installed executable is re-executed with the current arguments. When
+    /// [`UpdaterBuilder::relaunch_as_admin`] is enabled this delegates to
+    /// [`Self::relaunch_as_admin`].
+    pub fn relaunch(&self) -> Result<()> {
+        if self.as_admin {
+            self.relaunch_as_admin()
+        } else {
+            self.relaunch_inner()
+        }
+    }
+
+    /// Relaunches the application with administrator privileges.
+    ///
+    /// Windows launches the staged installer, or the current executable for
+    /// portable installs, with the `runas` verb, or with `open` when the
+    /// process is already elevated so no redundant UAC prompt appears. macOS
+    /// reopens the app bundle through an AppleScript `with administrator
+    /// privileges` block. Linux restarts the current executable through
+    /// `pkexec`, falling back to `sudo -A`.
+    pub fn relaunch_as_admin(&self) -> Result<()> {
+        self.relaunch_as_admin_inner()
+    }
+
+    /// Checks that the install volume has room to download and install `update`.
+    ///
+    /// The volume holding [`Update::extract_path`] must have
+    /// [`Self::disk_space_multiplier`] times [`Update::asset_size`] bytes free,
+    /// and the volume of [`Self::temp_dir`], where large downloads are staged,
+    /// must fit the artifact itself. Passes when the release source does not
+    /// list the artifact size.
+    ///
+    /// Returns [`Error::InsufficientDiskSpace`] when less space is available.
+    pub fn check_disk_space(&self, update: &Update) -> Result<()> {
+        match update.asset_size {
+            Some(size) => self.ensure_disk_space(update, size),
+            None => Ok(()),
+        }
+    }
+
+    fn ensure_disk_space(&self, update: &Update, asset_size: u64) -> Result<()> {
+        let required = (asset_size as f64 * f64::from(self.disk_space_multiplier)).ceil() as u64;
+        let temp_dir = self.temp_dir.clone().unwrap_or_else(std::env::temp_dir);
+        for (path, required) in [(&update.extract_path, required), (&temp_dir, asset_size)] {
+            let available = crate::utils::available_disk_space(path)?;
+            if available < required {
+                return Err(Error::InsufficientDiskSpace {
+                    required,
+                    available,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Convenience helper that downloads and installs a specific [`Update`].
+    ///
+    /// Before downloading, the install and temp volumes are checked like
+    /// [`Self::check_disk_space`], using the size listed by the release source
+    /// or else the `Content-Length` of a `HEAD` request, and
+    /// [`Error::InsufficientDiskSpace`] is returned when less is free.
+    ///
+    /// The [`UpdaterBuilder::before_install`] and
+    /// [`UpdaterBuilder::after_install`] hooks run around the install.
+    ///
+    /// When [`UpdaterBuilder::post_download_script`] is configured, the
+    /// verified artifact is written to a temporary file and the script runs
+    /// with its path as the first argument before installation. A non-zero
+    /// exit aborts with [`Error::PostDownloadScriptFailed`].
+    ///
+    /// The lock from [`Self::acquire_lock`] is held until this returns, and
+    /// [`Error::LockAlreadyHeld`] is returned without downloading when
+    /// another process is already installing.
+    pub async fn download_and_install<C: FnMut(usize)>(
+        &self,
+        update: &Update,
+        on_chunk: C,
+    ) -> Result<()> {
+        let _lock = self.acquire_lock()?;
+        self.install_update(update, on_chunk).await
+    }
+
+    /// Downloads and installs `update` without taking the install lock.
+    async fn install_update<C: FnMut(usize)>(
+        &self,
+        update: &Update,
+        mut on_chunk: C,
+    ) -> Result<()> {
+        self.check_disk_space(update)?;
+        let remote_size = update.remote_size().await;
+        if let (None, Some(size)) = (update.asset_size, remote_size) {
+            self.ensure_disk_space(update, size)?;
+        }
+        if remote_size.is_some_and(|size| size > self.stream_to_disk_threshold) {
+            let file = self
+                .download_to_file(update, |chunk, _| on_chunk(chunk))
+                .await?;
+            if let Some(script_path) = &self.post_download_script {
+                run_post_download_script(script_path, file.path())?;
+            }
+            self.run_before_install_file(file.path())?;
+            update.install_from_file(file.path())?;
+            self.run_after_install();
+            return Ok(());
+        }
+
+        let bytes = self
+            .download_with_progress(update, |chunk, _| on_chunk(chunk))
+            .await?;
+        if let Some(script_path) = &self.post_download_script {
+            let staging_dir =
+                crate::utils::temp_dir_in("release-hub-post-download-", self.temp_dir.as_deref())?;
+            let artifact_path = staging_dir.path().join(update.artifact_name());
+            fs::write(&artifact_path, &bytes)?;
+            run_post_download_script(script_path, &artifact_path)?;
+        }
+        self.run_before_install(&bytes)?;
+        update.install(&bytes)?;
+        self.run_after_install();
+        Ok(())
+    }
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+/// Returns whether a failed download is worth retrying from the same URL.
+fn is_transient(error: &Error) -> bool {
+    match error {
+        Error::Io(_) => true,
+        Error::Reqwest(error) => match error.status() {
+            Some(status) => {
+                status == StatusCode::TOO_MANY_REQUESTS || (500..=503).contains(&status.as_u16())
+            }
+            None => !error.is_builder() && !error.is_redirect(),
+        },
+        _ => false,
+    }
+}
+
+/// Returns whether a failed download should be retried from the fallback URL.
+fn should_use_fallback(error: &Error) -> bool {
+    match error {
+        Error::Reqwest(error) => !error
+            .status()
+            .is_some_and(|status| status.is_client_error()),
+        Error::MaxRetriesExceeded(_) => true,
+        _ => false,
+    }
+}
+
+/// Returns whether a failed download should be retried from the next mirror.
+fn should_use_mirror(error: &Error) -> bool {
+    matches!(
+        error,
+        Error::Reqwest(_) | Error::Io(_) | Error::Network(_) | Error::MaxRetriesExceeded(_)
+    )
+}
+
+/// Returns the path recording the validator of the download resumed into `partial`.
+fn resume_validator_path(partial: &Path) -> PathBuf {
+    let mut path = partial.as_os_str().to_owned();
+    path.push(".validator");
+    PathBuf::from(path)
+}
+
+/// Parses `Content-Range: bytes <start>-<end>/<total>` into its start and total.
+///
+/// Either part is `None` when the server sent `*` in its place.
+fn content_range(headers: &HeaderMap) -> Option<(Option<u64>, Option<u64>)> {
+    let value = headers.get(CONTENT_RANGE)?.to_str().ok()?;
+    let (range, total) = value.strip_prefix("bytes ")?.split_once('/')?;
+    let start = range
+        .split_once('-')
+        .and_then(|(start, _)| start.parse().ok());
+    Some((start, total.parse().ok()))
+}
+
+/// Returns whether `url` is a download mirror template rather than a manifest mirror.
+fn is_download_mirror(url: &Url) -> bool {
+    url.as_str().contains(MIRROR_FILENAME_PLACEHOLDER) || url.as_str().contains("%7Bfilename%7D")
+}
+
+/// Expands the `{version}` and `{filename}` placeholders of a mirror template for `update`.
+///
+/// [`Url`] percent-encodes the braces in paths, so both spellings are replaced.
+fn mirror_download_url(template: &Url, update: &Update) -> Result<Url> {
+    let version = update.version.to_string();
+    let filename = update.artifact_name();
+    let expanded = template
+        .as_str()
+        .replace("{version}", &version)
+        .replace("%7Bversion%7D", &version)
+        .replace(MIRROR_FILENAME_PLACEHOLDER, filename)
+        .replace("%7Bfilename%7D", filename);
+    Url::parse(&expanded).map_err(|_| Error::InvalidMirrorTemplate(template.to_string()))
+}
+
+/// Headers carrying credentials that must not leave the origin they were configured for.
+const CREDENTIAL_HEADERS: [&str; 4] = ["authorization", "cookie", "private-token", "job-token"];
+
+/// Removes [`CREDENTIAL_HEADERS`] from `headers`.
+fn strip_credentials(headers: &mut HeaderMap) {
+    for name in CREDENTIAL_HEADERS {
+        headers.remove(name);
+    }
+}
+
+/// Points `update` at `url`, dropping credential headers when `url` is on another origin.
+fn retarget_download(update: &Update, url: Url) -> Update {
+    let mut retargeted = update.clone();
+    if url.origin() != update.download_url.origin() {
+        strip_credentials(&mut retargeted.headers);
+    }
+    retargeted.download_url = url;
+    retargeted
+}
+
+/// Forwards download progress across attempts without counting a byte twice.
+///
+/// Each retry, mirror or fallback attempt starts from zero; chunks are only
+/// reported once an attempt has received more bytes than any earlier one, so
+/// the sum of reported chunk lengths never exceeds the artifact size.
+struct AttemptProgress<C> {
+    on_chunk: C,
+    reported: u64,
+}
+
+impl<C: FnMut(usize, Option<u64>)> AttemptProgress<C> {
+    fn new(on_chunk: C) -> Self {
+        Self {
+            on_chunk,
+            reported: 0,
+        }
+    }
+
+    /// Returns the chunk callback for a new attempt.
+    fn attempt(&mut self) -> impl FnMut(usize, Option<u64>) + '_ {
+        let mut received = 0u64;
+        move |chunk, total| {
+            received += chunk as u64;
+            if received > self.reported {
+                let fresh = received - self.reported;
+                self.reported = received;
+                (self.on_chunk)(fresh as usize, total);
+            }
+        }
+    }
+}
+
+/// Reports HTTP error statuses of artifact downloads as [`Error::Network`].
+fn download_status_error(error: Error) -> Error {
+    let status = match &error {
+        Error::Reqwest(error) => error.status(),
+        _ => None,
+    };
+    match status {
+        Some(status) => Error::Network(format!("Download request failed with status: {status}")),
+        None => error,
+    }
+}
+
+/// Reports Minisign failures on downloaded artifacts as [`Error::SignatureVerificationFailed`].
+fn signature_error(error: Error) -> Error {
+    match error {
+        Error::Minisign(_) => Error::SignatureVerificationFailed,
+        error => error,
+    }
+}
+
+fn check_digest(actual: &str, expected: &str) -> Result<()> {
+    if expected == actual {
+        Ok(())
+    } else {
+        Err(Error::ChecksumMismatch {
+            expected: expected.to_owned(),
+            actual: actual.to_owned(),
+        })
+    }
+}
+
+/// Computes the hex SHA-256 digest of the file at `path` without reading it into memory.
+fn sha256_file(path: &Path) -> Result<String> {
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut fs::File::open(path)?, &mut hasher)?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Reads a skipped versions file, treating a missing file as empty and ignoring invalid lines.
+fn read_skipped_versions(path: &Path) -> Result<Vec<Version>> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(contents
+            .lines()
+            .filter_map(|line| Version::parse(line.trim()).ok())
+            .collect()),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(error) => Err(error.into()),
+    }
+}
+
+fn run_post_download_script(script_path: &Path, artifact_path: &Path) -> Result<()> {
+    let status = std::process::Command::new(script_path)
+        .arg(artifact_path)
+        .status()?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(Error::PostDownloadScriptFailed(status.code().unwrap_or(-1)))
+    }
+}
+
+/// Transport settings shared by the updater and the updates it produces.
+struct ClientSettings<'a> {
+    accept_invalid_certs: bool,
+    accept_invalid_hostnames: bool,
+    connect_timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
+    local_address: Option<IpAddr>,
+    no_proxy: bool,
+    proxy: Option<&'a Url>,
+    root_certificates: &'a [String],
+    pinned_certificates: &'a [Vec<u8>],
+    pinned_fingerprints: &'a [String],
+}
+
+impl ClientSettings<'_> {
+    fn builder(self) -> Result<ClientBuilder> {
+        let mut request = ClientBuilder::new().user_agent(UPDATER_USER_AGENT);
+        if self.accept_invalid_certs {
+            request = request.danger_accept_invalid_certs(true);
+        }
+        if self.accept_invalid_hostnames {
+            request = request.danger_accept_invalid_hostnames(true);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            request = request.connect_timeout(timeout);
+        }
+        if let Some(timeout) = self.read_timeout {
+            request = request.read_timeout(timeout);
+        }
+        if let Some(local_address) = self.local_address {
+            request = request.local_address(local_address);
+        }
+        if self.no_proxy {
+            request = request.no_proxy();
+        } else if let Some(proxy) = self.proxy {
+            request = request.proxy(reqwest::Proxy::all(proxy.as_str())?);
+        }
+        crate::tls::configure(
+            request,
+            self.root_certificates,
+            self.pinned_certificates,
+            self.pinned_fingerprints,
+        )
+    }
+}
 
 impl Update {
     fn install_action(&self) -> InstallAction {
         match self.installer_kind {
-            InstallerKind::AppTarGz | InstallerKind::AppZip => InstallAction::MacosArchive,
+            InstallerKind::AppTarGz | InstallerKind::AppZip | InstallerKind::Dmg => {
+                InstallAction::MacosArchive
+            }
             InstallerKind::Msi | InstallerKind::Nsis => InstallAction::WindowsExecutableLaunch,
+            InstallerKind::PortableZip => InstallAction::WindowsArchiveExtract,
             InstallerKind::AppImage => InstallAction::LinuxAppImageReplace,
-            InstallerKind::Deb | InstallerKind::Rpm => InstallAction::LinuxPackageCommand,
+            InstallerKind::TarGz | InstallerKind::TarXz => InstallAction::LinuxArchiveExtract,
+            InstallerKind::Deb | InstallerKind::Rpm | InstallerKind::Snap => {
+                InstallAction::LinuxPackageCommand
+            }
         }
     }
 
-    /// Downloads the selected artifact and verifies its detached minisign signature.
+    fn client_builder(&self) -> Result<ClientBuilder> {
+        ClientSettings {
+            accept_invalid_certs: self.dangerous_accept_invalid_certs,
+            accept_invalid_hostnames: self.dangerous_accept_invalid_hostnames,
+            connect_timeout: self.connect_timeout,
+            read_timeout: self.read_timeout,
+            local_address: self.local_address,
+            no_proxy: self.no_proxy,
+            proxy: self.proxy.as_ref(),
+            root_certificates: &self.root_certificates,
+            pinned_certificates: &self.pinned_certificates,
+            pinned_fingerprints: &self.pinned_fingerprints,
+        }
+        .builder()
+    }
+
+    fn check_asset_size(&self, actual: u64) -> Result<()> {
+        match self.max_asset_size {
+            Some(limit) if actual > limit => Err(Error::AssetTooLarge { limit, actual }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the buffer capacity to reserve for a download advertising `content_length`.
     ///
-    /// The chunk callback receives the total number of bytes currently fetched
-    /// for this download operation.
-    pub async fn download<C>(&self, mut on_chunk: C) -> Result<Vec<u8>>
-    where
-        C: FnMut(usize),
-    {
+    /// The server-supplied length is capped by [`Self::max_asset_size`] and
+    /// [`MAX_PREALLOCATION`] so a bogus header cannot force a huge allocation.
+    fn preallocation(&self, content_length: Option<u64>) -> usize {
+        let limit = self
+            .max_asset_size
+            .map_or(MAX_PREALLOCATION, |limit| limit.min(MAX_PREALLOCATION));
+        usize::try_from(content_length.unwrap_or_default().min(limit)).unwrap_or_default()
+    }
+
+    /// Fails with [`Error::DownloadSizeMismatch`] when `actual` differs from [`Self::asset_size`].
+    fn check_download_size(&self, actual: u64) -> Result<()> {
+        match self.asset_size {
+            Some(expected) if actual != expected => {
+                Err(Error::DownloadSizeMismatch { expected, actual })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn download_headers(&self) -> HeaderMap {
         let mut headers = self.headers.clone();
         if !headers.contains_key(ACCEPT) {
             headers.insert(ACCEPT, HeaderValue::from_static("application/octet-stream"));
         }
+        headers
+    }
 
-        let mut request = ClientBuilder::new().user_agent(UPDATER_USER_AGENT);
-        if self.dangerous_accept_invalid_certs {
-            request = request.danger_accept_invalid_certs(true);
-        }
-        if self.dangerous_accept_invalid_hostnames {
-            request = request.danger_accept_invalid_hostnames(true);
+    /// Sends the download request and checks its status and advertised size.
+    ///
+    /// Error statuses are returned as [`Error::Reqwest`] carrying the status.
+    async fn start_download(&self, client: &reqwest::Client) -> Result<reqwest::Response> {
+        let response = client
+            .get(self.download_url.clone())
+            .headers(self.download_headers())
+            .send()
+            .await?
+            .error_for_status()?;
+        if let Some(actual) = response.content_length() {
+            self.check_asset_size(actual)?;
         }
-        if let Some(timeout) = self.timeout {
-            request = request.timeout(timeout);
+        Ok(response)
+    }
+
+    async fn download_with_client<C>(&self, client: reqwest::Client, on_chunk: C) -> Result<Vec<u8>>
+    where
+        C: FnMut(usize, Option<u64>),
+    {
+        self.stream_download(client, on_chunk)
+            .await
+            .map_err(download_status_error)
+    }
+
+    /// Like [`Self::download_with_progress`], but leaves HTTP error statuses as
+    /// [`Error::Reqwest`] so retries can tell transient failures apart.
+    pub(crate) async fn download_attempt<C>(&self, on_chunk: C) -> Result<Vec<u8>>
+    where
+        C: FnMut(usize, Option<u64>),
+    {
+        let client = self.client_builder()?.build()?;
+        self.stream_download(client, on_chunk).await
+    }
+
+    async fn stream_download<C>(&self, client: reqwest::Client, mut on_chunk: C) -> Result<Vec<u8>>
+    where
+        C: FnMut(usize, Option<u64>),
+    {
+        let response = self.start_download(&client).await?;
+        let content_length = response.content_length();
+
+        let mut bytes = Vec::with_capacity(self.preallocation(content_length));
+        let mut chunks = response.bytes_stream();
+        while let Some(chunk) = chunks.next().await {
+            let chunk = chunk?;
+            self.check_asset_size((bytes.len() + chunk.len()) as u64)?;
+            on_chunk(chunk.len(), content_length);
+            bytes.extend_from_slice(&chunk);
         }
-        if self.no_proxy {
-            request = request.no_proxy();
-        } else if let Some(ref proxy) = self.proxy {
-            let proxy = reqwest::Proxy::all(proxy.as_str())?;
-            request = request.proxy(proxy);
+        self.check_download_size(bytes.len() as u64)?;
+        crate::verify_minisign(&bytes, &self.pubkey, &self.signature)?;
+        Ok(bytes)
+    }
+
+    /// Downloads the selected artifact and verifies its detached minisign signature.
+    ///
+    /// The chunk callback receives the length of each chunk as it arrives.
+    #[deprecated(note = "use `download_with_progress`, which also reports the total size")]
+    pub async fn download<C>(&self, mut on_chunk: C) -> Result<Vec<u8>>
+    where
+        C: FnMut(usize),
+    {
+        self.download_with_progress(|chunk, _| on_chunk(chunk))
+            .await
+    }
+
+    /// Downloads the selected artifact and verifies its detached minisign signature.
+    ///
+    /// The callback receives the length of each chunk as it arrives together
+    /// with the expected total size from the `Content-Length` header, or
+    /// `None` when the server does not send one. Fails with
+    /// [`Error::DownloadSizeMismatch`] when [`Self::asset_size`] is known and
+    /// the received size differs, such as when a proxy truncates the response.
+    pub async fn download_with_progress<C>(&self, on_chunk: C) -> Result<Vec<u8>>
+    where
+        C: FnMut(usize, Option<u64>),
+    {
+        let client = self.client_builder()?.build()?;
+        self.download_with_client(client, on_chunk).await
+    }
+
+    /// Like [`Self::download_with_progress`], but stops when `cancel` is cancelled.
+    ///
+    /// Cancellation drops the bytes received so far and returns
+    /// [`Error::Cancelled`].
+    pub async fn download_cancellable<C>(
+        &self,
+        on_chunk: C,
+        cancel: CancellationToken,
+    ) -> Result<Vec<u8>>
+    where
+        C: FnMut(usize, Option<u64>),
+    {
+        cancel
+            .run_until_cancelled(self.download_with_progress(on_chunk))
+            .await
+            .unwrap_or(Err(Error::Cancelled))
+    }
+
+    /// Streams the selected artifact into a temporary file and verifies its signature.
+    ///
+    /// Returns the file together with the hex SHA-256 digest of its contents.
+    pub(crate) async fn download_to_file<C>(
+        &self,
+        mut on_chunk: C,
+    ) -> Result<(NamedTempFile, String)>
+    where
+        C: FnMut(usize, Option<u64>),
+    {
+        let client = self.client_builder()?.build()?;
+        let response = self
+            .start_download(&client)
+            .await
+            .map_err(download_status_error)?;
+        let content_length = response.content_length();
+
+        let suffix = format!("-{}", self.artifact_name());
+        let mut builder = tempfile::Builder::new();
+        builder.prefix("release-hub-download-").suffix(&suffix);
+        let mut file = match &self.temp_dir {
+            Some(temp_dir) => {
+                fs::create_dir_all(temp_dir)?;
+                builder.tempfile_in(temp_dir)?
+            }
+            None => builder.tempfile()?,
+        };
+        let mut hasher = Sha256::new();
+        let mut written = 0;
+        let mut chunks = response.bytes_stream();
+        while let Some(chunk) = chunks.next().await {
+            let chunk = chunk?;
+            written += chunk.len() as u64;
+            self.check_asset_size(written)?;
+            on_chunk(chunk.len(), content_length);
+            hasher.update(&chunk);
+            file.write_all(&chunk)?;
         }
+        file.flush()?;
+        self.check_download_size(written)?;
+
+        crate::verify_minisign_file(file.path(), &self.pubkey, &self.signature)?;
+        Ok((file, format!("{:x}", hasher.finalize())))
+    }
 
-        let response = request
+    /// Downloads the selected artifact into `partial`, resuming after the bytes it already holds.
+    ///
+    /// A `Range: bytes=<offset>-` request is sent when `partial` is not
+    /// empty, together with `If-Range` carrying the `ETag` or `Last-Modified`
+    /// validator recorded in `<partial>.validator` when the download started. A
+    /// `206 Partial Content` response whose `Content-Range` starts at the
+    /// offset is appended to the file, while a `200 OK` from a server without
+    /// range support, or for an artifact that changed since, restarts it from
+    /// scratch. A `206` starting elsewhere fails with [`Error::Network`] and
+    /// discards `partial`. A `416 Range Not Satisfiable` means `partial`
+    /// already holds the whole artifact, which is then verified as is. Chunks
+    /// are written as they arrive, so a later call can pick up after another
+    /// interruption. When the server announced the full size and the file
+    /// ends up shorter or longer, this fails with
+    /// [`Error::IncompleteDownload`]. The callback receives each chunk length
+    /// and the number of bytes still expected for this request.
+    ///
+    /// Once complete, the signature is verified and `partial` is removed;
+    /// a file that fails verification is removed as well.
+    pub async fn download_resume<C>(&self, partial: &Path, mut on_chunk: C) -> Result<Vec<u8>>
+    where
+        C: FnMut(usize, Option<u64>),
+    {
+        let offset = match fs::metadata(partial) {
+            Ok(metadata) => metadata.len(),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => 0,
+            Err(error) => return Err(error.into()),
+        };
+        let validator_path = resume_validator_path(partial);
+        let mut headers = self.download_headers();
+        if offset > 0 {
+            headers.insert(RANGE, HeaderValue::from_str(&format!("bytes={offset}-"))?);
+            if let Ok(validator) = fs::read_to_string(&validator_path) {
+                headers.insert(IF_RANGE, HeaderValue::from_str(validator.trim())?);
+            }
+        }
+        let response = self
+            .client_builder()?
             .build()?
             .get(self.download_url.clone())
             .headers(headers)
             .send()
             .await?;
-        if !response.status().is_success() {
+
+        if offset > 0 && response.status() == StatusCode::RANGE_NOT_SATISFIABLE {
+            let total = content_range(response.headers()).and_then(|(_, total)| total);
+            return self.finish_resume(partial, total.or(Some(offset)));
+        }
+        let response = response.error_for_status()?;
+
+        let resumed = response.status() == StatusCode::PARTIAL_CONTENT;
+        let content_length = response.content_length();
+        let range = content_range(response.headers());
+        if resumed
+            && let Some((Some(start), _)) = range
+            && start != offset
+        {
+            let _ = fs::remove_file(&validator_path);
+            fs::remove_file(partial)?;
             return Err(Error::Network(format!(
-                "Download request failed with status: {}",
-                response.status()
+                "Resumed download starts at byte {start} instead of {offset}"
+            )));
+        }
+        let expected = if resumed {
+            range
+                .and_then(|(_, total)| total)
+                .or_else(|| content_length.map(|length| offset + length))
+        } else {
+            content_length
+        };
+        if let Some(actual) = expected {
+            self.check_asset_size(actual)?;
+        }
+        if !resumed {
+            let validator = [ETAG, LAST_MODIFIED]
+                .into_iter()
+                .find_map(|name| response.headers().get(name)?.to_str().ok());
+            match validator {
+                Some(validator) => fs::write(&validator_path, validator)?,
+                None => {
+                    let _ = fs::remove_file(&validator_path);
+                }
+            }
+        }
+
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(resumed)
+            .truncate(!resumed)
+            .open(partial)?;
+        let mut written = if resumed { offset } else { 0 };
+        let mut chunks = response.bytes_stream();
+        while let Some(chunk) = chunks.next().await {
+            let chunk = chunk?;
+            written += chunk.len() as u64;
+            if let Err(error) = self.check_asset_size(written) {
+                drop(file);
+                let _ = fs::remove_file(&validator_path);
+                fs::remove_file(partial)?;
+                return Err(error);
+            }
+            on_chunk(chunk.len(), content_length);
+            file.write_all(&chunk)?;
+        }
+        file.flush()?;
+        drop(file);
+
+        self.finish_resume(partial, expected)
+    }
+
+    /// Checks and verifies the completed `partial` file, then removes it and its validator.
+    fn finish_resume(&self, partial: &Path, expected: Option<u64>) -> Result<Vec<u8>> {
+        let bytes = fs::read(partial)?;
+        if let Some(expected) = expected
+            && bytes.len() as u64 != expected
+        {
+            return Err(Error::IncompleteDownload {
+                expected,
+                actual: bytes.len() as u64,
+            });
+        }
+        let _ = fs::remove_file(resume_validator_path(partial));
+        if let Err(error) = self.check_download_size(bytes.len() as u64) {
+            fs::remove_file(partial)?;
+            return Err(error);
+        }
+        let verified = crate::verify_minisign(&bytes, &self.pubkey, &self.signature);
+        fs::remove_file(partial)?;
+        verified?;
+        Ok(bytes)
+    }
+
+    /// Returns the artifact size advertised by a `HEAD` request, if any.
+    async fn remote_size(&self) -> Option<u64> {
+        let client = self.client_builder().ok()?.build().ok()?;
+        let head = client
+            .head(self.download_url.clone())
+            .headers(self.download_headers())
+            .send()
+            .await
+            .ok()?;
+        head.headers()
+            .get(CONTENT_LENGTH)?
+            .to_str()
+            .ok()?
+            .parse()
+            .ok()
+    }
+
+    /// Downloads and verifies the selected artifact while recording every URL visited.
+    ///
+    /// The returned history starts with [`Self::download_url`] and ends with the
+    /// URL that finally served the payload, which helps diagnose CDN redirect
+    /// chains. At most ten redirects are followed.
+    pub async fn download_with_redirect_history<C>(
+        &self,
+        mut on_chunk: C,
+    ) -> Result<(Vec<u8>, Vec<Url>)>
+    where
+        C: FnMut(usize),
+    {
+        let history = Arc::new(Mutex::new(vec![self.download_url.clone()]));
+        let visited = Arc::clone(&history);
+        let policy = reqwest::redirect::Policy::custom(move |attempt| {
+            if attempt.previous().len() > MAX_REDIRECTS {
+                return attempt.error("too many redirects");
+            }
+            if let Ok(mut visited) = visited.lock() {
+                visited.push(attempt.url().clone());
+            }
+            attempt.follow()
+        });
+
+        let client = self.client_builder()?.redirect(policy).build()?;
+        let bytes = self
+            .download_with_client(client, |chunk, _| on_chunk(chunk))
+            .await?;
+        let history = history
+            .lock()
+            .map(|history| history.clone())
+            .unwrap_or_default();
+        Ok((bytes, history))
+    }
+
+    /// Downloads the selected artifact in `parts` concurrent byte ranges and verifies it.
+    ///
+    /// A `HEAD` request determines the artifact size. When the server does not
+    /// advertise `Accept-Ranges: bytes` or a `Content-Length`, or `parts` is at
+    /// most one, this falls back to a single-request download. The
+    /// chunk callback receives the size of each part once all parts finished.
+    pub async fn download_with_multipart_acceleration<C>(
+        &self,
+        parts: u8,
+        mut on_chunk: C,
+    ) -> Result<Vec<u8>>
+    where
+        C: FnMut(usize),
+    {
+        let client = self.client_builder()?.build()?;
+        let head = client
+            .head(self.download_url.clone())
+            .headers(self.download_headers())
+            .send()
+            .await?;
+        let accepts_ranges = head
+            .headers()
+            .get(ACCEPT_RANGES)
+            .is_some_and(|value| value.as_bytes() == b"bytes");
+        let content_length = head
+            .headers()
+            .get(CONTENT_LENGTH)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u64>().ok())
+            .filter(|length| *length > 0);
+
+        if let Some(actual) = content_length {
+            self.check_asset_size(actual)?;
+        }
+
+        let Some(content_length) = content_length.filter(|_| accepts_ranges && parts > 1) else {
+            return self
+                .download_with_client(client, |chunk, _| on_chunk(chunk))
+                .await;
+        };
+
+        let part_size = content_length.div_ceil(u64::from(parts));
+        let ranges = (0..content_length)
+            .step_by(part_size as usize)
+            .map(|start| (start, (start + part_size).min(content_length) - 1));
+        let requests = ranges.map(|(start, end)| {
+            let mut headers = self.download_headers();
+            let client = client.clone();
+            async move {
+                headers.insert(
+                    RANGE,
+                    HeaderValue::from_str(&format!("bytes={start}-{end}"))?,
+                );
+                let response = client
+                    .get(self.download_url.clone())
+                    .headers(headers)
+                    .send()
+                    .await?;
+                if response.status() != StatusCode::PARTIAL_CONTENT {
+                    return Err(Error::Network(format!(
+                        "Range request failed with status: {}",
+                        response.status()
+                    )));
+                }
+                Ok::<_, Error>(response.bytes().await?)
+            }
+        });
+
+        let mut bytes = Vec::with_capacity(self.preallocation(Some(content_length)));
+        for part in try_join_all(requests).await? {
+            self.check_asset_size((bytes.len() + part.len()) as u64)?;
+            on_chunk(part.len());
+            bytes.extend_from_slice(&part);
+        }
+        if bytes.len() as u64 != content_length {
+            return Err(Error::Network(format!(
+                "Downloaded {} bytes but expected {content_length}",
+                bytes.len()
             )));
         }
+        self.check_download_size(bytes.len() as u64)?;
 
-        let bytes = response.bytes().await?;
-        on_chunk(bytes.len());
         crate::verify_minisign(&bytes, &self.pubkey, &self.signature)?;
-        Ok(bytes.to_vec())
+        Ok(bytes)
     }
 
     /// Installs already-downloaded artifact bytes using the selected platform backend.
     pub fn install(&self, bytes: &[u8]) -> Result<()> {
         match self.install_action() {
             InstallAction::MacosArchive => self.install_macos(bytes),
-            InstallAction::WindowsExecutableLaunch => self.install_windows(bytes),
-            InstallAction::LinuxAppImageReplace | InstallAction::LinuxPackageCommand => {
-                self.install_linux(bytes)
+            InstallAction::WindowsExecutableLaunch | InstallAction::WindowsArchiveExtract => {
+                self.install_windows(bytes)
+            }
+            InstallAction::LinuxAppImageReplace
+            | InstallAction::LinuxArchiveExtract
+            | InstallAction::LinuxPackageCommand => self.install_linux(bytes),
+        }
+    }
+
+    /// Installs an already-downloaded artifact stored at `path`.
+    ///
+    /// Linux backends copy the file instead of reading it into memory; other
+    /// platforms read it in full and install the bytes.
+    pub(crate) fn install_from_file(&self, path: &Path) -> Result<()> {
+        match self.install_action() {
+            InstallAction::LinuxAppImageReplace
+            | InstallAction::LinuxArchiveExtract
+            | InstallAction::LinuxPackageCommand => self.install_linux_file(path),
+            _ => self.install(&fs::read(path)?),
+        }
+    }
+
+    /// Installs already-downloaded artifact bytes while reporting coarse progress events.
+    ///
+    /// See [`Updater::install_with_progress`] for the events each backend emits.
+    pub fn install_with_progress<F>(&self, bytes: &[u8], mut on_progress: F) -> Result<()>
+    where
+        F: FnMut(InstallProgress) + Send,
+    {
+        on_progress(InstallProgress::Starting);
+        match self.install_action() {
+            InstallAction::WindowsExecutableLaunch | InstallAction::WindowsArchiveExtract => {
+                self.install_windows_with_progress(bytes, &mut on_progress)?
             }
+            _ => self.install(bytes)?,
         }
+        on_progress(InstallProgress::Done);
+        Ok(())
     }
 
     /// Downloads, verifies, and installs the selected update in one step.
-    pub async fn download_and_install<C>(&self, on_chunk: C) -> Result<()>
+    pub async fn download_and_install<C>(&self, mut on_chunk: C) -> Result<()>
     where
         C: FnMut(usize),
     {
-        let bytes = self.download(on_chunk).await?;
+        let bytes = self
+            .download_with_progress(|chunk, _| on_chunk(chunk))
+            .await?;
         self.install(&bytes)
     }
 }
@@ -504,9 +4171,45 @@ impl Update {
     pub(crate) fn install_windows(&self, _bytes: &[u8]) -> Result<()> {
         Err(Error::UnsupportedOs)
     }
+
+    pub(crate) fn install_windows_with_progress(
+        &self,
+        _bytes: &[u8],
+        _on_progress: &mut dyn FnMut(InstallProgress),
+    ) -> Result<()> {
+        Err(Error::UnsupportedOs)
+    }
 }
 
-#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+#[cfg(not(target_os = "windows"))]
+impl Updater {
+    pub(crate) fn install_inner_with_progress(
+        &self,
+        bytes: &[u8],
+        _on_progress: &mut dyn FnMut(InstallProgress),
+    ) -> Result<()> {
+        self.install_inner(bytes)
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+pub(crate) fn running_process_ids(_install_dir: &Path) -> Result<Vec<u32>> {
+    Err(Error::UnsupportedOs)
+}
+
+#[cfg(not(target_os = "windows"))]
+pub(crate) fn terminate_process(_pid: u32) -> Result<()> {
+    Err(Error::UnsupportedOs)
+}
+
+#[cfg(not(target_os = "linux"))]
+impl Updater {
+    pub(crate) fn install_file_inner(&self, path: &Path) -> Result<()> {
+        self.install_inner(&fs::read(path)?)
+    }
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
 impl Updater {
     pub(crate) fn install_inner(&self, _bytes: &[u8]) -> Result<()> {
         Err(Error::UnsupportedOs)
@@ -517,6 +4220,30 @@ impl Updater {
     }
 }
 
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+impl Updater {
+    pub(crate) fn simulate_install_inner(&self, bytes: &[u8]) -> Result<InstallSimulation> {
+        let writable = self
+            .extract_path
+            .parent()
+            .is_some_and(crate::utils::is_dir_writable);
+        Ok(InstallSimulation::from_planned_files(
+            vec![(self.extract_path.clone(), bytes.len() as u64)],
+            !writable,
+        ))
+    }
+
+    #[cfg(target_os = "linux")]
+    pub(crate) fn relaunch_as_admin_inner(&self) -> Result<()> {
+        crate::linux::relaunch_linux_as_admin(&current_exe()?)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub(crate) fn relaunch_as_admin_inner(&self) -> Result<()> {
+        Err(Error::UnsupportedOs)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -536,14 +4263,30 @@ mod tests {
             target: "linux-x86_64".into(),
             installer_kind,
             headers: HeaderMap::new(),
-            timeout: None,
+            connect_timeout: None,
+            read_timeout: None,
             proxy: None,
             no_proxy: false,
+            local_address: None,
+            auto_terminate_running: false,
+            max_asset_size: None,
+            asset_size: None,
+            remove_quarantine: false,
+            verify_codesign: false,
+            codesign_team_id: None,
+            verify_authenticode: false,
+            expected_signer: None,
+            silent_install: true,
+            install_with_sudo: false,
+            pinned_certificates: Vec::new(),
+            pinned_fingerprints: Vec::new(),
+            root_certificates: Vec::new(),
             dangerous_accept_invalid_certs: false,
             dangerous_accept_invalid_hostnames: false,
             extract_path: PathBuf::from("/tmp/release-hub"),
             app_name: "ReleaseHub".into(),
             installer_args: Vec::new(),
+            temp_dir: None,
         }
     }
 
@@ -574,4 +4317,123 @@ mod tests {
             ))
         );
     }
+
+    #[tokio::test]
+    async fn default_updater_reports_not_configured() {
+        let updater = Updater::default();
+
+        assert_eq!(updater.current_version, Version::new(0, 0, 0));
+        assert!(matches!(updater.check().await, Err(Error::NotConfigured)));
+    }
+
+    #[test]
+    fn summary_reports_check_outcome_and_install_path() {
+        let updater = Updater {
+            extract_path: std::env::temp_dir().join("ReleaseHub.AppImage"),
+            app_name: "ReleaseHub".into(),
+            ..Updater::default()
+        };
+
+        let mut update = test_update(InstallerKind::AppImage);
+        update.asset_size = Some(4096);
+        let summary = updater.summary(&Ok(Some(update)));
+        assert!(summary.contains("update available (1.0.1)"));
+        assert!(summary.contains("Detected system:  "));
+        assert!(summary.contains("Artifact:         release-hub.AppImage"));
+        assert!(summary.contains("Artifact size:    4096 bytes"));
+        assert!(summary.contains("Install writable: yes"));
+
+        let summary = updater.summary(&Err(Error::NotConfigured));
+        assert!(summary.contains("check failed: updater is not configured"));
+    }
+
+    #[tokio::test]
+    async fn check_summary_is_written_to_the_given_writer() {
+        let mut out = Vec::new();
+
+        let result = Updater::default().check_and_print_summary(&mut out).await;
+
+        assert!(matches!(result, Err(Error::NotConfigured)));
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("check failed: updater is not configured"));
+        assert!(out.ends_with('\n'));
+    }
+
+    #[test]
+    fn nsis_installers_are_detected_and_launched_silently() {
+        let mut bytes = vec![0u8; 0x100];
+        bytes[0x88..0x8C].copy_from_slice(&[0x1F, 0x9D, 0x8C, 0x00]);
+        assert!(Updater::is_nsis_installer(&bytes));
+        assert!(Updater::is_nsis_installer(b"MZ...Nullsoft Install System"));
+        assert!(!Updater::is_nsis_installer(b"MZ plain executable"));
+
+        let args = vec![OsString::from("/D=C:\\Apps")];
+        assert_eq!(
+            nsis_installer_args(true, &args),
+            vec![OsString::from("/S"), OsString::from("/D=C:\\Apps")]
+        );
+        assert_eq!(nsis_installer_args(false, &args), args);
+    }
+
+    #[test]
+    fn progress_is_not_counted_twice_across_attempts() {
+        let mut chunks = Vec::new();
+        let mut progress = AttemptProgress::new(|chunk, _| chunks.push(chunk));
+        {
+            let mut failed = progress.attempt();
+            failed(4, Some(10));
+            failed(3, Some(10));
+        }
+        {
+            let mut retried = progress.attempt();
+            retried(5, Some(10));
+            retried(5, Some(10));
+        }
+        assert_eq!(chunks, vec![4, 3, 3]);
+    }
+
+    #[test]
+    fn mirror_templates_expand_placeholders_and_drop_credentials() {
+        let mut update = test_update(InstallerKind::AppImage);
+        update.headers.insert(
+            http::header::AUTHORIZATION,
+            HeaderValue::from_static("token"),
+        );
+        let template = Url::parse("https://mirror.example.com/{version}/{filename}").unwrap();
+        assert!(is_download_mirror(&template));
+        assert!(!is_download_mirror(
+            &Url::parse("https://mirror.example.com/latest.json").unwrap()
+        ));
+
+        let url = mirror_download_url(&template, &update).unwrap();
+        assert_eq!(
+            url.as_str(),
+            "https://mirror.example.com/1.0.1/release-hub.AppImage"
+        );
+        let same_origin = retarget_download(
+            &update,
+            Url::parse("https://example.com/other.AppImage").unwrap(),
+        );
+        assert!(
+            same_origin
+                .headers
+                .contains_key(http::header::AUTHORIZATION)
+        );
+        let mirrored = retarget_download(&update, url);
+        assert!(!mirrored.headers.contains_key(http::header::AUTHORIZATION));
+    }
+
+    #[test]
+    fn preallocation_is_capped_regardless_of_content_length() {
+        let mut update = test_update(InstallerKind::AppImage);
+        assert_eq!(update.preallocation(None), 0);
+        assert_eq!(update.preallocation(Some(1024)), 1024);
+        assert_eq!(
+            update.preallocation(Some(u64::MAX)),
+            MAX_PREALLOCATION as usize
+        );
+
+        update.max_asset_size = Some(4096);
+        assert_eq!(update.preallocation(Some(u64::MAX)), 4096);
+    }
 }
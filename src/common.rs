@@ -0,0 +1,89 @@
+//! Archive helpers shared by the macOS and Windows backends and dry runs.
+
+use crate::Result;
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+use fs_err as fs;
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+use std::path::Path;
+use std::{io::Cursor, path::PathBuf};
+use zip::ZipArchive;
+
+/// Extracts a ZIP archive into `dest` and returns every extracted path.
+///
+/// Entries with unsafe paths are skipped. On Unix, stored permissions are
+/// restored, and files without stored permissions that look like executables
+/// (inside `Contents/MacOS/` or without an extension outside
+/// `Contents/Resources/`) are marked `0o755`.
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+pub(crate) fn extract_zip_to_dir(bytes: &[u8], dest: &Path) -> Result<Vec<PathBuf>> {
+    let mut archive = ZipArchive::new(Cursor::new(bytes))?;
+    let mut extracted_files = Vec::new();
+
+    for i in 0..archive.len() {
+        let mut file = archive.by_index(i)?;
+        let outpath = match file.enclosed_name() {
+            Some(path) => dest.join(path),
+            None => continue,
+        };
+
+        if file.name().ends_with('/') {
+            fs::create_dir_all(&outpath)?;
+        } else {
+            if let Some(parent) = outpath.parent()
+                && !parent.exists()
+            {
+                fs::create_dir_all(parent)?;
+            }
+            let mut outfile = fs::File::create(&outpath)?;
+            std::io::copy(&mut file, &mut outfile)?;
+        }
+
+        #[cfg(target_os = "macos")]
+        set_unix_permissions(&outpath, file.unix_mode(), file.name().ends_with('/'))?;
+
+        extracted_files.push(outpath);
+    }
+
+    Ok(extracted_files)
+}
+
+/// Lists the safe file entries of a ZIP archive with their uncompressed sizes.
+///
+/// Nothing is extracted; directory entries and unsafe paths are skipped.
+pub(crate) fn zip_file_entries(bytes: &[u8]) -> Result<Vec<(PathBuf, u64)>> {
+    let mut archive = ZipArchive::new(Cursor::new(bytes))?;
+    let mut entries = Vec::new();
+
+    for i in 0..archive.len() {
+        let file = archive.by_index(i)?;
+        if file.is_dir() {
+            continue;
+        }
+        if let Some(path) = file.enclosed_name() {
+            entries.push((path, file.size()));
+        }
+    }
+
+    Ok(entries)
+}
+
+#[cfg(target_os = "macos")]
+fn set_unix_permissions(path: &Path, mode: Option<u32>, is_dir: bool) -> Result<()> {
+    use std::{fs::Permissions, os::unix::fs::PermissionsExt};
+
+    if let Some(mode) = mode {
+        fs::set_permissions(path, Permissions::from_mode(mode))?;
+    } else if !is_dir {
+        let file_name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or("");
+        let path_str = path.to_string_lossy();
+        if path_str.contains("Contents/MacOS/")
+            || (!file_name.contains('.') && !path_str.contains("Contents/Resources/"))
+        {
+            fs::set_permissions(path, Permissions::from_mode(0o755))?;
+        }
+    }
+    Ok(())
+}
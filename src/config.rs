@@ -1,17 +1,18 @@
 //! Static updater configuration shared across all release-source implementations.
 
-use serde::{Deserialize, Deserializer, de::Error as DeError};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error as DeError};
 use std::ffi::OsString;
 use url::Url;
 
 /// Windows-specific installer configuration.
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct WindowsConfig {
     /// Additional arguments forwarded to the Windows installer launch path.
     #[serde(
         default,
         alias = "installer-args",
+        serialize_with = "serialize_os_string",
         deserialize_with = "deserialize_os_string"
     )]
     pub installer_args: Vec<OsString>,
@@ -23,7 +24,8 @@ pub struct WindowsConfig {
 /// passed into [`crate::UpdaterBuilder`]. It controls endpoint defaults,
 /// Minisign verification, and a few escape hatches for local development or
 /// unusual networking environments.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Config {
     /// Allows non-HTTPS update endpoints. Intended for development only.
     pub dangerous_insecure_transport_protocol: bool,
@@ -49,6 +51,13 @@ impl Config {
     }
 }
 
+fn serialize_os_string<S>(args: &[OsString], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_seq(args.iter().map(|arg| arg.to_string_lossy()))
+}
+
 fn deserialize_os_string<'de, D>(deserializer: D) -> Result<Vec<OsString>, D::Error>
 where
     D: Deserializer<'de>,
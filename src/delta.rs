@@ -0,0 +1,181 @@
+//! Binary delta patch support.
+//!
+//! Patches use the uncompressed control/diff/extra stream produced by the
+//! `bsdiff` crate: a sequence of 24-byte control headers, each followed by the
+//! diff bytes to add onto the old file and the extra bytes to copy verbatim.
+
+use crate::{Error, Result, Update, Updater};
+use fs_err as fs;
+use std::io::Write;
+
+const CONTROL_LEN: usize = 24;
+
+/// Applies a `bsdiff`-format patch to `old` and returns the patched bytes.
+///
+/// Malformed patches, including lengths or offsets that point outside `old`
+/// or the patch, fail with [`Error::InvalidUpdaterFormat`].
+pub fn apply_bsdiff_patch(old: &[u8], patch: &[u8]) -> Result<Vec<u8>> {
+    let mut new = Vec::with_capacity(old.len());
+    let mut old_pos = 0i64;
+    let mut patch_pos = 0usize;
+
+    while patch_pos < patch.len() {
+        let control = take(patch, &mut patch_pos, CONTROL_LEN)?;
+        let mix_len = read_len(&control[0..8])?;
+        let copy_len = read_len(&control[8..16])?;
+        let seek_len = read_offset(&control[16..24]);
+
+        let diff = take(patch, &mut patch_pos, mix_len)?;
+        let old_start = usize::try_from(old_pos).map_err(|_| Error::InvalidUpdaterFormat)?;
+        let old_slice = old_start
+            .checked_add(mix_len)
+            .and_then(|old_end| old.get(old_start..old_end))
+            .ok_or(Error::InvalidUpdaterFormat)?;
+        new.extend(
+            diff.iter()
+                .zip(old_slice)
+                .map(|(diff, old)| diff.wrapping_add(*old)),
+        );
+        new.extend_from_slice(take(patch, &mut patch_pos, copy_len)?);
+
+        old_pos = i64::try_from(mix_len)
+            .ok()
+            .and_then(|mix_len| old_pos.checked_add(mix_len)?.checked_add(seek_len))
+            .ok_or(Error::InvalidUpdaterFormat)?;
+    }
+
+    Ok(new)
+}
+
+fn take<'a>(patch: &'a [u8], pos: &mut usize, len: usize) -> Result<&'a [u8]> {
+    let end = pos.checked_add(len).ok_or(Error::InvalidUpdaterFormat)?;
+    let bytes = patch.get(*pos..end).ok_or(Error::InvalidUpdaterFormat)?;
+    *pos = end;
+    Ok(bytes)
+}
+
+fn read_len(bytes: &[u8]) -> Result<usize> {
+    let mut buf = [0u8; 8];
+    buf.copy_from_slice(bytes);
+    usize::try_from(u64::from_le_bytes(buf)).map_err(|_| Error::InvalidUpdaterFormat)
+}
+
+fn read_offset(bytes: &[u8]) -> i64 {
+    let mut buf = [0u8; 8];
+    buf.copy_from_slice(bytes);
+    let value = i64::from_le_bytes(buf);
+    if value & i64::MIN == 0 {
+        value
+    } else {
+        -(value & i64::MAX)
+    }
+}
+
+impl Updater {
+    /// Applies a binary delta patch to the installed executable to produce `update`.
+    ///
+    /// The file at [`Self::extract_path`] is patched with
+    /// [`apply_bsdiff_patch`], and the result must match the minisign
+    /// signature of `update`'s full artifact before it is atomically swapped
+    /// into the same path. The [`UpdaterBuilder::before_install`] and
+    /// [`UpdaterBuilder::after_install`] hooks run around the swap. Only
+    /// single-file installs such as AppImages are supported; directory-based
+    /// installs such as macOS `.app` bundles return
+    /// [`Error::InvalidUpdaterFormat`]. Nothing is left behind on failure.
+    ///
+    /// [`UpdaterBuilder::before_install`]: crate::UpdaterBuilder::before_install
+    /// [`UpdaterBuilder::after_install`]: crate::UpdaterBuilder::after_install
+    pub fn apply_patch(&self, update: &Update, patch_bytes: &[u8]) -> Result<()> {
+        if self.extract_path.is_dir() {
+            return Err(Error::InvalidUpdaterFormat);
+        }
+
+        let old = fs::read(&self.extract_path)?;
+        let new = apply_bsdiff_patch(&old, patch_bytes)?;
+        crate::verify_minisign(&new, &update.pubkey, &update.signature)?;
+        self.mark_verified(&new);
+        self.ensure_verified(&new)?;
+        self.run_before_install(&new)?;
+
+        let dir = self
+            .extract_path
+            .parent()
+            .ok_or(Error::FailedToDetermineExtractPath)?;
+        // The staging file is removed when dropped, so failures leave nothing behind.
+        let mut staging = tempfile::Builder::new()
+            .prefix(".release-hub-patched-")
+            .tempfile_in(dir)?;
+        staging.write_all(&new)?;
+        #[cfg(unix)]
+        {
+            use std::{fs::Permissions, os::unix::fs::PermissionsExt};
+
+            staging
+                .as_file()
+                .set_permissions(Permissions::from_mode(0o755))?;
+        }
+        staging
+            .persist(&self.extract_path)
+            .map_err(|error| Error::Io(error.error))?;
+        self.run_after_install();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn control(mix_len: u64, copy_len: u64, seek_len: i64) -> Vec<u8> {
+        let seek = if seek_len < 0 {
+            (-seek_len) as u64 | (1 << 63)
+        } else {
+            seek_len as u64
+        };
+        [
+            mix_len.to_le_bytes(),
+            copy_len.to_le_bytes(),
+            seek.to_le_bytes(),
+        ]
+        .concat()
+    }
+
+    #[test]
+    fn applies_diff_and_extra_blocks() {
+        let old = b"hello world";
+        let mut patch = control(5, 1, 1);
+        patch.extend_from_slice(&[0, 0, 0, 0, 0]);
+        patch.push(b',');
+        patch.extend(control(5, 1, 0));
+        patch.extend_from_slice(&[0, 0, 0, 0, 0]);
+        patch.push(b'!');
+
+        assert_eq!(apply_bsdiff_patch(old, &patch).unwrap(), b"hello,world!");
+    }
+
+    #[test]
+    fn rejects_truncated_patches() {
+        let mut patch = control(5, 0, 0);
+        patch.extend_from_slice(&[0, 0]);
+
+        assert!(matches!(
+            apply_bsdiff_patch(b"hello", &patch),
+            Err(Error::InvalidUpdaterFormat)
+        ));
+    }
+
+    #[test]
+    fn rejects_overflowing_lengths_and_offsets() {
+        let huge_mix = control(u64::MAX >> 1, 0, 0);
+        let mut huge_seek = control(0, 0, i64::MAX);
+        huge_seek.extend(control(1, 0, 0));
+        huge_seek.push(0);
+
+        for patch in [huge_mix, control(0, u64::MAX >> 1, 0), huge_seek] {
+            assert!(matches!(
+                apply_bsdiff_patch(b"hello", &patch),
+                Err(Error::InvalidUpdaterFormat)
+            ));
+        }
+    }
+}
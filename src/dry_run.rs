@@ -0,0 +1,226 @@
+//! Structural validation of update artifacts without installing them.
+
+use crate::{
+    Error, InstallSimulation, InstallerKind, Result, Update, Updater, common::zip_file_entries,
+    utils::is_dir_writable,
+};
+use flate2::read::GzDecoder;
+use std::{
+    io::{Cursor, Read},
+    path::{Component, Path, PathBuf},
+};
+
+const ELF_MAGIC: &[u8] = b"\x7fELF";
+const DEB_MAGIC: &[u8] = b"!<arch>\n";
+const RPM_MAGIC: &[u8] = b"\xED\xAB\xEE\xDB";
+const SQUASHFS_MAGIC: &[u8] = b"hsqs";
+const MSI_MAGIC: &[u8] = b"\xD0\xCF\x11\xE0\xA1\xB1\x1A\xE1";
+/// Offset of the `e_lfanew` field pointing at the PE signature in a DOS header.
+const PE_OFFSET_FIELD: usize = 0x3C;
+
+impl Updater {
+    /// Validates `bytes` as the artifact of `update` and reports what
+    /// installing it would write, without touching the file system.
+    ///
+    /// Unlike [`Self::simulate_install`], the artifact is parsed according to
+    /// [`Update::installer_kind`] on any host, so CI can check packages built
+    /// for other platforms: archives must contain the application binary or
+    /// `.app` bundle, and installers, packages and AppImages must carry the
+    /// expected magic bytes, including the PE header of Windows `.exe`
+    /// installers. Packages handed to an external installer plan no files and
+    /// always require elevation. Checksums configured with
+    /// [`crate::UpdaterBuilder::checksum_sha256`] or published in the manifest
+    /// are verified as well.
+    ///
+    /// Returns [`Error::DryRunFailed`] when the artifact is malformed and
+    /// [`Error::ChecksumMismatch`] when it does not match a checksum.
+    pub fn dry_run_install(&self, update: &Update, bytes: &[u8]) -> Result<InstallSimulation> {
+        let install_path = &update.extract_path;
+        let whole_artifact = || Some(vec![(install_path.clone(), bytes.len() as u64)]);
+        let files = match update.installer_kind {
+            InstallerKind::AppImage => {
+                expect_magic(bytes, ELF_MAGIC, "AppImage is not an ELF executable")?;
+                whole_artifact()
+            }
+            InstallerKind::TarGz | InstallerKind::TarXz => {
+                let (_, size) = tar_entries(bytes, &update.installer_kind)?
+                    .into_iter()
+                    .find(|(entry, _)| is_archive_binary(entry, &update.app_name))
+                    .ok_or_else(|| {
+                        Error::DryRunFailed(format!(
+                            "archive does not contain the `{}` binary",
+                            update.app_name
+                        ))
+                    })?;
+                Some(vec![(install_path.clone(), size)])
+            }
+            InstallerKind::Deb => {
+                expect_magic(bytes, DEB_MAGIC, "not a Debian package")?;
+                None
+            }
+            InstallerKind::Rpm => {
+                expect_magic(bytes, RPM_MAGIC, "not an RPM package")?;
+                None
+            }
+            InstallerKind::Snap => {
+                expect_magic(bytes, SQUASHFS_MAGIC, "not a snap package")?;
+                None
+            }
+            InstallerKind::AppTarGz => Some(bundle_files(
+                tar_entries(bytes, &update.installer_kind)?,
+                install_path,
+            )?),
+            InstallerKind::AppZip => Some(bundle_files(zip_entries(bytes)?, install_path)?),
+            InstallerKind::Dmg => {
+                if !(bytes.len() >= 512 && bytes[bytes.len() - 512..].starts_with(b"koly")) {
+                    return Err(Error::DryRunFailed("not a UDIF disk image".into()));
+                }
+                whole_artifact()
+            }
+            InstallerKind::Msi => {
+                expect_magic(bytes, MSI_MAGIC, "not an MSI package")?;
+                None
+            }
+            InstallerKind::Nsis => {
+                check_pe_header(bytes)?;
+                None
+            }
+            InstallerKind::PortableZip => {
+                let entries = zip_entries(bytes)?;
+                let executable = format!("{}.exe", update.app_name);
+                if !entries.iter().any(|(entry, _)| {
+                    entry
+                        .file_name()
+                        .is_some_and(|name| name.eq_ignore_ascii_case(executable.as_str()))
+                }) {
+                    return Err(Error::DryRunFailed(format!(
+                        "archive does not contain `{executable}`"
+                    )));
+                }
+                Some(
+                    entries
+                        .into_iter()
+                        .map(|(entry, size)| (install_path.join(entry), size))
+                        .collect(),
+                )
+            }
+        };
+
+        self.verify_checksum(update, bytes)?;
+
+        let Some(files) = files else {
+            return Ok(InstallSimulation {
+                bytes_to_write: bytes.len() as u64,
+                requires_elevation: true,
+                ..InstallSimulation::default()
+            });
+        };
+        // Portable ZIPs are unpacked into the install directory itself.
+        let target_dir = if update.installer_kind == InstallerKind::PortableZip {
+            Some(install_path.as_path())
+        } else {
+            install_path.parent()
+        };
+        let writable = target_dir.is_some_and(is_dir_writable);
+        Ok(InstallSimulation::from_planned_files(files, !writable))
+    }
+}
+
+fn expect_magic(bytes: &[u8], magic: &[u8], message: &str) -> Result<()> {
+    if bytes.starts_with(magic) {
+        Ok(())
+    } else {
+        Err(Error::DryRunFailed(message.into()))
+    }
+}
+
+/// Checks the DOS header and the `PE\0\0` signature it points at.
+fn check_pe_header(bytes: &[u8]) -> Result<()> {
+    expect_magic(bytes, b"MZ", "installer is missing the DOS header")?;
+    let pe_offset = bytes
+        .get(PE_OFFSET_FIELD..PE_OFFSET_FIELD + 4)
+        .and_then(|field| field.try_into().ok())
+        .map(u32::from_le_bytes)
+        .ok_or_else(|| Error::DryRunFailed("installer DOS header is truncated".into()))?;
+    match bytes.get(pe_offset as usize..) {
+        Some(header) if header.starts_with(b"PE\0\0") => Ok(()),
+        _ => Err(Error::DryRunFailed(
+            "installer is missing the PE header".into(),
+        )),
+    }
+}
+
+/// Lists the file entries of a compressed tar archive with their sizes.
+fn tar_entries(bytes: &[u8], kind: &InstallerKind) -> Result<Vec<(PathBuf, u64)>> {
+    let invalid =
+        |error: std::io::Error| Error::DryRunFailed(format!("invalid tar archive: {error}"));
+    let decoder: Box<dyn Read + '_> = if *kind == InstallerKind::TarXz {
+        let mut tar = Vec::new();
+        lzma_rs::xz_decompress(&mut &*bytes, &mut tar)
+            .map_err(|error| Error::DryRunFailed(format!("invalid xz stream: {error}")))?;
+        Box::new(Cursor::new(tar))
+    } else {
+        Box::new(GzDecoder::new(bytes))
+    };
+
+    let mut archive = tar::Archive::new(decoder);
+    let mut entries = Vec::new();
+    for entry in archive.entries().map_err(invalid)? {
+        let entry = entry.map_err(invalid)?;
+        if entry.header().entry_type().is_dir() {
+            continue;
+        }
+        entries.push((entry.path().map_err(invalid)?.into_owned(), entry.size()));
+    }
+    Ok(entries)
+}
+
+fn zip_entries(bytes: &[u8]) -> Result<Vec<(PathBuf, u64)>> {
+    zip_file_entries(bytes)
+        .map_err(|error| Error::DryRunFailed(format!("invalid ZIP archive: {error}")))
+}
+
+/// Returns the normal components of an archive entry, skipping `./` prefixes.
+fn entry_components(entry: &Path) -> Vec<&std::ffi::OsStr> {
+    entry
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(name) => Some(name),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Mirrors the Linux installer, which looks for the binary at the top level
+/// or inside a directory named after the application.
+fn is_archive_binary(entry: &Path, app_name: &str) -> bool {
+    match entry_components(entry).as_slice() {
+        [name] => name.eq_ignore_ascii_case(app_name),
+        [dir, name] => dir.eq_ignore_ascii_case(app_name) && name.eq_ignore_ascii_case(app_name),
+        _ => false,
+    }
+}
+
+/// Maps the files of the `.app` bundle in an archive onto `install_path`.
+fn bundle_files(entries: Vec<(PathBuf, u64)>, install_path: &Path) -> Result<Vec<(PathBuf, u64)>> {
+    let files: Vec<(PathBuf, u64)> = entries
+        .into_iter()
+        .filter_map(|(entry, size)| {
+            let components = entry_components(&entry);
+            let (bundle, rest) = components.split_first()?;
+            if Path::new(bundle).extension() != Some("app".as_ref()) || rest.is_empty() {
+                return None;
+            }
+            let path = rest
+                .iter()
+                .fold(install_path.to_path_buf(), |path, name| path.join(name));
+            Some((path, size))
+        })
+        .collect();
+    if files.is_empty() {
+        return Err(Error::DryRunFailed(
+            "archive does not contain an `.app` bundle".into(),
+        ));
+    }
+    Ok(files)
+}
@@ -4,7 +4,16 @@
 pub enum Error {
     /// GitHub API or connector error.
     #[error(transparent)]
-    GitHub(#[from] octocrab::Error),
+    GitHub(octocrab::Error),
+    /// The GitHub API rate limit was exhausted; authenticate to raise it.
+    #[error(
+        "GitHub API rate limit exceeded{}; configure a personal access token to raise it.",
+        reset_hint(*reset_at)
+    )]
+    RateLimitExceeded {
+        /// When the limit resets, if GitHub reported it.
+        reset_at: Option<std::time::SystemTime>,
+    },
     /// Filesystem or process I/O error.
     #[error(transparent)]
     Io(#[from] std::io::Error),
@@ -37,21 +46,121 @@ pub enum Error {
     /// No suitable artifact could be found for the requested target.
     #[error("Asset not found.")]
     AssetNotFound,
+    /// The selected artifact exceeds the configured maximum download size.
+    #[error("Asset is {actual} bytes, exceeding the {limit} byte limit.")]
+    AssetTooLarge {
+        /// Configured maximum size in bytes.
+        limit: u64,
+        /// Reported artifact size in bytes.
+        actual: u64,
+    },
+    /// The artifact's Minisign signature did not verify, or the bytes were never verified.
+    #[error("signature verification failed")]
+    SignatureVerificationFailed,
+    /// The downloaded artifact's SHA-256 digest did not match the expected value.
+    #[error("checksum mismatch: expected {expected}, got {actual}")]
+    ChecksumMismatch {
+        /// Expected lowercase hex digest.
+        expected: String,
+        /// Digest of the downloaded bytes.
+        actual: String,
+    },
+    /// Checksum verification was enabled but the release has no checksum sidecar asset.
+    #[error("no `.sha256sum` or `.sha256` checksum asset found for the artifact")]
+    ChecksumSidecarNotFound,
     /// The install target path could not be derived from the executable path.
     #[error("Failed to determine updater package extract path.")]
     FailedToDetermineExtractPath,
     /// An update endpoint used an insecure transport protocol.
     #[error("The configured updater endpoint must use a secure protocol like `https`.")]
     InsecureTransportProtocol,
+    /// A download mirror template is not a URL or lacks the `{filename}` placeholder.
+    #[error("invalid download mirror template `{0}`")]
+    InvalidMirrorTemplate(String),
     /// The requested platform key was not present in the remote release metadata.
     #[error("the platform `{0}` was not found on the response `platforms` object")]
     TargetNotFound(String),
+    /// A release lacks artifacts for one or more required `(OS, Arch)` pairs.
+    #[error("release is missing assets for required targets: {0:?}")]
+    MissingRequiredAssets(Vec<(crate::OS, crate::Arch)>),
     /// A matching detached signature asset was not found for the selected artifact.
     #[error("missing signature asset for `{0}`")]
     MissingSignatureAsset(String),
+    /// The installed version is newer than the latest published release.
+    #[error("installed version {installed} is newer than the latest release {latest}")]
+    DowngradeDetected {
+        /// Currently installed version.
+        installed: semver::Version,
+        /// Latest published version.
+        latest: semver::Version,
+    },
+    /// No release matching the version pinned with `UpdaterBuilder::target_version` exists.
+    #[error("no release found for version {0}")]
+    VersionNotFound(semver::Version),
+    /// The source has no release on the requested channel.
+    #[error("no release found on the requested channel")]
+    NoMatchingRelease,
+    /// A download ended before the size announced by the server was reached.
+    #[error("download incomplete: expected {expected} bytes, got {actual}")]
+    IncompleteDownload {
+        /// Size announced by the server.
+        expected: u64,
+        /// Bytes actually received.
+        actual: u64,
+    },
+    /// The running version is below the floor set with `UpdaterBuilder::minimum_version`.
+    #[error(
+        "installed version {current} is older than {minimum}, the minimum version that can be updated{}",
+        migration_hint(migration_url.as_deref())
+    )]
+    CurrentVersionTooOld {
+        /// Currently installed version.
+        current: semver::Version,
+        /// Oldest version allowed to update.
+        minimum: semver::Version,
+        /// Migration docs set with `UpdaterBuilder::migration_url`.
+        migration_url: Option<String>,
+    },
+    /// The downloaded artifact's size differs from the size listed by the release source.
+    #[error("downloaded {actual} bytes, but the release lists the asset as {expected} bytes")]
+    DownloadSizeMismatch {
+        /// Size listed by the release source.
+        expected: u64,
+        /// Bytes actually received.
+        actual: u64,
+    },
+    /// The install volume has less free space than the update needs.
+    #[error("insufficient disk space: {required} bytes required, {available} bytes available")]
+    InsufficientDiskSpace {
+        /// Bytes the download and installation need.
+        required: u64,
+        /// Bytes free on the install volume.
+        available: u64,
+    },
+    /// A download kept failing with transient errors after the configured number of retries.
+    #[error("download failed after {0} retries")]
+    MaxRetriesExceeded(u32),
+    /// [`crate::Updater::dry_run_install`] found a structural problem in the artifact.
+    #[error("dry run failed: {0}")]
+    DryRunFailed(String),
+    /// A download was cancelled through its cancellation token.
+    #[error("download cancelled")]
+    Cancelled,
+    /// [`crate::Updater::rollback`] was called without a preserved installation.
+    #[error("no previous installation is available to roll back to")]
+    NoRollbackAvailable,
     /// Generic network or transport failure represented as a message.
     #[error("`{0}`")]
     Network(String),
+    /// The updater was created as a placeholder without a release source.
+    #[error("updater is not configured")]
+    NotConfigured,
+    /// Both IPv4-only and IPv6-only downloads were requested.
+    #[error("`force_ipv4` and `force_ipv6` cannot both be enabled")]
+    ConflictingNetworkConfig,
+    /// A builder option was set to a value it cannot use, such as a zero interval.
+    #[error("invalid updater configuration: {0}")]
+    InvalidConfig(String),
     /// Downloaded installer or archive bytes did not match the expected format.
     #[error("invalid updater binary format")]
     InvalidUpdaterFormat,
@@ -64,9 +173,33 @@ pub enum Error {
     /// Windows installer could not proceed because files are in use.
     #[error("Installation failed: file in use. Please close the application and try again.")]
     FileInUse,
-    /// Windows installer launch returned an execution error code.
+    /// `snap install` exited unsuccessfully.
+    #[error("Snap installation failed with exit code {exit_code}.")]
+    SnapInstallFailed {
+        /// Exit code reported by `snap`, or `-1` when terminated by a signal.
+        exit_code: i32,
+    },
+    /// An installer or platform install tool, such as `hdiutil`, returned an error code.
     #[error("Installation failed: installer execution error. Error code: {0}")]
     InstallerExecutionFailed(i32),
+    /// `msiexec.exe` exited with a non-zero code while installing an `.msi` package.
+    #[error("MSI installation failed with exit code {0}.")]
+    MsiExecutionFailed(i32),
+    /// The `.msi` package was installed, but Windows must restart before it takes effect.
+    #[error("Installation finished; a restart is required to complete it.")]
+    RebootRequired,
+    /// The configured post-download script exited unsuccessfully.
+    #[error("Post-download script failed with exit code {0}.")]
+    PostDownloadScriptFailed(i32),
+    /// `dpkg` or `rpm` failed to install a `.deb` or `.rpm` package; holds its stderr.
+    #[error("Package manager failed: {0}")]
+    PackageManagerFailed(String),
+    /// Another process is installing an update for the same application.
+    #[error("another update of this application is already in progress")]
+    LockAlreadyHeld,
+    /// A running application process could not be terminated before installing.
+    #[error("Failed to terminate running process {0}.")]
+    ProcessTerminationFailed(u32),
     /// Windows elevation prompt was cancelled by the user.
     #[error("Installation cancelled: User declined administrator privileges.")]
     UserCancelledElevation,
@@ -76,11 +209,125 @@ pub enum Error {
     /// RFC3339 or other time parsing error.
     #[error(transparent)]
     Time(#[from] time::error::Parse),
-    #[cfg(target_os = "macos")]
-    /// ZIP archive extraction error on macOS.
+    #[cfg(feature = "catch-panic")]
+    /// A panic was caught while checking for updates.
+    #[error("internal panic: {0}")]
+    InternalPanic(String),
+    /// ZIP archive error.
     #[error(transparent)]
     Zip(#[from] zip::result::ZipError),
 }
 
 /// Convenient result alias used throughout the crate.
 pub type Result<T> = std::result::Result<T, Error>;
+
+impl From<octocrab::Error> for Error {
+    /// Reports rate-limit responses as [`Error::RateLimitExceeded`].
+    ///
+    /// A `429` status is always a rate limit. A `403` is one only when its
+    /// headers say so, which the HTTP service checks where they are visible.
+    fn from(error: octocrab::Error) -> Self {
+        match &error {
+            octocrab::Error::GitHub { source, .. }
+                if source.status_code == http::StatusCode::TOO_MANY_REQUESTS =>
+            {
+                Self::RateLimitExceeded { reset_at: None }
+            }
+            octocrab::Error::Service { source, .. } => match source.downcast_ref::<RateLimited>() {
+                Some(limited) => Self::RateLimitExceeded {
+                    reset_at: limited.reset_at,
+                },
+                None => Self::GitHub(error),
+            },
+            _ => Self::GitHub(error),
+        }
+    }
+}
+
+/// Rate-limited GitHub API response, detected from its status and headers.
+///
+/// GitHub answers an exhausted primary limit with `x-ratelimit-remaining: 0`
+/// and a secondary limit with `retry-after`, on a `403` or `429` status.
+#[derive(Debug)]
+pub(crate) struct RateLimited {
+    reset_at: Option<std::time::SystemTime>,
+}
+
+impl RateLimited {
+    /// Returns the rate limit reported by a response, if any.
+    pub(crate) fn from_response(
+        status: http::StatusCode,
+        headers: &http::HeaderMap,
+    ) -> Option<Self> {
+        use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+        if !matches!(status.as_u16(), 403 | 429) {
+            return None;
+        }
+        let header = |name| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse::<u64>().ok())
+        };
+        let reset =
+            header("x-ratelimit-reset").map(|reset| UNIX_EPOCH + Duration::from_secs(reset));
+        let retry_after = header(http::header::RETRY_AFTER.as_str())
+            .map(|seconds| SystemTime::now() + Duration::from_secs(seconds));
+        if retry_after.is_some() {
+            Some(Self {
+                reset_at: retry_after,
+            })
+        } else if header("x-ratelimit-remaining") == Some(0) {
+            Some(Self { reset_at: reset })
+        } else {
+            None
+        }
+    }
+}
+
+impl std::fmt::Display for RateLimited {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("GitHub API rate limit exceeded")
+    }
+}
+
+impl std::error::Error for RateLimited {}
+
+impl From<Error> for std::io::Error {
+    /// Converts updater errors so `?` works in `io::Result` functions.
+    ///
+    /// I/O errors are returned unchanged; privilege, file-in-use, lock, and
+    /// missing asset errors map to their closest [`std::io::ErrorKind`], and every
+    /// other error becomes [`std::io::ErrorKind::Other`] carrying the message.
+    fn from(error: Error) -> Self {
+        use std::io::ErrorKind;
+
+        let kind = match error {
+            Error::Io(error) => return error,
+            Error::InsufficientPrivileges => ErrorKind::PermissionDenied,
+            Error::FileInUse | Error::LockAlreadyHeld => ErrorKind::WouldBlock,
+            Error::AssetNotFound => ErrorKind::NotFound,
+            _ => ErrorKind::Other,
+        };
+        std::io::Error::new(kind, error.to_string())
+    }
+}
+
+/// Formats the migration docs suffix of [`Error::CurrentVersionTooOld`].
+fn migration_hint(url: Option<&str>) -> String {
+    url.map(|url| format!("; see {url} for migration steps"))
+        .unwrap_or_default()
+}
+
+/// Formats the reset time suffix of [`Error::RateLimitExceeded`].
+fn reset_hint(reset_at: Option<std::time::SystemTime>) -> String {
+    reset_at
+        .and_then(|reset_at| {
+            time::OffsetDateTime::from(reset_at)
+                .format(&time::format_description::well_known::Rfc3339)
+                .ok()
+        })
+        .map(|reset_at| format!(" until {reset_at}"))
+        .unwrap_or_default()
+}
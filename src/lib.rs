@@ -11,13 +11,25 @@
 // This crate is forked and modified from the [tauri-apps/tauri-plugin-updater](https://github.com/tauri-apps/plugins-workspace/tree/v2/plugins/updater), which is licensed under [MIT](https://github.com/tauri-apps/plugins-workspace/blob/v2/plugins/updater/LICENSE_MIT) or [Apache 2.0](https://github.com/tauri-apps/plugins-workspace/blob/v2/plugins/updater/LICENSE_APACHE-2.0)/[MIT](https://github.com/tauri-apps/plugins-workspace/blob/v2/plugins/updater/LICENSE_MIT).
 
 mod builder;
-pub use builder::{Updater, UpdaterBuilder, VersionComparator};
+mod common;
+pub use builder::{
+    AfterInstallHook, BeforeInstallFileHook, BeforeInstallHook, CheckCompleteHook, CheckResult,
+    DiagnosticsReport, InstallProgress, InstallSimulation, UpdateRequired, Updater, UpdaterBuilder,
+    VersionComparator, check_tcp_connectivity,
+};
 mod config;
 pub use config::*;
+#[cfg(feature = "delta")]
+mod delta;
+#[cfg(feature = "delta")]
+pub use delta::apply_bsdiff_patch;
+mod dry_run;
 mod error;
 pub use error::*;
 mod linux;
 pub use linux::LinuxInstallCommand;
+mod lock;
+pub use lock::UpdaterLock;
 mod verify;
 pub use verify::*;
 /// Release source implementations and the source abstraction used by the updater.
@@ -25,8 +37,17 @@ pub mod source;
 pub use source::*;
 mod target;
 pub use target::*;
+mod schedule;
+pub use schedule::{STAGED_INSTALL_ARG, StagedInstall};
 mod release;
-pub use release::{ReleaseManifestPlatform, RemoteRelease, RemoteReleaseInner, Update};
+mod release_notes;
+pub use release_notes::{ReleaseNotes, ReleaseSection};
+mod rollback;
+mod tls;
+pub use release::{
+    ReleaseAsset, ReleaseManifestPlatform, ReleaseTier, RemoteRelease, RemoteReleaseInner,
+    StagedUpdate, Update,
+};
 #[cfg(target_os = "macos")]
 /// macOS installation and relaunch implementation.
 ///
@@ -37,9 +58,10 @@ mod macos;
 /// Windows installation and relaunch implementation.
 ///
 /// Writes the downloaded installer to a temporary location and launches it with
-/// elevation using `ShellExecuteW` and the `runas` verb. Handles common error
+/// elevation using `ShellExecuteW` and the `runas` verb, or `open` when the
+/// process is already elevated. Handles common error
 /// cases like access denied or user-cancelled elevation.
 mod windows;
-pub use source::github::GitHubSource;
+pub use source::github::{GitHubSource, try_parse_version};
 mod utils;
-pub use utils::{BundleType, extract_path_from_executable};
+pub use utils::{BundleType, detect_linux_distro, extract_path_from_executable, is_elevated};
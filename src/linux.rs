@@ -1,10 +1,12 @@
 //! Linux-specific installation helpers.
 
+#[cfg(target_os = "linux")]
+use crate::Updater;
 use crate::{Error, InstallerKind, Result, Update};
 use fs_err as fs;
 use std::{
     path::{Path, PathBuf},
-    process::Command,
+    process::{Command, Stdio},
 };
 
 /// Linux command description for package-manager-backed installs.
@@ -19,8 +21,11 @@ pub struct LinuxInstallCommand {
 impl LinuxInstallCommand {
     /// Builds the Linux install command for a staged artifact.
     ///
-    /// `.deb` and `.rpm` artifacts are installed through `pkexec`, while
-    /// AppImages are staged through `install` before the final atomic swap.
+    /// `.deb` and `.rpm` artifacts are installed through `pkexec`, `.snap`
+    /// artifacts through `pkexec snap install --dangerous` (required for
+    /// local files), and `.tar.gz` / `.tar.xz` archives are unpacked with `tar`
+    /// next to the artifact, while AppImages are staged through `install`
+    /// before the final atomic swap.
     pub fn for_kind(kind: InstallerKind, artifact: PathBuf) -> Result<Self> {
         let path = artifact.display().to_string();
         match kind {
@@ -41,54 +46,241 @@ impl LinuxInstallCommand {
                 program: "pkexec".into(),
                 args: vec!["rpm".into(), "-U".into(), path],
             }),
+            InstallerKind::Snap => Ok(Self {
+                program: "pkexec".into(),
+                args: vec!["snap".into(), "install".into(), "--dangerous".into(), path],
+            }),
+            InstallerKind::TarGz | InstallerKind::TarXz => {
+                let flags = if kind == InstallerKind::TarGz {
+                    "-xzf"
+                } else {
+                    "-xJf"
+                };
+                let dir = artifact
+                    .parent()
+                    .ok_or(Error::TempDirNotFound)?
+                    .display()
+                    .to_string();
+                Ok(Self {
+                    program: "tar".into(),
+                    args: vec![flags.into(), path, "-C".into(), dir],
+                })
+            }
             _ => unreachable!("non-linux installer kind"),
         }
     }
+
+    /// Elevates through `sudo -A` instead of `pkexec`.
+    ///
+    /// Commands that do not require elevation are returned unchanged.
+    pub fn with_sudo(mut self) -> Self {
+        if self.program == "pkexec" {
+            self.program = "sudo".into();
+            self.args.insert(0, "-A".into());
+        }
+        self
+    }
 }
 
 impl Update {
     pub(crate) fn install_linux(&self, bytes: &[u8]) -> Result<()> {
         if self.installer_kind == InstallerKind::AppImage {
-            return install_appimage(bytes, &self.extract_path);
+            return replace_executable(bytes, &self.extract_path);
+        }
+
+        self.package_install()
+            .run(|artifact_path| Ok(fs::write(artifact_path, bytes)?))
+    }
+
+    /// Installs an artifact stored at `path`, copying it instead of reading it into memory.
+    pub(crate) fn install_linux_file(&self, path: &Path) -> Result<()> {
+        if self.installer_kind == InstallerKind::AppImage {
+            return replace_executable_with(&self.extract_path, |staging_path| {
+                fs::copy(path, staging_path)?;
+                Ok(())
+            });
         }
 
-        let staging_dir = tempfile::Builder::new()
-            .prefix("release-hub-linux-installer-")
-            .tempdir()?;
-        let artifact_name = self
-            .download_url
-            .path_segments()
-            .and_then(|mut segments| segments.rfind(|segment| !segment.is_empty()))
-            .unwrap_or("release-hub-installer.bin");
-        let artifact_path = staging_dir.path().join(artifact_name);
+        self.package_install().run(|artifact_path| {
+            fs::copy(path, artifact_path)?;
+            Ok(())
+        })
+    }
 
-        fs::write(&artifact_path, bytes)?;
+    fn package_install(&self) -> PackageInstall<'_> {
+        PackageInstall {
+            kind: self.installer_kind.clone(),
+            artifact_name: self.artifact_name(),
+            app_name: &self.app_name,
+            extract_path: &self.extract_path,
+            temp_dir: self.temp_dir.as_deref(),
+            install_with_sudo: self.install_with_sudo,
+        }
+    }
+}
 
-        let command = LinuxInstallCommand::for_kind(self.installer_kind.clone(), artifact_path)?;
-        let status = Command::new(&command.program)
+/// Package, snap or archive install run through [`LinuxInstallCommand`].
+struct PackageInstall<'a> {
+    kind: InstallerKind,
+    artifact_name: &'a str,
+    app_name: &'a str,
+    extract_path: &'a Path,
+    temp_dir: Option<&'a Path>,
+    install_with_sudo: bool,
+}
+
+impl PackageInstall<'_> {
+    /// Stages the artifact with `stage` and runs the package command for it.
+    fn run(&self, stage: impl FnOnce(&Path) -> Result<()>) -> Result<()> {
+        // Dropping the staging directory removes partial extractions on error.
+        let staging_dir = crate::utils::temp_dir_in("release-hub-linux-installer-", self.temp_dir)?;
+        let artifact_path = staging_dir.path().join(self.artifact_name);
+
+        stage(&artifact_path)?;
+
+        let mut command = LinuxInstallCommand::for_kind(self.kind.clone(), artifact_path.clone())?;
+        if self.install_with_sudo {
+            command = command.with_sudo();
+        }
+        let output = Command::new(&command.program)
             .args(&command.args)
-            .status()?;
-        if status.success() {
+            .stdout(Stdio::inherit())
+            .stderr(Stdio::piped())
+            .output()?;
+        let status = output.status;
+        let exit_code = status.code().unwrap_or(-1);
+        match (status.success(), &self.kind) {
+            (true, InstallerKind::TarGz | InstallerKind::TarXz) => {
+                fs::remove_file(&artifact_path)?;
+                let binary = find_archive_binary(staging_dir.path(), self.app_name)?;
+                replace_executable_with(self.extract_path, |staging_path| {
+                    fs::copy(&binary, staging_path)?;
+                    Ok(())
+                })
+            }
+            (true, _) => Ok(()),
+            (false, InstallerKind::Snap) => Err(Error::SnapInstallFailed { exit_code }),
+            (false, InstallerKind::Deb | InstallerKind::Rpm) => Err(Error::PackageManagerFailed(
+                String::from_utf8_lossy(&output.stderr).trim().to_owned(),
+            )),
+            (false, _) => Err(Error::InstallerExecutionFailed(exit_code)),
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Updater {
+    /// Replaces the running AppImage at the extract path with `bytes`.
+    pub(crate) fn install_inner(&self, bytes: &[u8]) -> Result<()> {
+        replace_executable(bytes, &self.extract_path)
+    }
+
+    /// Installs the file at `path` according to the installer kind its name implies.
+    ///
+    /// Packages, snaps and archives go through their package command like
+    /// [`Update::install`]; anything else replaces the running AppImage at the
+    /// extract path.
+    pub(crate) fn install_file_inner(&self, path: &Path) -> Result<()> {
+        let kind = InstallerKind::from_path(path).unwrap_or(InstallerKind::AppImage);
+        let copy = |staging_path: &Path| -> Result<()> {
+            fs::copy(path, staging_path)?;
             Ok(())
+        };
+        match kind {
+            InstallerKind::Deb
+            | InstallerKind::Rpm
+            | InstallerKind::Snap
+            | InstallerKind::TarGz
+            | InstallerKind::TarXz => PackageInstall {
+                kind,
+                artifact_name: path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .unwrap_or_default(),
+                app_name: &self.app_name,
+                extract_path: &self.extract_path,
+                temp_dir: self.temp_dir.as_deref(),
+                install_with_sudo: self.install_with_sudo,
+            }
+            .run(copy),
+            _ => replace_executable_with(&self.extract_path, copy),
+        }
+    }
+
+    /// Re-executes the installed AppImage with the current arguments and exits.
+    pub(crate) fn relaunch_inner(&self) -> Result<()> {
+        let _ = Command::new(&self.extract_path)
+            .args(std::env::args_os().skip(1))
+            .spawn()?;
+        std::process::exit(0);
+    }
+}
+
+/// Restarts `exe` through `pkexec`, or `sudo -A` when polkit is unavailable, and exits.
+#[cfg(target_os = "linux")]
+pub(crate) fn relaunch_linux_as_admin(exe: &Path) -> Result<()> {
+    let spawned = match Command::new("pkexec").arg(exe).spawn() {
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            Command::new("sudo").arg("-A").arg(exe).spawn()
+        }
+        spawned => spawned,
+    };
+    let _ = spawned?;
+    std::process::exit(0);
+}
+
+/// Finds the file named `app_name` (case-insensitively) in an extracted archive.
+///
+/// Archives commonly wrap the binary in a directory named after the
+/// application, so a matching directory is searched one level down.
+fn find_archive_binary(dir: &Path, app_name: &str) -> Result<PathBuf> {
+    let mut app_dir = None;
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if !entry
+            .file_name()
+            .to_string_lossy()
+            .eq_ignore_ascii_case(app_name)
+        {
+            continue;
+        }
+        if entry.file_type()?.is_dir() {
+            app_dir = Some(entry.path());
         } else {
-            Err(Error::InstallerExecutionFailed(status.code().unwrap_or(-1)))
+            return Ok(entry.path());
         }
     }
+
+    match app_dir {
+        Some(app_dir) => find_archive_binary(&app_dir, app_name),
+        None => Err(Error::InvalidUpdaterFormat),
+    }
+}
+
+fn replace_executable(bytes: &[u8], target_path: &Path) -> Result<()> {
+    replace_executable_with(target_path, |staging_path| {
+        Ok(fs::write(staging_path, bytes)?)
+    })
 }
 
-fn install_appimage(bytes: &[u8], target_path: &Path) -> Result<()> {
+/// Writes the new executable next to `target_path` with `write`, then swaps it in.
+fn replace_executable_with(
+    target_path: &Path,
+    write: impl FnOnce(&Path) -> Result<()>,
+) -> Result<()> {
     if let Some(parent) = target_path.parent() {
         fs::create_dir_all(parent)?;
     }
 
     let staging_path = appimage_staging_path(target_path);
-    fs::write(&staging_path, bytes)?;
+    write(&staging_path)?;
     #[cfg(unix)]
     {
         use std::{fs::Permissions, os::unix::fs::PermissionsExt};
 
         fs::set_permissions(&staging_path, Permissions::from_mode(0o755))?;
     }
+    crate::rollback::preserve_file(target_path)?;
     fs::rename(&staging_path, target_path)?;
     Ok(())
 }
@@ -0,0 +1,84 @@
+//! Cross-process lock serializing installs of the same application.
+
+use crate::{Error, Result, Updater};
+use sha2::{Digest, Sha256};
+use std::{
+    fs::{File, OpenOptions, TryLockError},
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+/// Exclusive advisory lock on an installation, released when dropped.
+///
+/// Returned by [`Updater::acquire_lock`]. The lock is also released when the
+/// holding process exits, so a crash never leaves it stale.
+#[derive(Debug)]
+pub struct UpdaterLock {
+    file: File,
+    path: PathBuf,
+}
+
+impl UpdaterLock {
+    /// Returns the path of the lock file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for UpdaterLock {
+    fn drop(&mut self) {
+        let _ = self.file.unlock();
+    }
+}
+
+/// Returns the lock file guarding the installation at `extract_path`.
+pub(crate) fn lock_path(extract_path: &Path) -> PathBuf {
+    PathBuf::from(format!("{}.update.lock", extract_path.display()))
+}
+
+/// Returns the lock file used when the install location is not writable.
+fn fallback_lock_path(extract_path: &Path) -> PathBuf {
+    let digest = Sha256::digest(extract_path.as_os_str().as_encoded_bytes());
+    std::env::temp_dir().join(format!("release-hub-{digest:x}.update.lock"))
+}
+
+fn open_lock_file(path: &Path) -> std::io::Result<File> {
+    OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(path)
+}
+
+impl Updater {
+    /// Takes the exclusive lock serializing installs of [`Self::extract_path`] across processes.
+    ///
+    /// The lock is an advisory lock on `<extract_path>.update.lock`, or on a
+    /// file in the system temp directory when the install location is not
+    /// writable, as with Windows installs under `Program Files`.
+    /// [`Self::download_and_install`] and [`Self::check_and_update_atomic`]
+    /// take it automatically.
+    ///
+    /// Returns [`Error::LockAlreadyHeld`] when another process, or another
+    /// guard in this process, holds the lock.
+    pub fn acquire_lock(&self) -> Result<UpdaterLock> {
+        let mut path = lock_path(&self.extract_path);
+        let file = match open_lock_file(&path) {
+            Err(error)
+                if matches!(
+                    error.kind(),
+                    ErrorKind::PermissionDenied | ErrorKind::ReadOnlyFilesystem
+                ) =>
+            {
+                path = fallback_lock_path(&self.extract_path);
+                open_lock_file(&path)?
+            }
+            result => result?,
+        };
+        match file.try_lock() {
+            Ok(()) => Ok(UpdaterLock { file, path }),
+            Err(TryLockError::WouldBlock) => Err(Error::LockAlreadyHeld),
+            Err(TryLockError::Error(error)) => Err(error.into()),
+        }
+    }
+}
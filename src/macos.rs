@@ -6,83 +6,126 @@
 // Copyright (c) 2015 - Present - The Tauri Programme within The Commons Conservancy.
 // Licensed under MIT OR MIT/Apache-2.0
 
-use crate::{Error, Result, Update, Updater};
+use crate::{
+    Error, InstallSimulation, InstallerKind, Result, Update, Updater,
+    common::{extract_zip_to_dir, zip_file_entries},
+    utils::{is_dir_writable, temp_dir_in},
+};
 use fs_err as fs;
 use osakit::{Language, Script};
 use std::{
-    fs::Permissions,
-    io::Cursor,
-    os::unix::fs::PermissionsExt,
     path::{Path, PathBuf},
-    process::Command,
+    process::{Command, Stdio},
 };
-use zip::ZipArchive;
+
+/// Checks applied to a new bundle while it is still in the temporary directory.
+#[derive(Clone, Copy)]
+struct BundleChecks<'a> {
+    remove_quarantine: bool,
+    verify_codesign: bool,
+    team_id: Option<&'a str>,
+}
+
+impl BundleChecks<'_> {
+    /// Verifies the code signature of the bundle at `path`, then drops its
+    /// quarantine attribute.
+    ///
+    /// Runs before the bundle replaces the installation, so a bundle failing
+    /// verification is never moved into place nor stripped of its quarantine.
+    fn apply(&self, path: &Path) -> Result<()> {
+        if self.verify_codesign {
+            verify_codesign(path, self.team_id)?;
+        }
+        if self.remove_quarantine {
+            remove_quarantine(path);
+        }
+        Ok(())
+    }
+}
 
 impl Update {
     pub(crate) fn install_macos(&self, bytes: &[u8]) -> Result<()> {
-        install_macos_at(&self.extract_path, bytes)
+        let checks = BundleChecks {
+            remove_quarantine: self.remove_quarantine,
+            verify_codesign: self.verify_codesign,
+            team_id: self.codesign_team_id.as_deref(),
+        };
+        if self.installer_kind == InstallerKind::Dmg {
+            install_dmg_at(&self.extract_path, self.temp_dir.as_deref(), bytes, checks)
+        } else {
+            install_macos_at(&self.extract_path, self.temp_dir.as_deref(), bytes, checks)
+        }
     }
 }
 
 impl Updater {
     pub(crate) fn install_inner(&self, bytes: &[u8]) -> Result<()> {
-        install_macos_at(&self.extract_path, bytes)
+        let checks = BundleChecks {
+            remove_quarantine: self.remove_quarantine,
+            verify_codesign: self.verify_codesign,
+            team_id: self.codesign_team_id.as_deref(),
+        };
+        if is_dmg(bytes) {
+            install_dmg_at(&self.extract_path, self.temp_dir.as_deref(), bytes, checks)
+        } else {
+            install_macos_at(&self.extract_path, self.temp_dir.as_deref(), bytes, checks)
+        }
     }
 
     pub(crate) fn relaunch_inner(&self) -> Result<()> {
         relaunch_macos_at(&self.extract_path)
     }
-}
 
-fn extract_zip(bytes: &[u8], extract_path: &Path) -> Result<Vec<PathBuf>> {
-    let cursor = Cursor::new(bytes);
-    let mut archive = ZipArchive::new(cursor)?;
-    let mut extracted_files = Vec::new();
-
-    let tmp_extract_dir = tempfile::Builder::new()
-        .prefix("rust_updated_app")
-        .tempdir()?;
-
-    for i in 0..archive.len() {
-        let mut file = archive.by_index(i)?;
-        let outpath = match file.enclosed_name() {
-            Some(path) => tmp_extract_dir.path().join(path),
-            None => continue,
-        };
+    pub(crate) fn simulate_install_inner(&self, bytes: &[u8]) -> Result<InstallSimulation> {
+        let writable = self.extract_path.parent().is_some_and(is_dir_writable);
+        if is_dmg(bytes) {
+            // Disk image contents are only known once mounted.
+            return Ok(InstallSimulation::from_planned_files(
+                vec![(self.extract_path.clone(), bytes.len() as u64)],
+                !writable,
+            ));
+        }
 
-        if file.name().ends_with('/') {
-            fs::create_dir_all(&outpath)?;
-            if let Some(mode) = file.unix_mode() {
-                let permissions = Permissions::from_mode(mode);
-                fs::set_permissions(&outpath, permissions)?;
-            }
-        } else {
-            if let Some(parent) = outpath.parent()
-                && !parent.exists()
-            {
-                fs::create_dir_all(parent)?;
-            }
-            let mut outfile = fs::File::create(&outpath)?;
-            std::io::copy(&mut file, &mut outfile)?;
+        let files = zip_file_entries(bytes)?
+            .into_iter()
+            .map(|(path, size)| {
+                // The archive's `.app` bundle is moved onto the extract path.
+                let mut components = path.components();
+                let in_bundle = components
+                    .next()
+                    .is_some_and(|first| Path::new(&first).extension() == Some("app".as_ref()));
+                let relative = if in_bundle {
+                    components.as_path()
+                } else {
+                    path.as_path()
+                };
+                (self.extract_path.join(relative), size)
+            })
+            .collect();
+        Ok(InstallSimulation::from_planned_files(files, !writable))
+    }
 
-            if let Some(mode) = file.unix_mode() {
-                let permissions = Permissions::from_mode(mode);
-                fs::set_permissions(&outpath, permissions)?;
-            } else {
-                let file_name = outpath
-                    .file_name()
-                    .and_then(|name| name.to_str())
-                    .unwrap_or("");
-                let path_str = outpath.to_string_lossy();
-                if path_str.contains("Contents/MacOS/")
-                    || (!file_name.contains('.') && !path_str.contains("Contents/Resources/"))
-                {
-                    fs::set_permissions(&outpath, Permissions::from_mode(0o755))?;
-                }
-            }
+    pub(crate) fn relaunch_as_admin_inner(&self) -> Result<()> {
+        let apple_script = format!(
+            "do shell script \"open -n {app}\" with administrator privileges",
+            app = shell_arg(&self.extract_path)
+        );
+        let script = compile_apple_script(&apple_script)?;
+        if script.execute().is_err() {
+            return Err(Error::UserCancelledElevation);
         }
-        extracted_files.push(outpath);
+        std::process::exit(0);
     }
+}
+
+fn extract_zip(
+    bytes: &[u8],
+    extract_path: &Path,
+    temp_dir: Option<&Path>,
+    checks: BundleChecks<'_>,
+) -> Result<Vec<PathBuf>> {
+    let tmp_extract_dir = temp_dir_in("rust_updated_app", temp_dir)?;
+    let extracted_files = extract_zip_to_dir(bytes, tmp_extract_dir.path())?;
 
     let app_bundle = extracted_files
         .iter()
@@ -90,20 +133,24 @@ fn extract_zip(bytes: &[u8], extract_path: &Path) -> Result<Vec<PathBuf>> {
         .cloned();
 
     if let Some(app_path) = app_bundle {
-        move_app_bundle(&app_path, extract_path)?;
+        checks.apply(&app_path)?;
+        move_app_bundle(&app_path, extract_path, temp_dir)?;
     } else {
-        move_extracted_files(tmp_extract_dir.path(), extract_path)?;
+        checks.apply(tmp_extract_dir.path())?;
+        move_extracted_files(tmp_extract_dir.path(), extract_path, temp_dir)?;
     }
 
     Ok(extracted_files)
 }
 
-fn move_app_bundle(app_path: &Path, extract_path: &Path) -> Result<()> {
-    let tmp_backup_dir = tempfile::Builder::new()
-        .prefix("tauri_current_app")
-        .tempdir()?;
+fn move_app_bundle(app_path: &Path, extract_path: &Path, temp_dir: Option<&Path>) -> Result<()> {
+    let tmp_backup_dir = temp_dir_in("tauri_current_app", temp_dir)?;
+    // The replaced bundle goes straight to its rollback location, so keeping
+    // it cannot fail once the new bundle is in place.
+    let current_app = rollback_location(extract_path)?
+        .unwrap_or_else(|| tmp_backup_dir.path().join("current_app"));
 
-    let move_result = fs::rename(extract_path, tmp_backup_dir.path().join("current_app"));
+    let move_result = move_path(extract_path, &current_app);
 
     let need_authorization = if let Err(err) = move_result {
         if err.kind() == std::io::ErrorKind::PermissionDenied {
@@ -117,61 +164,60 @@ fn move_app_bundle(app_path: &Path, extract_path: &Path) -> Result<()> {
 
     if need_authorization {
         let backup_path = format!("{}.backup", extract_path.display());
+        // The replaced bundle is kept for `Updater::rollback` when a backup location exists.
+        let keep_backup = match (
+            crate::rollback::backup_dir(extract_path),
+            crate::rollback::backup_path(extract_path),
+        ) {
+            (Some(dir), Some(rollback)) => format!(
+                "rm -rf {dir} && mkdir -p {dir} && mv {backup} {rollback}",
+                dir = shell_arg(&dir),
+                backup = shell_arg(Path::new(&backup_path)),
+                rollback = shell_arg(&rollback)
+            ),
+            _ => format!("rm -rf {}", shell_arg(Path::new(&backup_path))),
+        };
+        let src = shell_arg(extract_path);
+        let backup = shell_arg(Path::new(&backup_path));
         let apple_script = format!(
-            "do shell script \"mv '{src}' '{backup}' && mv '{new}' '{src}' && rm -rf '{backup}'\" with administrator privileges",
-            src = extract_path.display(),
-            new = app_path.display(),
-            backup = backup_path
+            "do shell script \"mv {src} {backup} && mv {new} {src} && {keep_backup}\" with administrator privileges",
+            new = shell_arg(app_path),
         );
 
-        let mut script = Script::new_from_source(Language::AppleScript, &apple_script);
-        script.compile().expect("invalid AppleScript");
+        let script = compile_apple_script(&apple_script)?;
         let result = script.execute();
 
         if result.is_err() {
             let restore_script = format!(
-                "do shell script \"if [ -d '{backup}' ]; then rm -rf '{src}' && mv '{backup}' '{src}'; fi\" with administrator privileges",
-                src = extract_path.display(),
-                backup = backup_path
+                "do shell script \"if [ -d {backup} ]; then rm -rf {src} && mv {backup} {src}; fi\" with administrator privileges"
             );
-            let mut restore_script =
-                Script::new_from_source(Language::AppleScript, &restore_script);
-            restore_script.compile().expect("invalid AppleScript");
-            let _ = restore_script.execute();
+            if let Ok(restore_script) = compile_apple_script(&restore_script) {
+                let _ = restore_script.execute();
+            }
 
             return Err(Error::Io(std::io::Error::new(
                 std::io::ErrorKind::PermissionDenied,
                 "Failed to move the new app into place",
             )));
         }
-    } else {
-        let backup_path = extract_path.with_extension("backup");
-
-        if extract_path.exists() {
-            fs::rename(extract_path, &backup_path)?;
-        }
-
-        if let Err(err) = fs::rename(app_path, extract_path) {
-            if backup_path.exists() {
-                let _ = fs::rename(&backup_path, extract_path);
-            }
-            return Err(err.into());
-        }
-
-        if backup_path.exists() {
-            let _ = fs::remove_dir_all(&backup_path);
-        }
+    } else if let Err(err) = move_path(app_path, extract_path) {
+        let _ = move_path(&current_app, extract_path);
+        return Err(err.into());
     }
 
     Ok(())
 }
 
-fn move_extracted_files(extract_dir: &Path, extract_path: &Path) -> Result<()> {
-    let tmp_backup_dir = tempfile::Builder::new()
-        .prefix("rust_current_app")
-        .tempdir()?;
+fn move_extracted_files(
+    extract_dir: &Path,
+    extract_path: &Path,
+    temp_dir: Option<&Path>,
+) -> Result<()> {
+    let tmp_backup_dir = temp_dir_in("rust_current_app", temp_dir)?;
+    let current_app = rollback_location(extract_path)?
+        .unwrap_or_else(|| tmp_backup_dir.path().join("current_app"));
 
-    let move_result = fs::rename(extract_path, tmp_backup_dir.path().join("current_app"));
+    let move_result = move_path(extract_path, &current_app);
 
     let need_authorization = if let Err(err) = move_result {
         if err.kind() == std::io::ErrorKind::PermissionDenied {
@@ -185,13 +231,12 @@ fn move_extracted_files(extract_dir: &Path, extract_path: &Path) -> Result<()> {
 
     if need_authorization {
         let apple_script = format!(
-            "do shell script \"rm -rf '{src}' && mv -f '{new}' '{src}'\" with administrator privileges",
-            src = extract_path.display(),
-            new = extract_dir.display()
+            "do shell script \"rm -rf {src} && mv -f {new} {src}\" with administrator privileges",
+            src = shell_arg(extract_path),
+            new = shell_arg(extract_dir)
         );
 
-        let mut script = Script::new_from_source(Language::AppleScript, &apple_script);
-        script.compile().expect("invalid AppleScript");
+        let script = compile_apple_script(&apple_script)?;
         let result = script.execute();
 
         if result.is_err() {
@@ -200,22 +245,186 @@ fn move_extracted_files(extract_dir: &Path, extract_path: &Path) -> Result<()> {
                 "Failed to move the new app into place",
             )));
         }
-    } else {
-        if extract_path.exists() {
-            fs::remove_dir_all(extract_path)?;
+    } else if let Err(err) = move_path(extract_dir, extract_path) {
+        let _ = move_path(&current_app, extract_path);
+        return Err(err.into());
+    }
+
+    Ok(())
+}
+
+/// Renames `from` to `to`, copying with `ditto` when they are on different volumes.
+fn move_path(from: &Path, to: &Path) -> std::io::Result<()> {
+    match fs::rename(from, to) {
+        Err(error) if error.kind() == std::io::ErrorKind::CrossesDevices => {
+            // `ditto` preserves code signatures, extended attributes and symlinks.
+            let status = Command::new("ditto").arg(from).arg(to).status()?;
+            if !status.success() {
+                return Err(std::io::Error::other(format!(
+                    "`ditto` failed with {status}"
+                )));
+            }
+            if from.is_dir() {
+                fs::remove_dir_all(from)
+            } else {
+                fs::remove_file(from)
+            }
         }
-        fs::rename(extract_dir, extract_path)?;
+        result => result,
+    }
+}
+
+/// Prepares the location the installation at `extract_path` is moved to for `Updater::rollback`.
+///
+/// Returns `None` when the install directory is not writable, in which case
+/// the installation is replaced with administrator privileges instead.
+fn rollback_location(extract_path: &Path) -> Result<Option<PathBuf>> {
+    match crate::rollback::fresh_backup_path(extract_path) {
+        Err(Error::Io(error)) if error.kind() == std::io::ErrorKind::PermissionDenied => Ok(None),
+        result => result,
     }
+}
+
+/// Returns an AppleScript expression splicing `path`, shell-quoted, into a `do shell script` string.
+///
+/// `quoted form of` lets AppleScript quote the path, so quotes, spaces and
+/// other shell metacharacters in it are never interpreted by the shell.
+fn shell_arg(path: &Path) -> String {
+    let escaped = path
+        .to_string_lossy()
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"");
+    format!("\" & quoted form of \"{escaped}\" & \"")
+}
 
+/// Compiles `source` as AppleScript, reporting compilation errors as invalid input.
+fn compile_apple_script(source: &str) -> Result<Script> {
+    let mut script = Script::new_from_source(Language::AppleScript, source);
+    script.compile().map_err(|error| {
+        Error::Io(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("invalid AppleScript: {error}"),
+        ))
+    })?;
+    Ok(script)
+}
+
+fn install_macos_at(
+    extract_path: &Path,
+    temp_dir: Option<&Path>,
+    bytes: &[u8],
+    checks: BundleChecks<'_>,
+) -> Result<()> {
+    extract_zip(bytes, extract_path, temp_dir, checks)?;
+    let _ = Command::new("touch").arg(extract_path).status()?;
     Ok(())
 }
 
-fn install_macos_at(extract_path: &Path, bytes: &[u8]) -> Result<()> {
-    extract_zip(bytes, extract_path)?;
+/// Returns `true` when `bytes` end with a UDIF disk image trailer.
+fn is_dmg(bytes: &[u8]) -> bool {
+    bytes.len() >= 512 && bytes[bytes.len() - 512..].starts_with(b"koly")
+}
+
+/// Mounted disk image that is detached when dropped, even on error paths.
+struct MountedDmg {
+    mount_point: PathBuf,
+}
+
+impl MountedDmg {
+    fn attach(image: &Path, mount_point: &Path) -> Result<Self> {
+        let status = Command::new("hdiutil")
+            .arg("attach")
+            .arg("-nobrowse")
+            .arg("-quiet")
+            .arg("-mountpoint")
+            .arg(mount_point)
+            .arg(image)
+            .status()?;
+        if !status.success() {
+            return Err(Error::InstallerExecutionFailed(status.code().unwrap_or(-1)));
+        }
+        Ok(Self {
+            mount_point: mount_point.to_path_buf(),
+        })
+    }
+}
+
+impl Drop for MountedDmg {
+    fn drop(&mut self) {
+        let _ = Command::new("hdiutil")
+            .arg("detach")
+            .arg("-quiet")
+            .arg(&self.mount_point)
+            .status();
+    }
+}
+
+fn install_dmg_at(
+    extract_path: &Path,
+    temp_dir: Option<&Path>,
+    bytes: &[u8],
+    checks: BundleChecks<'_>,
+) -> Result<()> {
+    let tmp_dir = temp_dir_in("rust_updated_dmg", temp_dir)?;
+    let image_path = tmp_dir.path().join("update.dmg");
+    let mount_point = tmp_dir.path().join("volume");
+    fs::write(&image_path, bytes)?;
+    fs::create_dir_all(&mount_point)?;
+
+    let app_path = {
+        let volume = MountedDmg::attach(&image_path, &mount_point)?;
+        let bundle = fs::read_dir(&volume.mount_point)?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .find(|path| path.extension().and_then(|s| s.to_str()) == Some("app"))
+            .ok_or(Error::InvalidUpdaterFormat)?;
+        let app_path = tmp_dir.path().join(bundle.file_name().unwrap_or_default());
+
+        // `ditto` preserves code signatures, extended attributes and symlinks.
+        let status = Command::new("ditto").arg(&bundle).arg(&app_path).status()?;
+        if !status.success() {
+            return Err(Error::InstallerExecutionFailed(status.code().unwrap_or(-1)));
+        }
+        app_path
+    };
+
+    checks.apply(&app_path)?;
+    move_app_bundle(&app_path, extract_path, temp_dir)?;
     let _ = Command::new("touch").arg(extract_path).status()?;
     Ok(())
 }
 
+/// Checks the code signature of the bundle at `path` with `codesign --verify`.
+///
+/// With a `team_id`, the signature must also satisfy a requirement pinning
+/// the leaf certificate's organizational unit to it.
+fn verify_codesign(path: &Path, team_id: Option<&str>) -> Result<()> {
+    let mut command = Command::new("/usr/bin/codesign");
+    command.args(["--verify", "--deep", "--strict"]);
+    if let Some(team_id) = team_id {
+        command.arg(format!(
+            "-R=anchor trusted and certificate leaf[subject.OU] = \"{team_id}\""
+        ));
+    }
+    let status = command.arg(path).stderr(Stdio::null()).status()?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(Error::SignatureVerificationFailed)
+    }
+}
+
+/// Recursively removes the `com.apple.quarantine` attribute from `path`.
+///
+/// `xattr` fails when the attribute is absent, so errors are ignored.
+fn remove_quarantine(path: &Path) {
+    let _ = Command::new("/usr/bin/xattr")
+        .args(["-dr", "com.apple.quarantine"])
+        .arg(path)
+        .stderr(Stdio::null())
+        .status();
+}
+
 fn relaunch_macos_at(extract_path: &Path) -> Result<()> {
     let _ = Command::new("open").arg("-n").arg(extract_path).spawn()?;
     std::process::exit(0);
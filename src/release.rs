@@ -3,11 +3,38 @@
 use http::HeaderMap;
 use semver::Version;
 use serde::{Deserialize, Deserializer, Serialize, de::Error as DeError};
-use std::{collections::HashMap, ffi::OsString, path::PathBuf, time::Duration};
+use std::{collections::HashMap, ffi::OsString, net::IpAddr, path::PathBuf, time::Duration};
 use time::OffsetDateTime;
 use url::Url;
 
-use crate::InstallerKind;
+use crate::{InstallerKind, ReleaseNotes};
+
+const SECURITY_KEYWORDS: &[&str] = &["security", "cve-", "vulnerability", "exploit", "patch"];
+const BREAKING_KEYWORDS: &[&str] = &["breaking", "breaking change", "breaking:", "removed"];
+
+fn notes_mention(notes: Option<&str>, keywords: &[&str]) -> bool {
+    notes.is_some_and(|notes| {
+        let notes = notes.to_lowercase();
+        keywords.iter().any(|keyword| notes.contains(keyword))
+    })
+}
+
+/// Stability tier of a release, inferred by [`RemoteRelease::stability_tier`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReleaseTier {
+    /// Production release.
+    Stable,
+    /// Release candidate, such as `1.0.0-rc.1`.
+    ReleaseCandidate,
+    /// Beta release, such as `1.0.0-beta.2`.
+    Beta,
+    /// Alpha release, such as `1.0.0-alpha`.
+    Alpha,
+    /// Nightly, dev, or snapshot build.
+    Nightly,
+    /// Pre-release without a recognised tier.
+    Unknown,
+}
 
 /// Target-specific release payload returned by a manifest.
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -16,6 +43,24 @@ pub struct ReleaseManifestPlatform {
     pub url: Url,
     /// Detached minisign signature for the artifact.
     pub signature: String,
+    /// Source-specific asset identifier, such as the GitHub release asset ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_id: Option<u64>,
+    /// Hex-encoded SHA-256 digest of the artifact, checked after download when present.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
+}
+
+/// Downloadable file attached to a release, including non-installer assets.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct ReleaseAsset {
+    /// File name of the asset, such as `app-portable.zip`.
+    pub name: String,
+    /// Download URL for the asset.
+    pub url: Url,
+    /// Asset size in bytes, when the source reports one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
 }
 
 /// Release payload shape supported by the updater manifests.
@@ -37,13 +82,32 @@ pub enum RemoteReleaseInner {
 pub struct RemoteRelease {
     /// Remote version advertised by the source.
     pub version: Version,
+    /// Original release tag, such as `v1.2.3`, when the source exposes one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag_name: Option<String>,
+    /// Source-specific release identifier, such as the GitHub release ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub release_id: Option<u64>,
+    /// Web page for the release, such as the GitHub release page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_url: Option<Url>,
+    /// Whether the source marks the release as an unpublished draft.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub draft: bool,
+    /// Whether the source flags the release as a pre-release.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub prerelease: bool,
     /// Optional release notes or body text.
     pub notes: Option<String>,
     /// Optional publication timestamp.
+    #[serde(serialize_with = "time::serde::rfc3339::option::serialize")]
     pub pub_date: Option<OffsetDateTime>,
     /// Target-specific artifact metadata.
     #[serde(flatten)]
     pub data: RemoteReleaseInner,
+    /// Every file attached to the release, such as portable builds or source archives.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub assets: Vec<ReleaseAsset>,
     /// Additional headers required when downloading the selected artifact.
     #[serde(skip)]
     pub download_headers: HeaderMap,
@@ -58,11 +122,21 @@ impl<'de> Deserialize<'de> for RemoteRelease {
         struct InnerRemoteRelease {
             #[serde(alias = "name")]
             version: Version,
+            tag_name: Option<String>,
+            release_id: Option<u64>,
+            html_url: Option<Url>,
+            #[serde(default)]
+            draft: bool,
+            #[serde(default)]
+            prerelease: bool,
             notes: Option<String>,
             pub_date: Option<String>,
             platforms: Option<HashMap<String, ReleaseManifestPlatform>>,
             url: Option<Url>,
             signature: Option<String>,
+            sha256: Option<String>,
+            #[serde(default)]
+            assets: Vec<ReleaseAsset>,
         }
 
         let release = InnerRemoteRelease::deserialize(deserializer)?;
@@ -85,19 +159,33 @@ impl<'de> Deserialize<'de> for RemoteRelease {
                 signature: release.signature.ok_or_else(|| {
                     DeError::custom("the `signature` field was not set on the updater response")
                 })?,
+                asset_id: None,
+                sha256: release.sha256,
             }),
         };
 
         Ok(Self {
             version: release.version,
+            tag_name: release.tag_name,
+            release_id: release.release_id,
+            html_url: release.html_url,
+            draft: release.draft,
+            prerelease: release.prerelease,
             notes: release.notes,
             pub_date,
             data,
+            assets: release.assets,
             download_headers: HeaderMap::new(),
         })
     }
 }
 
+impl AsRef<Version> for RemoteRelease {
+    fn as_ref(&self) -> &Version {
+        &self.version
+    }
+}
+
 impl RemoteRelease {
     /// Returns the download URL for the requested target.
     ///
@@ -113,6 +201,80 @@ impl RemoteRelease {
         }
     }
 
+    /// Returns the release asset named exactly `name`, if any.
+    pub fn find_asset_by_name(&self, name: &str) -> Option<&ReleaseAsset> {
+        self.assets.iter().find(|asset| asset.name == name)
+    }
+
+    /// Returns `true` when the release notes mention a security fix.
+    ///
+    /// This is a case-insensitive keyword heuristic (`security`, `CVE-`,
+    /// `vulnerability`, `exploit`, `patch`) suitable for "urgent update" UI
+    /// hints, not an authoritative classification.
+    pub fn mentions_security_fix(&self) -> bool {
+        notes_mention(self.notes.as_deref(), SECURITY_KEYWORDS)
+    }
+
+    /// Returns `true` when the release notes mention a breaking change.
+    ///
+    /// Like [`Self::mentions_security_fix`], this is a case-insensitive keyword
+    /// heuristic (`breaking`, `BREAKING CHANGE`, `BREAKING:`, `removed`).
+    pub fn mentions_breaking_change(&self) -> bool {
+        notes_mention(self.notes.as_deref(), BREAKING_KEYWORDS)
+    }
+
+    /// Parses the release notes as CommonMark, when the release has any.
+    ///
+    /// [`Self::notes`] keeps the raw Markdown.
+    pub fn release_notes(&self) -> Option<ReleaseNotes> {
+        self.notes.as_deref().map(ReleaseNotes::parse)
+    }
+
+    /// Returns `true` for releases suitable for production use.
+    ///
+    /// A stable release is neither a draft nor flagged as a pre-release by
+    /// its source, and its version has no semver pre-release segment.
+    pub fn is_stable(&self) -> bool {
+        !self.draft && !self.prerelease && self.version.pre.is_empty()
+    }
+
+    /// Returns `true` when [`Self::is_stable`] does not hold.
+    pub fn is_prerelease(&self) -> bool {
+        !self.is_stable()
+    }
+
+    /// Infers the release tier from the semver pre-release segment.
+    ///
+    /// The first pre-release identifier, ignoring case and a trailing number,
+    /// is compared against `rc`, `beta`, `alpha`, and `nightly` / `dev` /
+    /// `snapshot`. Releases that are
+    /// not [stable](Self::is_stable) but carry no recognised marker are
+    /// [`ReleaseTier::Unknown`].
+    pub fn stability_tier(&self) -> ReleaseTier {
+        if self.is_stable() {
+            return ReleaseTier::Stable;
+        }
+
+        // Only the leading identifier names the tier, so `1.0.0-rc.1` and
+        // `1.0.0-rc1` are release candidates while `1.0.0-src.1` is not.
+        let label = self
+            .version
+            .pre
+            .as_str()
+            .split('.')
+            .next()
+            .unwrap_or_default()
+            .trim_end_matches(|c: char| c.is_ascii_digit())
+            .to_ascii_lowercase();
+        match label.as_str() {
+            "rc" => ReleaseTier::ReleaseCandidate,
+            "beta" => ReleaseTier::Beta,
+            "alpha" => ReleaseTier::Alpha,
+            "nightly" | "dev" | "snapshot" => ReleaseTier::Nightly,
+            _ => ReleaseTier::Unknown,
+        }
+    }
+
     /// Returns the detached signature for the requested target.
     pub fn signature(&self, target: &str) -> crate::Result<&String> {
         match &self.data {
@@ -123,13 +285,34 @@ impl RemoteRelease {
                 .ok_or_else(|| crate::Error::TargetNotFound(target.into())),
         }
     }
+
+    /// Returns the published SHA-256 digest of the artifact for `target`, if any.
+    pub fn sha256(&self, target: &str) -> Option<&str> {
+        match &self.data {
+            RemoteReleaseInner::Dynamic(platform) => platform.sha256.as_deref(),
+            RemoteReleaseInner::Static { platforms } => platforms
+                .get(target)
+                .and_then(|platform| platform.sha256.as_deref()),
+        }
+    }
+
+    /// Returns the size in bytes of the artifact for `target`, when its asset lists one.
+    pub fn asset_size(&self, target: &str) -> Option<u64> {
+        let url = self.download_url(target).ok()?;
+        self.assets
+            .iter()
+            .find(|asset| asset.url == *url)
+            .and_then(|asset| asset.size)
+    }
 }
 
 /// Ready-to-download update candidate produced by [`crate::Updater::check`].
 ///
 /// This is the fully resolved, target-specific update payload after source
-/// selection, manifest decoding, and installer-kind detection.
+/// selection, manifest decoding, and installer-kind detection. Build one
+/// outside this crate with [`Update::new`].
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct Update {
     /// Current application version.
     pub current_version: Version,
@@ -153,12 +336,42 @@ pub struct Update {
     pub installer_kind: InstallerKind,
     /// HTTP headers propagated from the updater builder.
     pub headers: HeaderMap,
-    /// Optional download timeout.
-    pub timeout: Option<Duration>,
+    /// Optional limit on establishing a connection.
+    pub connect_timeout: Option<Duration>,
+    /// Optional limit on waiting for each read of a response, including download bodies.
+    pub read_timeout: Option<Duration>,
     /// Optional proxy configuration.
     pub proxy: Option<Url>,
     /// Whether proxy configuration should be ignored.
     pub no_proxy: bool,
+    /// Optional local address used to force IPv4 or IPv6 downloads.
+    pub local_address: Option<IpAddr>,
+    /// Whether running app instances are terminated before a Windows install.
+    pub auto_terminate_running: bool,
+    /// Maximum accepted download size in bytes.
+    pub max_asset_size: Option<u64>,
+    /// Artifact size listed by the release source, checked against the downloaded bytes.
+    pub asset_size: Option<u64>,
+    /// Whether macOS installs drop the `com.apple.quarantine` attribute.
+    pub remove_quarantine: bool,
+    /// Whether macOS installs are checked with `codesign --verify`.
+    pub verify_codesign: bool,
+    /// Team identifier the installed macOS app must be signed by.
+    pub codesign_team_id: Option<String>,
+    /// Whether Windows installers are checked with `WinVerifyTrust` before running.
+    pub verify_authenticode: bool,
+    /// Signer Windows installers must be signed by.
+    pub expected_signer: Option<String>,
+    /// Whether Windows installers run without their UI.
+    pub silent_install: bool,
+    /// Whether Linux packages are installed through `sudo` instead of `pkexec`.
+    pub install_with_sudo: bool,
+    /// DER certificates that replace the built-in roots for downloads.
+    pub pinned_certificates: Vec<Vec<u8>>,
+    /// SHA-256 fingerprints the download server's leaf certificate must match.
+    pub pinned_fingerprints: Vec<String>,
+    /// PEM root certificates trusted for downloads in addition to the system roots.
+    pub root_certificates: Vec<String>,
     /// Whether invalid TLS certificates should be accepted.
     pub dangerous_accept_invalid_certs: bool,
     /// Whether invalid TLS hostnames should be accepted.
@@ -169,4 +382,121 @@ pub struct Update {
     pub app_name: String,
     /// Windows installer arguments propagated from configuration and builder overrides.
     pub installer_args: Vec<OsString>,
+    /// Directory temporary files are created in; the system temp directory when `None`.
+    pub temp_dir: Option<PathBuf>,
+}
+
+/// Downloaded and verified artifact persisted for a later install.
+///
+/// Returned by [`crate::Updater::download_and_stage`].
+#[derive(Debug, Clone)]
+pub struct StagedUpdate {
+    /// Location of the staged artifact.
+    pub path: PathBuf,
+    /// Update the staged artifact belongs to.
+    pub update: Update,
+}
+
+impl StagedUpdate {
+    /// Re-verifies the staged artifact's signature and installs it with [`Update::install`].
+    ///
+    /// Verification uses the public key and signature recorded in
+    /// [`Self::update`], so staged artifacts can be installed by a later
+    /// process. The installer is chosen by the update's
+    /// [`Update::installer_kind`], and the `updater`'s install hooks run
+    /// around it.
+    pub fn install(&self, updater: &crate::Updater) -> crate::Result<()> {
+        let bytes = fs_err::read(&self.path)?;
+        crate::verify_minisign(&bytes, &self.update.pubkey, &self.update.signature)
+            .map_err(|_| crate::Error::SignatureVerificationFailed)?;
+        updater.run_before_install(&bytes)?;
+        self.update.install(&bytes)?;
+        updater.run_after_install();
+        Ok(())
+    }
+}
+
+impl Update {
+    /// Creates an update from `current_version` to `version` that installs
+    /// the artifact at `download_url` to `extract_path` as `installer_kind`.
+    ///
+    /// Every other field starts empty or at the [`crate::UpdaterBuilder`]
+    /// default and can be set afterwards; in particular `signature` and
+    /// `pubkey` must be filled in before the download can be verified.
+    pub fn new(
+        current_version: Version,
+        version: Version,
+        download_url: Url,
+        installer_kind: InstallerKind,
+        extract_path: PathBuf,
+    ) -> Self {
+        Self {
+            current_version,
+            version,
+            date: None,
+            body: None,
+            raw_json: serde_json::Value::Null,
+            download_url,
+            signature: String::new(),
+            pubkey: String::new(),
+            target: String::new(),
+            installer_kind,
+            headers: HeaderMap::new(),
+            connect_timeout: None,
+            read_timeout: None,
+            proxy: None,
+            no_proxy: false,
+            local_address: None,
+            auto_terminate_running: false,
+            max_asset_size: None,
+            asset_size: None,
+            remove_quarantine: true,
+            verify_codesign: false,
+            codesign_team_id: None,
+            verify_authenticode: false,
+            expected_signer: None,
+            silent_install: true,
+            install_with_sudo: false,
+            pinned_certificates: Vec::new(),
+            pinned_fingerprints: Vec::new(),
+            root_certificates: Vec::new(),
+            dangerous_accept_invalid_certs: false,
+            dangerous_accept_invalid_hostnames: false,
+            extract_path,
+            app_name: String::new(),
+            installer_args: Vec::new(),
+            temp_dir: None,
+        }
+    }
+
+    /// Returns `true` when the release body mentions a security fix.
+    ///
+    /// See [`RemoteRelease::mentions_security_fix`].
+    pub fn mentions_security_fix(&self) -> bool {
+        notes_mention(self.body.as_deref(), SECURITY_KEYWORDS)
+    }
+
+    /// Returns `true` when the release body mentions a breaking change.
+    ///
+    /// See [`RemoteRelease::mentions_breaking_change`].
+    pub fn mentions_breaking_change(&self) -> bool {
+        notes_mention(self.body.as_deref(), BREAKING_KEYWORDS)
+    }
+
+    /// Parses the release body as CommonMark, when the release has one.
+    ///
+    /// See [`RemoteRelease::release_notes`].
+    pub fn release_notes(&self) -> Option<ReleaseNotes> {
+        self.body.as_deref().map(ReleaseNotes::parse)
+    }
+}
+
+impl Update {
+    /// Returns the artifact filename taken from the last non-empty URL path segment.
+    pub(crate) fn artifact_name(&self) -> &str {
+        self.download_url
+            .path_segments()
+            .and_then(|mut segments| segments.rfind(|segment| !segment.is_empty()))
+            .unwrap_or("release-hub-installer.bin")
+    }
 }
@@ -0,0 +1,252 @@
+//! Structured access to Markdown release notes.
+
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+
+/// Release notes parsed from their CommonMark source.
+///
+/// Returned by [`crate::RemoteRelease::release_notes`] and
+/// [`crate::Update::release_notes`]. The Markdown is parsed with
+/// `pulldown-cmark`, with GitHub-style strikethrough enabled; HTML is dropped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReleaseNotes {
+    markdown: String,
+}
+
+/// Part of the release notes introduced by a heading.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReleaseSection {
+    /// Heading text without markup, empty for text before the first heading.
+    pub heading: String,
+    /// Body of the section as plain text.
+    pub body: String,
+}
+
+/// Block-level element of the notes.
+#[derive(Debug, PartialEq, Eq)]
+enum Block {
+    Heading(String),
+    Paragraph(String),
+    ListItem { marker: String, text: String },
+    Code(String),
+}
+
+impl ReleaseNotes {
+    /// Wraps the Markdown body of a release.
+    pub fn parse(markdown: &str) -> Self {
+        Self {
+            markdown: markdown.to_owned(),
+        }
+    }
+
+    /// Returns the original Markdown.
+    pub fn as_markdown(&self) -> &str {
+        &self.markdown
+    }
+
+    /// Renders the notes as plain text for native text views.
+    ///
+    /// Markup is removed, soft line breaks become spaces, list items keep
+    /// their markers with bullets normalised to `-`, and blocks are separated
+    /// by blank lines.
+    pub fn plain_text(&self) -> String {
+        render(&parse_blocks(&self.markdown))
+    }
+
+    /// Splits the notes at each heading.
+    ///
+    /// Text before the first heading becomes a section with an empty
+    /// heading; it is omitted when empty.
+    pub fn sections(&self) -> Vec<ReleaseSection> {
+        let mut sections = Vec::new();
+        let mut heading = String::new();
+        let mut body = Vec::new();
+        for block in parse_blocks(&self.markdown) {
+            match block {
+                Block::Heading(text) => {
+                    if !heading.is_empty() || !body.is_empty() {
+                        sections.push(ReleaseSection {
+                            heading,
+                            body: render(&body),
+                        });
+                    }
+                    heading = text;
+                    body.clear();
+                }
+                block => body.push(block),
+            }
+        }
+        if !heading.is_empty() || !body.is_empty() {
+            sections.push(ReleaseSection {
+                heading,
+                body: render(&body),
+            });
+        }
+        sections
+    }
+}
+
+fn render(blocks: &[Block]) -> String {
+    let mut text = String::new();
+    let mut previous_was_item = false;
+    for block in blocks {
+        let is_item = matches!(block, Block::ListItem { .. });
+        if !text.is_empty() {
+            text.push_str(if is_item && previous_was_item {
+                "\n"
+            } else {
+                "\n\n"
+            });
+        }
+        match block {
+            Block::Heading(line) | Block::Paragraph(line) | Block::Code(line) => {
+                text.push_str(line)
+            }
+            Block::ListItem { marker, text: item } => {
+                text.push_str(marker);
+                text.push(' ');
+                text.push_str(item);
+            }
+        }
+        previous_was_item = is_item;
+    }
+    text
+}
+
+fn parse_blocks(markdown: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut text = String::new();
+    // Next number of each open ordered list, `None` for bullet lists.
+    let mut lists: Vec<Option<u64>> = Vec::new();
+    // Markers of the open list items, innermost last.
+    let mut items: Vec<String> = Vec::new();
+
+    for event in Parser::new_ext(markdown, Options::ENABLE_STRIKETHROUGH) {
+        match event {
+            Event::Start(Tag::List(start)) => {
+                flush_item(&mut blocks, &items, &mut text);
+                lists.push(start);
+            }
+            Event::End(TagEnd::List(_)) => {
+                lists.pop();
+            }
+            Event::Start(Tag::Item) => {
+                let marker = match lists.last_mut() {
+                    Some(Some(number)) => {
+                        *number += 1;
+                        format!("{}.", *number - 1)
+                    }
+                    _ => "-".to_owned(),
+                };
+                items.push(marker);
+            }
+            Event::End(TagEnd::Item) => {
+                flush_item(&mut blocks, &items, &mut text);
+                items.pop();
+            }
+            Event::End(TagEnd::Paragraph) if !items.is_empty() => text.push(' '),
+            Event::End(TagEnd::Paragraph) => push_block(&mut blocks, Block::Paragraph, &mut text),
+            Event::End(TagEnd::Heading(_)) => push_block(&mut blocks, Block::Heading, &mut text),
+            Event::End(TagEnd::CodeBlock) => {
+                let code = text.trim_end_matches('\n').to_owned();
+                text.clear();
+                if !code.is_empty() {
+                    blocks.push(Block::Code(code));
+                }
+            }
+            Event::Text(fragment) | Event::Code(fragment) => text.push_str(&fragment),
+            Event::SoftBreak => text.push(' '),
+            Event::HardBreak => text.push('\n'),
+            _ => {}
+        }
+    }
+    blocks
+}
+
+/// Moves the collected text into a `block`, skipping blank text.
+fn push_block(blocks: &mut Vec<Block>, block: impl FnOnce(String) -> Block, text: &mut String) {
+    let trimmed = text.trim();
+    if !trimmed.is_empty() {
+        blocks.push(block(trimmed.to_owned()));
+    }
+    text.clear();
+}
+
+/// Emits the text collected for the innermost open list item.
+fn flush_item(blocks: &mut Vec<Block>, items: &[String], text: &mut String) {
+    if let Some(marker) = items.last() {
+        push_block(
+            blocks,
+            |text| Block::ListItem {
+                marker: marker.clone(),
+                text,
+            },
+            text,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOTES: &str = "Highlights of this release.\n\
+        \n\
+        ## Features\n\
+        \n\
+        * Add **dark mode** and [`--theme`](https://example.com/theme) flag\n\
+        * Faster\n  startup\n\
+        \n\
+        Bug fixes\n\
+        ---------\n\
+        \n\
+        1. Fix `snake_case` config keys <br>\n\
+        \n\
+        ```toml\n\
+        theme = \"dark\"\n\
+        ```\n";
+
+    #[test]
+    fn plain_text_strips_markup() {
+        assert_eq!(
+            ReleaseNotes::parse(NOTES).plain_text(),
+            "Highlights of this release.\n\n\
+             Features\n\n\
+             - Add dark mode and --theme flag\n\
+             - Faster startup\n\n\
+             Bug fixes\n\n\
+             1. Fix snake_case config keys\n\n\
+             theme = \"dark\""
+        );
+    }
+
+    #[test]
+    fn sections_split_at_headings() {
+        let sections = ReleaseNotes::parse(NOTES).sections();
+
+        let headings: Vec<_> = sections.iter().map(|s| s.heading.as_str()).collect();
+        assert_eq!(headings, ["", "Features", "Bug fixes"]);
+        assert_eq!(sections[0].body, "Highlights of this release.");
+        assert_eq!(
+            sections[1].body,
+            "- Add dark mode and --theme flag\n- Faster startup"
+        );
+        assert!(sections[2].body.ends_with("theme = \"dark\""));
+    }
+
+    #[test]
+    fn inline_markup_edge_cases_are_kept_as_text() {
+        let plain = |markdown| ReleaseNotes::parse(markdown).plain_text();
+
+        assert_eq!(plain("2 * 3 = 6"), "2 * 3 = 6");
+        assert_eq!(plain(r"\*literal\*"), "*literal*");
+        assert_eq!(plain("a < b"), "a < b");
+        assert_eq!(plain("## C# support"), "C# support");
+        assert_eq!(plain("## Fixes ##"), "Fixes");
+        assert_eq!(
+            plain("see <https://example.com>"),
+            "see https://example.com"
+        );
+        assert_eq!(plain("![logo](logo.png) ~~old~~"), "logo old");
+        assert_eq!(plain("3. third\n4. fourth"), "3. third\n4. fourth");
+    }
+}
@@ -0,0 +1,184 @@
+//! Preservation of the replaced installation for [`Updater::rollback`].
+
+use crate::{Error, Result, Updater};
+use fs_err as fs;
+use sha2::{Digest, Sha256};
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+/// Directory holding preserved installations, next to the install or in `TEMP` on Windows.
+const BACKUP_DIR: &str = ".release-hub-backup";
+
+/// Returns the directory preserving the installation at `extract_path`.
+///
+/// Backups are keyed by a hash of the full installation path, so two
+/// installations sharing a file name never overwrite each other's backup.
+/// The directory is recreated for every backup, so its modification time
+/// records when the backup was taken.
+pub(crate) fn backup_dir(extract_path: &Path) -> Option<PathBuf> {
+    extract_path.file_name()?;
+    #[cfg(target_os = "windows")]
+    let root = std::env::temp_dir();
+    #[cfg(not(target_os = "windows"))]
+    let root = extract_path.parent()?.to_path_buf();
+    let digest = Sha256::digest(extract_path.as_os_str().as_encoded_bytes());
+    Some(root.join(BACKUP_DIR).join(format!("{digest:x}")))
+}
+
+/// Returns where the installation at `extract_path` is preserved.
+pub(crate) fn backup_path(extract_path: &Path) -> Option<PathBuf> {
+    Some(backup_dir(extract_path)?.join(extract_path.file_name()?))
+}
+
+/// Discards any previous backup of `extract_path` and returns the path to preserve it at.
+pub(crate) fn fresh_backup_path(extract_path: &Path) -> Result<Option<PathBuf>> {
+    let (Some(dir), Some(path)) = (backup_dir(extract_path), backup_path(extract_path)) else {
+        return Ok(None);
+    };
+    if dir.exists() {
+        fs::remove_dir_all(&dir)?;
+    }
+    fs::create_dir_all(&dir)?;
+    Ok(Some(path))
+}
+
+/// Preserves the file at `extract_path` before it is replaced, leaving it in place.
+///
+/// A hard link is used when possible so the backup costs no extra space.
+pub(crate) fn preserve_file(extract_path: &Path) -> Result<()> {
+    if !extract_path.is_file() {
+        return Ok(());
+    }
+    if let Some(backup) = fresh_backup_path(extract_path)?
+        && fs::hard_link(extract_path, &backup).is_err()
+    {
+        fs::copy(extract_path, &backup)?;
+    }
+    Ok(())
+}
+
+/// Copies the portable installation directory at `extract_path` into the backup location.
+///
+/// Only portable archives are preserved: `.msi` and `.exe` installers manage
+/// the installation themselves and are not copied for every install.
+#[cfg(target_os = "windows")]
+pub(crate) fn preserve_dir(extract_path: &Path) -> Result<()> {
+    if !extract_path.is_dir() {
+        return Ok(());
+    }
+    match fresh_backup_path(extract_path)? {
+        Some(backup) => copy_dir(extract_path, &backup),
+        None => Ok(()),
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn copy_dir(source: &Path, dest: &Path) -> Result<()> {
+    fs::create_dir_all(dest)?;
+    for entry in fs::read_dir(source)? {
+        let entry = entry?;
+        let target = dest.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
+
+/// Removes the backup of `extract_path` once it is older than `retention`.
+pub(crate) fn prune_backup(extract_path: &Path, retention: Duration) -> Result<()> {
+    let Some(dir) = backup_dir(extract_path) else {
+        return Ok(());
+    };
+    let Ok(taken_at) = fs::metadata(&dir).and_then(|metadata| metadata.modified()) else {
+        return Ok(());
+    };
+    if SystemTime::now()
+        .duration_since(taken_at)
+        .is_ok_and(|age| age > retention)
+    {
+        fs::remove_dir_all(&dir)?;
+    }
+    Ok(())
+}
+
+/// Swaps `backup` back into `extract_path`.
+#[cfg(not(target_os = "windows"))]
+fn restore(backup: &Path, extract_path: &Path) -> Result<()> {
+    if backup.is_dir() && extract_path.exists() {
+        // Directories cannot be renamed over, so the current bundle is moved aside first.
+        let replaced = backup.with_file_name(".replaced");
+        fs::rename(extract_path, &replaced)?;
+        if let Err(err) = fs::rename(backup, extract_path) {
+            let _ = fs::rename(&replaced, extract_path);
+            return Err(err.into());
+        }
+        return Ok(());
+    }
+    // Renaming a file over another replaces it atomically.
+    fs::rename(backup, extract_path)?;
+    Ok(())
+}
+
+/// Copies `backup` back over the installation directory at `extract_path`.
+#[cfg(target_os = "windows")]
+fn restore(backup: &Path, extract_path: &Path) -> Result<()> {
+    crate::windows::replace_dir_contents(backup, extract_path)
+}
+
+/// Restores the backup of `extract_path` and discards it.
+pub(crate) fn restore_backup(extract_path: &Path) -> Result<()> {
+    let backup = backup_path(extract_path)
+        .filter(|backup| backup.exists())
+        .ok_or(Error::NoRollbackAvailable)?;
+    restore(&backup, extract_path)?;
+    if let Some(dir) = backup_dir(extract_path) {
+        let _ = fs::remove_dir_all(dir);
+    }
+    Ok(())
+}
+
+impl Updater {
+    /// Returns whether the installation replaced by the last update is available to [`Self::rollback`].
+    pub fn has_rollback(&self) -> bool {
+        backup_path(&self.extract_path).is_some_and(|backup| backup.exists())
+    }
+
+    /// Restores the installation replaced by the last update.
+    ///
+    /// Installs that swap files in place keep the previous installation in a
+    /// `.release-hub-backup` directory next to [`Self::extract_path`], under
+    /// a hash of its full path: the AppImage or archive binary on Linux and
+    /// the app bundle on macOS. On Windows the directory of a portable `.zip`
+    /// installation is copied to `TEMP` before it is replaced and copied
+    /// back here. Linux package-manager installs (`.deb`, `.rpm`, snap) and
+    /// Windows `.msi` and `.exe` installers are not preserved. The backup is
+    /// consumed by a successful rollback.
+    ///
+    /// Returns [`Error::NoRollbackAvailable`] when no backup exists.
+    pub fn rollback(&self) -> Result<()> {
+        restore_backup(&self.extract_path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backups_are_keyed_by_the_full_installation_path() {
+        let first = backup_path(Path::new("/opt/one/ReleaseHub.AppImage")).unwrap();
+        let second = backup_path(Path::new("/opt/two/ReleaseHub.AppImage")).unwrap();
+
+        assert_ne!(first.parent(), second.parent());
+        assert_eq!(first.file_name(), second.file_name());
+        assert_eq!(
+            backup_path(Path::new("/opt/one/ReleaseHub.AppImage")),
+            Some(first)
+        );
+    }
+}
@@ -0,0 +1,610 @@
+//! Deferred installation of downloaded updates through the platform task scheduler.
+//!
+//! Linux uses a `systemd --user` timer, macOS a launchd agent in
+//! `~/Library/LaunchAgents`, and Windows a Task Scheduler task registered with
+//! `schtasks`. Each scheduled task relaunches the application executable with
+//! [`STAGED_INSTALL_ARG`], which the application forwards to
+//! [`Updater::install_staged`].
+
+use crate::{Error, InstallerKind, Result, Update, Updater, utils::app_data_dir};
+use fs_err as fs;
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use std::{
+    ffi::OsString,
+    path::{Path, PathBuf},
+    process::Command,
+    time::SystemTime,
+};
+use time::{OffsetDateTime, UtcOffset};
+use url::Url;
+
+/// Command-line flag passed to the application by a scheduled update task.
+///
+/// The flag is followed by the path of the staged-install manifest, which the
+/// application should hand to [`Updater::install_staged`] during startup.
+pub const STAGED_INSTALL_ARG: &str = "--release-hub-install-staged";
+
+const STAGED_DIR_NAME: &str = "staged";
+const STAGED_MANIFEST_NAME: &str = "staged.json";
+
+/// Persisted description of a downloaded update awaiting a scheduled install.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct StagedInstall {
+    /// Application name used by platform backends.
+    pub app_name: String,
+    /// Version of the staged update.
+    pub version: Version,
+    /// Verified artifact written to the staging directory.
+    pub artifact_path: PathBuf,
+    /// Minisign signature of the artifact, checked again before installing.
+    #[serde(default)]
+    pub signature: String,
+    /// Final installation target path.
+    pub extract_path: PathBuf,
+    /// Windows installer arguments forwarded to the installer.
+    pub installer_args: Vec<String>,
+    /// Platform scheduler task name used to remove the task after installing.
+    pub task_name: String,
+    /// Whether macOS installs drop the `com.apple.quarantine` attribute.
+    #[serde(default = "default_true")]
+    pub remove_quarantine: bool,
+    /// Whether macOS installs are checked with `codesign --verify`.
+    #[serde(default)]
+    pub verify_codesign: bool,
+    /// Team identifier the installed macOS app must be signed by.
+    #[serde(default)]
+    pub codesign_team_id: Option<String>,
+    /// Whether Windows installers are checked with `WinVerifyTrust` before running.
+    #[serde(default)]
+    pub verify_authenticode: bool,
+    /// Signer Windows installers must be signed by.
+    #[serde(default)]
+    pub expected_signer: Option<String>,
+    /// Whether Windows installers run without their UI.
+    #[serde(default = "default_true")]
+    pub silent_install: bool,
+    /// Whether Linux packages are installed through `sudo` instead of `pkexec`.
+    #[serde(default)]
+    pub install_with_sudo: bool,
+    /// Directory temporary files are created in; the system temp directory when `None`.
+    #[serde(default)]
+    pub temp_dir: Option<PathBuf>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl StagedInstall {
+    fn into_update(self) -> Result<Update> {
+        let installer_kind = InstallerKind::from_path(&self.artifact_path)?;
+        let download_url = Url::from_file_path(&self.artifact_path).map_err(|()| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "staged artifact path is not absolute",
+            )
+        })?;
+
+        let mut update = Update::new(
+            self.version.clone(),
+            self.version,
+            download_url,
+            installer_kind,
+            self.extract_path,
+        );
+        update.remove_quarantine = self.remove_quarantine;
+        update.verify_codesign = self.verify_codesign;
+        update.codesign_team_id = self.codesign_team_id;
+        update.verify_authenticode = self.verify_authenticode;
+        update.expected_signer = self.expected_signer;
+        update.silent_install = self.silent_install;
+        update.install_with_sudo = self.install_with_sudo;
+        update.app_name = self.app_name;
+        update.installer_args = self
+            .installer_args
+            .into_iter()
+            .map(OsString::from)
+            .collect();
+        update.temp_dir = self.temp_dir;
+        update.signature = self.signature;
+        Ok(update)
+    }
+}
+
+impl Updater {
+    /// Stages verified update bytes and schedules their installation for `time`.
+    ///
+    /// `bytes` should come from [`Update::download_with_progress`], which already verified the
+    /// signature. The artifact and a [`StagedInstall`] manifest are written to
+    /// the per-user data directory, and a platform scheduler task is created
+    /// that relaunches the current executable with [`STAGED_INSTALL_ARG`].
+    pub fn schedule_update_at(
+        &self,
+        update: &Update,
+        bytes: &[u8],
+        time: SystemTime,
+    ) -> Result<()> {
+        let staging_dir = app_data_dir(&self.app_name)?.join(STAGED_DIR_NAME);
+        fs::create_dir_all(&staging_dir)?;
+
+        let artifact_path = staging_dir.join(update.artifact_name());
+        fs::write(&artifact_path, bytes)?;
+
+        let staged = StagedInstall {
+            app_name: update.app_name.clone(),
+            version: update.version.clone(),
+            artifact_path,
+            signature: update.signature.clone(),
+            extract_path: update.extract_path.clone(),
+            installer_args: update
+                .installer_args
+                .iter()
+                .map(|arg| arg.to_string_lossy().into_owned())
+                .collect(),
+            task_name: task_name(&self.app_name),
+            remove_quarantine: update.remove_quarantine,
+            verify_codesign: update.verify_codesign,
+            codesign_team_id: update.codesign_team_id.clone(),
+            verify_authenticode: update.verify_authenticode,
+            expected_signer: update.expected_signer.clone(),
+            silent_install: update.silent_install,
+            install_with_sudo: update.install_with_sudo,
+            temp_dir: update.temp_dir.clone(),
+        };
+        let manifest_path = staging_dir.join(STAGED_MANIFEST_NAME);
+        fs::write(&manifest_path, serde_json::to_vec_pretty(&staged)?)?;
+
+        schedule_task(
+            &staged.task_name,
+            &std::env::current_exe()?,
+            &manifest_path,
+            OffsetDateTime::from(time),
+        )
+    }
+
+    /// Installs an update previously staged by [`Self::schedule_update_at`].
+    ///
+    /// Call this when the application is launched with [`STAGED_INSTALL_ARG`].
+    /// The artifact's signature is verified again against this updater's
+    /// configured public key, never one read from the manifest, and the call
+    /// fails with [`Error::SignatureVerificationFailed`] when no key is
+    /// configured or the signature does not match. The scheduler task has
+    /// fired by then and is removed before installing, so it cannot run again;
+    /// the staged files are removed only once the install succeeds, so a
+    /// failed install can be retried with the same manifest.
+    pub fn install_staged(&self, manifest_path: &Path) -> Result<()> {
+        let staged: StagedInstall = serde_json::from_slice(&fs::read(manifest_path)?)?;
+        let bytes = fs::read(&staged.artifact_path)?;
+        let task_name = staged.task_name.clone();
+        let artifact_path = staged.artifact_path.clone();
+        let mut update = staged.into_update()?;
+        if self.config.pubkey.is_empty() {
+            return Err(Error::SignatureVerificationFailed);
+        }
+        crate::verify_minisign(&bytes, &self.config.pubkey, &update.signature)
+            .map_err(|_| Error::SignatureVerificationFailed)?;
+        update.pubkey = self.config.pubkey.clone();
+
+        let _ = unschedule_task(&task_name);
+        update.install(&bytes)?;
+
+        let _ = fs::remove_file(&artifact_path);
+        let _ = fs::remove_file(manifest_path);
+        Ok(())
+    }
+}
+
+fn task_name(app_name: &str) -> String {
+    let app_name = app_name
+        .chars()
+        .map(|ch| if ch.is_ascii_alphanumeric() { ch } else { '-' })
+        .collect::<String>();
+    format!("release-hub-{app_name}-update")
+}
+
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+fn format_utc(at: OffsetDateTime, separator: char, suffix: &str) -> String {
+    let at = at.to_offset(UtcOffset::UTC);
+    format!(
+        "{:04}-{:02}-{:02}{separator}{:02}:{:02}:{:02}{suffix}",
+        at.year(),
+        u8::from(at.month()),
+        at.day(),
+        at.hour(),
+        at.minute(),
+        at.second()
+    )
+}
+
+fn run_command(program: &str, args: &[&str]) -> Result<()> {
+    let status = Command::new(program).args(args).status()?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(Error::Io(std::io::Error::other(format!(
+            "`{program}` failed with {status}"
+        ))))
+    }
+}
+
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn systemd_service_unit(program: &Path, manifest_path: &Path) -> String {
+    format!(
+        "[Unit]\nDescription=Install staged application update\n\n[Service]\nType=oneshot\nExecStart=\"{}\" {STAGED_INSTALL_ARG} \"{}\"\n",
+        program.display(),
+        manifest_path.display()
+    )
+}
+
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn systemd_timer_unit(at: OffsetDateTime) -> String {
+    format!(
+        "[Unit]\nDescription=Scheduled application update\n\n[Timer]\nOnCalendar={}\nPersistent=true\n\n[Install]\nWantedBy=timers.target\n",
+        format_utc(at, ' ', " UTC")
+    )
+}
+
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn launchd_plist(label: &str, program: &Path, manifest_path: &Path, at: OffsetDateTime) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+    <key>Label</key>
+    <string>{label}</string>
+    <key>ProgramArguments</key>
+    <array>
+        <string>{program}</string>
+        <string>{STAGED_INSTALL_ARG}</string>
+        <string>{manifest}</string>
+    </array>
+    <key>LaunchOnlyOnce</key>
+    <true/>
+    <key>StartCalendarInterval</key>
+    <dict>
+        <key>Month</key>
+        <integer>{month}</integer>
+        <key>Day</key>
+        <integer>{day}</integer>
+        <key>Hour</key>
+        <integer>{hour}</integer>
+        <key>Minute</key>
+        <integer>{minute}</integer>
+    </dict>
+</dict>
+</plist>
+"#,
+        label = xml_escape(label),
+        program = xml_escape(&program.display().to_string()),
+        manifest = xml_escape(&manifest_path.display().to_string()),
+        month = u8::from(at.month()),
+        day = at.day(),
+        hour = at.hour(),
+        minute = at.minute(),
+    )
+}
+
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn task_scheduler_xml(program: &Path, manifest_path: &Path, at: OffsetDateTime) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-16"?>
+<Task version="1.2" xmlns="http://schemas.microsoft.com/windows/2004/02/mit/task">
+  <Triggers>
+    <TimeTrigger>
+      <StartBoundary>{start}</StartBoundary>
+      <Enabled>true</Enabled>
+    </TimeTrigger>
+  </Triggers>
+  <Settings>
+    <StartWhenAvailable>true</StartWhenAvailable>
+  </Settings>
+  <Actions Context="Author">
+    <Exec>
+      <Command>{program}</Command>
+      <Arguments>{STAGED_INSTALL_ARG} "{manifest}"</Arguments>
+    </Exec>
+  </Actions>
+</Task>
+"#,
+        start = format_utc(at, 'T', "Z"),
+        program = xml_escape(&program.display().to_string()),
+        manifest = xml_escape(&manifest_path.display().to_string()),
+    )
+}
+
+#[cfg(target_os = "linux")]
+fn systemd_user_dir() -> Result<PathBuf> {
+    std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .map(|config| config.join("systemd/user"))
+        .ok_or_else(|| {
+            Error::Io(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "failed to determine the systemd user unit directory",
+            ))
+        })
+}
+
+#[cfg(target_os = "linux")]
+fn schedule_task(
+    name: &str,
+    program: &Path,
+    manifest_path: &Path,
+    at: OffsetDateTime,
+) -> Result<()> {
+    let unit_dir = systemd_user_dir()?;
+    fs::create_dir_all(&unit_dir)?;
+    fs::write(
+        unit_dir.join(format!("{name}.service")),
+        systemd_service_unit(program, manifest_path),
+    )?;
+    fs::write(
+        unit_dir.join(format!("{name}.timer")),
+        systemd_timer_unit(at),
+    )?;
+
+    run_command("systemctl", &["--user", "daemon-reload"])?;
+    run_command("systemctl", &["--user", "start", &format!("{name}.timer")])
+}
+
+#[cfg(target_os = "linux")]
+fn unschedule_task(name: &str) -> Result<()> {
+    let unit_dir = systemd_user_dir()?;
+    let _ = run_command("systemctl", &["--user", "stop", &format!("{name}.timer")]);
+    let _ = fs::remove_file(unit_dir.join(format!("{name}.timer")));
+    let _ = fs::remove_file(unit_dir.join(format!("{name}.service")));
+    run_command("systemctl", &["--user", "daemon-reload"])
+}
+
+#[cfg(target_os = "macos")]
+fn launch_agent_path(name: &str) -> Result<PathBuf> {
+    std::env::var_os("HOME")
+        .map(|home| {
+            PathBuf::from(home)
+                .join("Library/LaunchAgents")
+                .join(format!("{name}.plist"))
+        })
+        .ok_or_else(|| {
+            Error::Io(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "failed to determine the LaunchAgents directory",
+            ))
+        })
+}
+
+#[cfg(target_os = "macos")]
+fn local_offset() -> UtcOffset {
+    Command::new("date")
+        .arg("+%z")
+        .output()
+        .ok()
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .and_then(|offset| {
+            let offset = offset.trim();
+            let sign = if offset.starts_with('-') { -1 } else { 1 };
+            let hours = offset.get(1..3)?.parse::<i8>().ok()?;
+            let minutes = offset.get(3..5)?.parse::<i8>().ok()?;
+            UtcOffset::from_hms(sign * hours, sign * minutes, 0).ok()
+        })
+        .unwrap_or(UtcOffset::UTC)
+}
+
+#[cfg(target_os = "macos")]
+fn schedule_task(
+    name: &str,
+    program: &Path,
+    manifest_path: &Path,
+    at: OffsetDateTime,
+) -> Result<()> {
+    let plist_path = launch_agent_path(name)?;
+    if let Some(parent) = plist_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(
+        &plist_path,
+        launchd_plist(name, program, manifest_path, at.to_offset(local_offset())),
+    )?;
+
+    run_command("launchctl", &["load", "-w", &plist_path.to_string_lossy()])
+}
+
+// `StartCalendarInterval` has no year, so the agent would otherwise fire again
+// on the same date every year. It is `LaunchOnlyOnce` for the current session
+// and removing the plist keeps it from being loaded at the next login; it is
+// not unloaded because that would terminate the install it launched.
+#[cfg(target_os = "macos")]
+fn unschedule_task(name: &str) -> Result<()> {
+    fs::remove_file(launch_agent_path(name)?)?;
+    Ok(())
+}
+
+#[cfg(target_os = "windows")]
+fn schedule_task(
+    name: &str,
+    program: &Path,
+    manifest_path: &Path,
+    at: OffsetDateTime,
+) -> Result<()> {
+    let xml = task_scheduler_xml(program, manifest_path, at);
+    let mut contents = vec![0xFF, 0xFE];
+    contents.extend(xml.encode_utf16().flat_map(u16::to_le_bytes));
+    let xml_path = manifest_path.with_extension("xml");
+    fs::write(&xml_path, contents)?;
+
+    let result = run_command(
+        "schtasks",
+        &[
+            "/Create",
+            "/TN",
+            name,
+            "/XML",
+            &xml_path.to_string_lossy(),
+            "/F",
+        ],
+    );
+    let _ = fs::remove_file(&xml_path);
+    result
+}
+
+#[cfg(target_os = "windows")]
+fn unschedule_task(name: &str) -> Result<()> {
+    run_command("schtasks", &["/Delete", "/TN", name, "/F"])
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+fn schedule_task(
+    _name: &str,
+    _program: &Path,
+    _manifest_path: &Path,
+    _at: OffsetDateTime,
+) -> Result<()> {
+    Err(Error::UnsupportedOs)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+fn unschedule_task(_name: &str) -> Result<()> {
+    Err(Error::UnsupportedOs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scheduler_definitions_use_utc_start_time() {
+        let at = OffsetDateTime::from_unix_timestamp(1_792_031_400).unwrap();
+        let program = Path::new("/opt/My App/app");
+        let manifest = Path::new("/data/staged.json");
+
+        assert!(systemd_timer_unit(at).contains("OnCalendar=2026-10-15 02:30:00 UTC"));
+        assert!(systemd_service_unit(program, manifest).contains(
+            "ExecStart=\"/opt/My App/app\" --release-hub-install-staged \"/data/staged.json\""
+        ));
+        assert!(
+            task_scheduler_xml(program, manifest, at)
+                .contains("<StartBoundary>2026-10-15T02:30:00Z</StartBoundary>")
+        );
+    }
+
+    #[test]
+    fn launchd_plist_runs_once_at_the_scheduled_time() {
+        let at = OffsetDateTime::from_unix_timestamp(1_792_031_400).unwrap();
+        let plist = launchd_plist(
+            "release-hub-App-update",
+            Path::new("/opt/My App/app"),
+            Path::new("/data/a&b/staged.json"),
+            at,
+        );
+
+        assert!(plist.contains("<key>Label</key>\n    <string>release-hub-App-update</string>"));
+        assert!(plist.contains(
+            "<string>/opt/My App/app</string>\n        <string>--release-hub-install-staged</string>\n        <string>/data/a&amp;b/staged.json</string>"
+        ));
+        assert!(plist.contains("<key>LaunchOnlyOnce</key>\n    <true/>"));
+        for (key, value) in [("Month", 10), ("Day", 15), ("Hour", 2), ("Minute", 30)] {
+            assert!(plist.contains(&format!(
+                "<key>{key}</key>\n        <integer>{value}</integer>"
+            )));
+        }
+    }
+
+    #[test]
+    fn task_names_are_sanitized() {
+        assert_eq!(task_name("My App!"), "release-hub-My-App--update");
+    }
+
+    #[test]
+    fn staged_installs_keep_the_updater_verification_settings() {
+        let manifest = serde_json::json!({
+            "app_name": "App",
+            "version": "1.0.1",
+            "artifact_path": "/data/staged/app.AppImage",
+            "extract_path": "/opt/app/app.AppImage",
+            "installer_args": [],
+            "task_name": "release-hub-App-update",
+        });
+        let mut staged: StagedInstall = serde_json::from_value(manifest).unwrap();
+        assert!(staged.remove_quarantine);
+        assert!(staged.silent_install);
+        assert!(!staged.verify_authenticode);
+        assert_eq!(staged.temp_dir, None);
+
+        staged.verify_codesign = true;
+        staged.verify_authenticode = true;
+        staged.expected_signer = Some("Example Corp".into());
+        staged.temp_dir = Some("/data/tmp".into());
+        let update = staged.into_update().unwrap();
+        assert!(update.verify_codesign);
+        assert!(update.verify_authenticode);
+        assert_eq!(update.expected_signer.as_deref(), Some("Example Corp"));
+        assert_eq!(update.temp_dir.as_deref(), Some(Path::new("/data/tmp")));
+    }
+
+    fn staged_manifest(dir: &Path) -> (PathBuf, PathBuf) {
+        let artifact_path = dir.join("app.AppImage");
+        fs::write(&artifact_path, b"tampered").unwrap();
+        let manifest_path = dir.join(STAGED_MANIFEST_NAME);
+        let manifest = serde_json::json!({
+            "app_name": "App",
+            "version": "1.0.1",
+            "artifact_path": artifact_path,
+            "signature": "invalid",
+            "pubkey": "",
+            "extract_path": dir.join("installed.AppImage"),
+            "installer_args": [],
+            "task_name": "release-hub-App-update",
+        });
+        fs::write(&manifest_path, manifest.to_string()).unwrap();
+        (artifact_path, manifest_path)
+    }
+
+    fn updater_with_pubkey(pubkey: &str) -> Updater {
+        let config = crate::Config {
+            endpoints: vec![Url::parse("https://example.com/latest.json").unwrap()],
+            pubkey: pubkey.into(),
+            ..Default::default()
+        };
+        crate::UpdaterBuilder::new("App", "1.0.0", config)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn staged_install_keeps_files_when_signature_does_not_verify() {
+        let dir = tempfile::tempdir().unwrap();
+        let (artifact_path, manifest_path) = staged_manifest(dir.path());
+        let updater =
+            updater_with_pubkey("RWTLYoQkCwlVXvZrMd4XSF5F1bZpcJmSvr/1J7Q6yTo+4C9Ql6uCw7Nt");
+
+        assert!(matches!(
+            updater.install_staged(&manifest_path),
+            Err(Error::SignatureVerificationFailed)
+        ));
+        assert!(artifact_path.exists());
+        assert!(manifest_path.exists());
+    }
+
+    #[test]
+    fn staged_install_requires_a_configured_pubkey() {
+        let dir = tempfile::tempdir().unwrap();
+        let (artifact_path, manifest_path) = staged_manifest(dir.path());
+
+        assert!(matches!(
+            updater_with_pubkey("").install_staged(&manifest_path),
+            Err(Error::SignatureVerificationFailed)
+        ));
+        assert!(artifact_path.exists());
+    }
+}
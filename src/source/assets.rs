@@ -0,0 +1,224 @@
+//! Asset-matching helpers shared by the hosted release sources.
+
+use crate::{Error, InstallerKind, ReleaseAsset, Result, SourceRequest, SystemInfo};
+use std::path::Path;
+
+/// Release asset that can be matched against a target by filename.
+pub(crate) trait NamedAsset {
+    /// Returns the asset filename as published on the release.
+    fn asset_name(&self) -> &str;
+
+    /// Describes the asset for [`SourceRequest::asset_filter`].
+    fn to_release_asset(&self) -> ReleaseAsset;
+}
+
+impl NamedAsset for octocrab::models::repos::Asset {
+    fn asset_name(&self) -> &str {
+        &self.name
+    }
+
+    fn to_release_asset(&self) -> ReleaseAsset {
+        ReleaseAsset {
+            name: self.name.clone(),
+            url: self.browser_download_url.clone(),
+            size: u64::try_from(self.size).ok(),
+        }
+    }
+}
+
+fn is_signature_asset(name: &str) -> bool {
+    name.ends_with(".sig") || name.ends_with(".minisig")
+}
+
+fn target_variants(target: &str) -> Vec<String> {
+    let mut variants = vec![
+        target.to_ascii_lowercase(),
+        target.replace('-', "_").to_ascii_lowercase(),
+        target.replace('_', "-").to_ascii_lowercase(),
+    ];
+    if let Some((os, arch)) = target.split_once('-') {
+        variants.push(format!("{arch}-{os}").to_ascii_lowercase());
+    }
+    if let Some(system) = SystemInfo::from_target(target) {
+        variants.push(String::from(&system));
+    }
+    // Linux artifacts are often named after the distribution they were built on.
+    if let Some(arch) = target.strip_prefix("linux-") {
+        for distro in ["ubuntu", "debian"] {
+            variants.push(format!("{distro}-{arch}"));
+            variants.push(format!("{distro}_{arch}"));
+        }
+    }
+    variants
+}
+
+/// Returns whether `name` is a macOS app built for both Intel and Apple Silicon.
+///
+/// Such assets carry a `universal` or `all` marker in place of the
+/// architecture, such as `myapp-macos-universal.app.zip`.
+fn is_universal_macos_asset(name: &str) -> bool {
+    let lowercase = name.to_ascii_lowercase();
+    let universal = lowercase
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .any(|token| token == "universal" || token == "all");
+    universal
+        && matches!(
+            InstallerKind::from_path(Path::new(name)),
+            Ok(InstallerKind::AppTarGz | InstallerKind::AppZip | InstallerKind::Dmg)
+        )
+}
+
+/// Compiles the [`SourceRequest::asset_name_glob`] pattern.
+pub(crate) fn asset_name_pattern(pattern: &str) -> Result<glob::Pattern> {
+    glob::Pattern::new(pattern).map_err(|error| {
+        Error::InvalidConfig(format!("invalid asset name glob `{pattern}`: {error}"))
+    })
+}
+
+/// Picks the installer asset whose filename matches `request.target`.
+///
+/// Only formats the target's platform installs are considered, see
+/// [`InstallerKind::for_target`].
+///
+/// With [`SourceRequest::asset_name_glob`] set, assets whose names do not
+/// match the pattern are skipped before the target is matched; a malformed
+/// pattern fails with [`Error::InvalidConfig`].
+///
+/// macOS targets fall back to universal assets, such as
+/// `myapp-macos-universal.app.zip`, when no asset names the architecture.
+///
+/// With [`SourceRequest::asset_filter`] set, the first matching asset that
+/// passes the filter is returned instead of applying the built-in preferences.
+pub(crate) fn select_target_asset<'a, A: NamedAsset>(
+    assets: &'a [A],
+    request: &SourceRequest,
+) -> Result<&'a A> {
+    let target = request.target.as_str();
+    let variants = target_variants(target);
+    let pattern = request
+        .asset_name_glob
+        .as_deref()
+        .map(asset_name_pattern)
+        .transpose()?;
+    let eligible = assets
+        .iter()
+        .filter(|asset| !is_signature_asset(asset.asset_name()))
+        .filter(|asset| {
+            pattern
+                .as_ref()
+                .is_none_or(|pattern| pattern.matches(asset.asset_name()))
+        })
+        .collect::<Vec<_>>();
+    let mut candidates = eligible
+        .iter()
+        .filter(|asset| {
+            let name = asset.asset_name().to_ascii_lowercase();
+            variants.iter().any(|variant| name.contains(variant))
+                && InstallerKind::for_target(Path::new(asset.asset_name()), target).is_ok()
+        })
+        .copied()
+        .collect::<Vec<_>>();
+    if candidates.is_empty() && target.starts_with("darwin-") {
+        candidates = eligible
+            .into_iter()
+            .filter(|asset| is_universal_macos_asset(asset.asset_name()))
+            .collect();
+    }
+    if let Some(filter) = &request.asset_filter {
+        return candidates
+            .into_iter()
+            .find(|asset| filter(&asset.to_release_asset()))
+            .ok_or(Error::AssetNotFound);
+    }
+    // `.app.zip` wins over `.dmg` on macOS unless `prefer_dmg` is set.
+    candidates.sort_by_key(|asset| asset.asset_name().ends_with(".dmg") != request.prefer_dmg);
+
+    // Prefer distro-specific packages, such as `app-ubuntu-x86_64.deb`, on
+    // Linux, for the host distribution first and then those it is like.
+    let distros = if target.starts_with("linux-") {
+        crate::utils::linux_distro_ids()
+    } else {
+        Vec::new()
+    };
+    distros
+        .iter()
+        .find_map(|distro| {
+            candidates
+                .iter()
+                .find(|asset| names_distro(asset.asset_name(), distro))
+        })
+        .or_else(|| candidates.first())
+        .copied()
+        .ok_or_else(|| Error::TargetNotFound(target.into()))
+}
+
+/// Returns whether the asset `name` carries `distro` as whole name components.
+///
+/// Components are the alphanumeric runs of the name, so `arch` matches
+/// `app-arch-x86_64.pkg.tar.zst` but not `app-archive.tar.gz`.
+fn names_distro(name: &str, distro: &str) -> bool {
+    let components = |value: &str| {
+        value
+            .split(|ch: char| !ch.is_ascii_alphanumeric())
+            .filter(|component| !component.is_empty())
+            .map(str::to_ascii_lowercase)
+            .collect::<Vec<_>>()
+    };
+    let (name, distro) = (components(name), components(distro));
+    !distro.is_empty() && name.windows(distro.len()).any(|window| window == distro)
+}
+
+/// Finds the `.sig` or `.minisig` sibling published for the asset called `name`.
+pub(crate) fn find_signature_asset<'a, A: NamedAsset>(
+    assets: &'a [A],
+    name: &str,
+) -> Option<&'a A> {
+    let sig_name = format!("{name}.sig");
+    let minisig_name = format!("{name}.minisig");
+    assets
+        .iter()
+        .find(|asset| asset.asset_name() == sig_name || asset.asset_name() == minisig_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{asset_name_pattern, names_distro};
+
+    #[test]
+    fn distro_names_match_whole_components() {
+        assert!(names_distro("app-ubuntu-x86_64.deb", "ubuntu"));
+        assert!(names_distro(
+            "app_opensuse-leap_x86_64.rpm",
+            "opensuse-leap"
+        ));
+        assert!(!names_distro("app-archive-x86_64.tar.gz", "arch"));
+        assert!(!names_distro(
+            "app-opensuse-tumbleweed.rpm",
+            "opensuse-leap"
+        ));
+    }
+
+    #[test]
+    fn asset_name_patterns_match_wildcards_and_classes() {
+        let matches = |pattern, name| asset_name_pattern(pattern).unwrap().matches(name);
+
+        assert!(matches(
+            "myapp-*-x86_64-windows.exe",
+            "myapp-1.2.3-20250101-x86_64-windows.exe"
+        ));
+        assert!(matches("app-?.zip", "app-1.zip"));
+        assert!(!matches("app-?.zip", "app-10.zip"));
+        assert!(matches("app-[0-9][!a-z].tar.gz", "app-1_.tar.gz"));
+        assert!(!matches("app-[0-9].tar.gz", "app-x.tar.gz"));
+        assert!(matches("*", ""));
+        assert!(!matches("*.msi", "app.exe"));
+    }
+
+    #[test]
+    fn malformed_asset_name_patterns_are_rejected() {
+        assert!(matches!(
+            asset_name_pattern("app-[.zip"),
+            Err(crate::Error::InvalidConfig(_))
+        ));
+    }
+}
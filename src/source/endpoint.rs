@@ -4,9 +4,33 @@ use crate::{ReleaseSource, RemoteRelease, Result, SourceFuture, SourceRequest};
 use url::Url;
 
 /// Release source backed by one or more HTTP(S) manifest endpoints.
+///
+/// Endpoints serve a JSON manifest such as:
+///
+/// ```json
+/// {
+///   "version": "1.2.3",
+///   "notes": "Bug fixes",
+///   "pub_date": "2026-04-21T08:00:00Z",
+///   "platforms": {
+///     "linux-x86_64": {
+///       "url": "https://updates.example.com/MyApp-linux-x86_64.AppImage",
+///       "signature": "<minisign signature>",
+///       "sha256": "<hex digest>"
+///     }
+///   }
+/// }
+/// ```
+///
+/// `platforms` is keyed by the target string derived from
+/// [`crate::SystemInfo::current`]. `notes`, `pub_date` and `sha256` are
+/// optional; when `sha256` is present, downloads are checked against it.
 #[derive(Debug, Clone)]
 pub struct EndpointSource {
     endpoints: Vec<Url>,
+    client: reqwest::Client,
+    /// Whether `client` was supplied through [`EndpointSource::with_client`].
+    custom_client: bool,
 }
 
 impl EndpointSource {
@@ -16,24 +40,49 @@ impl EndpointSource {
     /// [`crate::RemoteRelease`]. The current implementation fetches the first
     /// configured endpoint.
     pub fn new(endpoints: Vec<Url>) -> Self {
-        Self { endpoints }
+        Self {
+            endpoints,
+            client: reqwest::Client::new(),
+            custom_client: false,
+        }
+    }
+
+    /// Creates an endpoint-backed release source that fetches manifests with `client`.
+    ///
+    /// Use this to apply proxy, timeout or header settings to manifest requests.
+    pub fn with_client(endpoints: Vec<Url>, client: reqwest::Client) -> Self {
+        Self {
+            endpoints,
+            client,
+            custom_client: true,
+        }
     }
 
     pub(crate) async fn release_source_impl(
         &self,
-        _request: &SourceRequest,
+        request: &SourceRequest,
     ) -> Result<RemoteRelease> {
         let endpoint = self
             .endpoints
             .first()
             .cloned()
             .ok_or_else(|| crate::Error::Network("no endpoints configured".into()))?;
-        let body = reqwest::get(endpoint)
+        let body = self
+            .client
+            .get(endpoint)
+            .send()
             .await?
             .error_for_status()?
             .text()
             .await?;
-        Ok(serde_json::from_str(&body)?)
+        let release: RemoteRelease = serde_json::from_str(&body)?;
+        // A manifest only describes one release, so a pinned version must match it.
+        match &request.version {
+            Some(version) if *version != release.version => {
+                Err(crate::Error::VersionNotFound(version.clone()))
+            }
+            _ => Ok(release),
+        }
     }
 }
 
@@ -41,4 +90,19 @@ impl ReleaseSource for EndpointSource {
     fn fetch<'a>(&'a self, request: &'a SourceRequest) -> SourceFuture<'a> {
         Box::pin(async move { self.release_source_impl(request).await })
     }
+
+    fn connectivity_url(&self) -> Option<Url> {
+        self.endpoints.first().cloned()
+    }
+
+    fn describe(&self) -> Option<String> {
+        let endpoints = self.endpoints.iter().map(Url::as_str).collect::<Vec<_>>();
+        Some(endpoints.join(", "))
+    }
+
+    fn set_http_client(&mut self, client: reqwest::Client) {
+        if !self.custom_client {
+            self.client = client;
+        }
+    }
 }
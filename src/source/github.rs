@@ -1,19 +1,42 @@
 //! GitHub Release-backed source adapter.
 
+use super::assets::{NamedAsset, find_signature_asset, select_target_asset};
+use crate::error::RateLimited;
 use crate::{
-    Error, InstallerKind, ReleaseManifestPlatform, ReleaseSource, RemoteRelease,
-    RemoteReleaseInner, Result, SourceFuture, SourceRequest,
+    CachedRelease, Error, NotesFuture, ReleaseAsset, ReleaseCache, ReleaseManifestPlatform,
+    ReleaseSource, ReleasesFuture, RemoteRelease, RemoteReleaseInner, Result, SourceFuture,
+    SourceRequest, UpdateChannel,
 };
-use http::header::{ACCEPT, AUTHORIZATION};
-use http::{HeaderMap, HeaderValue};
+use futures_util::future::join_all;
+use http::header::{ACCEPT, AUTHORIZATION, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use http::{HeaderMap, HeaderValue, StatusCode, Uri};
+use http_body_util::BodyExt;
 use octocrab::{
-    Octocrab,
+    AuthState, OctoBody, Octocrab, OctocrabBuilder,
     models::repos::{Asset, Release},
+    service::middleware::{auth_header::AuthHeaderLayer, base_uri::BaseUriLayer},
 };
 use semver::Version;
 use serde_json::json;
-use std::{collections::HashMap, path::Path};
+use std::{
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 use time::OffsetDateTime;
+use tower::{BoxError, Layer, Service};
+use url::Url;
+
+/// Page size used when listing releases, GitHub's maximum.
+const RELEASES_PER_PAGE: u8 = 100;
+/// Most pages of releases fetched when searching the release history.
+const MAX_RELEASE_PAGES: usize = 10;
+/// GitHub REST API root probed by connectivity checks.
+const GITHUB_API_URL: &str = "https://api.github.com";
+/// GitHub release-asset upload root, which also receives the API token.
+const GITHUB_UPLOAD_URL: &str = "https://uploads.github.com";
 
 #[derive(Debug, Clone)]
 struct FixtureRelease {
@@ -25,6 +48,40 @@ impl ReleaseSource for GitHubSource {
     fn fetch<'a>(&'a self, request: &'a SourceRequest) -> SourceFuture<'a> {
         Box::pin(async move { self.release_source_impl(request).await })
     }
+
+    fn fetch_releases<'a>(
+        &'a self,
+        request: &'a SourceRequest,
+        limit: usize,
+    ) -> ReleasesFuture<'a> {
+        Box::pin(async move { self.releases_impl(request, limit).await })
+    }
+
+    fn fetch_notes<'a>(&'a self, request: &'a SourceRequest) -> NotesFuture<'a> {
+        Box::pin(async move { self.release_notes_impl(request).await })
+    }
+
+    fn connectivity_url(&self) -> Option<Url> {
+        if self.fixture_release.is_some() {
+            return None;
+        }
+        Url::parse(GITHUB_API_URL).ok()
+    }
+
+    fn describe(&self) -> Option<String> {
+        Some(format!("GitHub repository {}/{}", self.owner, self.repo))
+    }
+
+    fn set_http_client(&mut self, client: reqwest::Client) {
+        if !self.custom_client {
+            self.client = octocrab_over(
+                client.clone(),
+                self.asset_headers.get(AUTHORIZATION).cloned(),
+                Uri::from_static(GITHUB_API_URL),
+            );
+        }
+        self.http = client;
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -33,9 +90,19 @@ struct FixtureAsset {
     value: String,
 }
 
+impl NamedAsset for FixtureAsset {
+    fn asset_name(&self) -> &str {
+        &self.name
+    }
+
+    fn to_release_asset(&self) -> ReleaseAsset {
+        fixture_download_asset(self, 0).to_release_asset()
+    }
+}
+
 #[derive(Debug, Clone)]
 enum SignatureSource<'a> {
-    Download(&'a Asset),
+    Download(&'a Asset, &'a reqwest::Client),
     Fixture(&'a str),
 }
 
@@ -47,6 +114,11 @@ enum SignatureSource<'a> {
 #[derive(Debug, Clone)]
 pub struct GitHubSource {
     client: octocrab::Octocrab,
+    /// Client for signature downloads.
+    http: reqwest::Client,
+    /// Whether `client` was supplied through [`GitHubSource::with_client`]
+    /// and must be kept when the updater provides its own transport.
+    custom_client: bool,
     owner: String,
     repo: String,
     fixture_release: Option<FixtureRelease>,
@@ -61,6 +133,8 @@ impl GitHubSource {
     pub fn new(owner: impl Into<String>, repo: impl Into<String>) -> Self {
         Self {
             client: Octocrab::default(),
+            http: reqwest::Client::new(),
+            custom_client: false,
             owner: owner.into(),
             repo: repo.into(),
             fixture_release: None,
@@ -72,13 +146,14 @@ impl GitHubSource {
     ///
     /// This enables private-repository releases and higher GitHub API rate limits. The same
     /// token is propagated to release-asset and signature downloads handled by the updater.
+    /// Anonymous sources report exhausted limits as [`Error::RateLimitExceeded`].
     pub fn with_auth_token(
         owner: impl Into<String>,
         repo: impl Into<String>,
         token: impl AsRef<str>,
     ) -> Result<Self> {
         let token = token.as_ref();
-        let client = Octocrab::builder().personal_token(token).build().unwrap();
+        let client = Octocrab::builder().personal_token(token).build()?;
         let mut asset_headers = HeaderMap::new();
         asset_headers.insert(
             AUTHORIZATION,
@@ -87,6 +162,8 @@ impl GitHubSource {
 
         Ok(Self {
             client,
+            http: reqwest::Client::new(),
+            custom_client: false,
             owner: owner.into(),
             repo: repo.into(),
             fixture_release: None,
@@ -97,7 +174,9 @@ impl GitHubSource {
     /// Creates a GitHub-backed source from a custom Octocrab client.
     ///
     /// Use this when you need a preconfigured GitHub client with custom
-    /// middleware, base URLs, or authentication strategy.
+    /// middleware, base URLs, or authentication strategy. The updater keeps
+    /// this client for API requests and only routes signature downloads
+    /// through its own transport.
     pub fn with_client(
         owner: impl Into<String>,
         repo: impl Into<String>,
@@ -105,6 +184,8 @@ impl GitHubSource {
     ) -> Self {
         Self {
             client,
+            http: reqwest::Client::new(),
+            custom_client: true,
             owner: owner.into(),
             repo: repo.into(),
             fixture_release: None,
@@ -127,6 +208,8 @@ impl GitHubSource {
     ) -> Self {
         Self {
             client: Octocrab::default(),
+            http: reqwest::Client::new(),
+            custom_client: false,
             owner: owner.into(),
             repo: repo.into(),
             fixture_release: Some(FixtureRelease {
@@ -143,23 +226,249 @@ impl GitHubSource {
         }
     }
 
+    /// Fetches a single release asset directly by its GitHub IDs.
+    ///
+    /// Use the IDs recorded in [`RemoteRelease::release_id`] and
+    /// [`ReleaseManifestPlatform::asset_id`] to re-fetch a known asset without
+    /// listing every release again. Returns [`Error::AssetNotFound`] when the
+    /// asset does not belong to that release, for example after it was
+    /// re-uploaded to a different one.
+    pub async fn get_release_asset_by_id(&self, release_id: u64, asset_id: u64) -> Result<Asset> {
+        let release = self
+            .client
+            .repos(&self.owner, &self.repo)
+            .releases()
+            .get(release_id)
+            .await?;
+        release
+            .assets
+            .into_iter()
+            .find(|asset| asset.id.0 == asset_id)
+            .ok_or(Error::AssetNotFound)
+    }
+
+    /// Lists one page of the repository's releases, most recent first.
+    ///
+    /// `page` starts at 1 and GitHub caps `per_page` at 100.
+    pub async fn list_releases(&self, per_page: u8, page: u32) -> Result<Vec<Release>> {
+        let page = self
+            .client
+            .repos(&self.owner, &self.repo)
+            .releases()
+            .list()
+            .per_page(per_page)
+            .page(page)
+            .send()
+            .await?;
+        Ok(page.items)
+    }
+
+    /// Lists releases newer than `since`, most recent first.
+    ///
+    /// Pages of GitHub's default newest-first listing are fetched one at a
+    /// time, stopping at the first release whose version is `<= since` or
+    /// after `max_pages` pages. Releases whose tags are not semantic versions
+    /// are skipped.
+    pub async fn get_releases_since(&self, since: &Version, max_pages: u8) -> Result<Vec<Release>> {
+        let mut newer = Vec::new();
+        for page_number in 1..=u32::from(max_pages) {
+            let page = self
+                .client
+                .repos(&self.owner, &self.repo)
+                .releases()
+                .list()
+                .per_page(100)
+                .page(page_number)
+                .send()
+                .await?;
+            let has_next = page.next.is_some();
+            if take_releases_newer_than(page.items, since, &mut newer) || !has_next {
+                break;
+            }
+        }
+        Ok(newer)
+    }
+
+    /// Lists the login names of the repository's contributors.
+    ///
+    /// Uses the same authenticated client as release lookups and follows all
+    /// result pages.
+    #[cfg(feature = "publisher")]
+    pub async fn list_contributors(&self) -> Result<Vec<String>> {
+        let page = self
+            .client
+            .repos(&self.owner, &self.repo)
+            .list_contributors()
+            .per_page(100)
+            .send()
+            .await?;
+        Ok(self
+            .client
+            .all_pages(page)
+            .await?
+            .into_iter()
+            .map(|contributor| contributor.author.login)
+            .collect())
+    }
+
+    /// Returns the SHA of the latest commit on the repository's default branch.
+    #[cfg(feature = "publisher")]
+    pub async fn latest_commit_sha(&self) -> Result<String> {
+        self.client
+            .repos(&self.owner, &self.repo)
+            .list_commits()
+            .per_page(1)
+            .send()
+            .await?
+            .items
+            .into_iter()
+            .next()
+            .map(|commit| commit.sha)
+            .ok_or_else(|| Error::Network("repository has no commits".into()))
+    }
+
+    /// Fetches the body of the latest GitHub release without selecting an asset.
+    ///
+    /// Returns `Ok(None)` when no release matches the request.
+    pub(crate) async fn release_notes_impl(
+        &self,
+        request: &SourceRequest,
+    ) -> Result<Option<String>> {
+        if self.fixture_release.is_some() {
+            return Ok(None);
+        }
+
+        match self.requested_release(request).await {
+            Err(Error::NoMatchingRelease) => Ok(None),
+            result => Ok(result?.body),
+        }
+    }
+
+    /// Fetches the release the request asks for.
+    ///
+    /// That is the release of [`SourceRequest::version`] when pinned, looked up
+    /// by tag, otherwise the latest release. GitHub's "latest release" endpoint skips
+    /// pre-releases, so when the request allows them, or follows a
+    /// [`SourceRequest::channel`] other than stable, recent releases are
+    /// listed instead. A channel picks the highest version among stable
+    /// releases and its own. Fails with [`Error::NoMatchingRelease`] when the
+    /// listing has no candidate.
+    async fn requested_release(&self, request: &SourceRequest) -> Result<Release> {
+        if let Some(version) = &request.version {
+            return self.release_by_version(version, &request.channel).await;
+        }
+        if request.allow_prerelease {
+            let page = self
+                .client
+                .repos(&self.owner, &self.repo)
+                .releases()
+                .list()
+                .per_page(RELEASES_PER_PAGE)
+                .send()
+                .await?;
+            return newest_release(page.items).ok_or(Error::NoMatchingRelease);
+        }
+        if request.channel != UpdateChannel::Stable {
+            return highest_channel_release(self.recent_releases().await?, &request.channel)
+                .ok_or(Error::NoMatchingRelease);
+        }
+
+        Ok(self
+            .client
+            .repos(&self.owner, &self.repo)
+            .releases()
+            .get_latest()
+            .await?)
+    }
+
+    /// Fetches the release of `version` by tag.
+    ///
+    /// Tries each tag spelling [`try_parse_version`] accepts, with the tag
+    /// prefix of `channel` first, and fails with [`Error::VersionNotFound`]
+    /// when none of them exists.
+    async fn release_by_version(
+        &self,
+        version: &Version,
+        channel: &UpdateChannel,
+    ) -> Result<Release> {
+        let channel_prefixes = channel.tag_prefix().into_iter().chain([String::new()]);
+        for channel_prefix in channel_prefixes {
+            for prefix in ["v", "", "V", "release-", "ver-"] {
+                let tag = format!("{channel_prefix}{prefix}{version}");
+                match self
+                    .client
+                    .repos(&self.owner, &self.repo)
+                    .releases()
+                    .get_by_tag(&tag)
+                    .await
+                {
+                    Ok(release) => return Ok(release),
+                    Err(octocrab::Error::GitHub { source, .. })
+                        if source.status_code == StatusCode::NOT_FOUND => {}
+                    Err(error) => return Err(error.into()),
+                }
+            }
+        }
+        Err(Error::VersionNotFound(version.clone()))
+    }
+
+    /// Lists up to [`MAX_RELEASE_PAGES`] pages of releases, most recent first.
+    async fn recent_releases(&self) -> Result<Vec<Release>> {
+        let mut page = self
+            .client
+            .repos(&self.owner, &self.repo)
+            .releases()
+            .list()
+            .per_page(RELEASES_PER_PAGE)
+            .send()
+            .await?;
+        let mut releases = std::mem::take(&mut page.items);
+        for _ in 1..MAX_RELEASE_PAGES {
+            let Some(mut next) = self.client.get_page::<Release>(&page.next).await? else {
+                break;
+            };
+            releases.append(&mut next.items);
+            page = next;
+        }
+        Ok(releases)
+    }
+
+    /// Returns when the core GitHub API rate limit resets.
+    ///
+    /// GitHub's `rate_limit` endpoint does not count against the limit, so it
+    /// still answers once the limit is exhausted.
+    async fn rate_limit_reset(&self) -> Option<SystemTime> {
+        let rate_limit = self.client.ratelimit().get().await.ok()?;
+        Some(UNIX_EPOCH + Duration::from_secs(rate_limit.resources.core.reset))
+    }
+
     /// Fetches and adapts the latest GitHub release into the crate's neutral release model.
+    ///
+    /// An exhausted rate limit is reported as [`Error::RateLimitExceeded`]
+    /// with the time it resets.
     pub(crate) async fn release_source_impl(
         &self,
         request: &SourceRequest,
     ) -> Result<RemoteRelease> {
         if let Some(fixture_release) = &self.fixture_release {
-            let asset = select_fixture_target_asset(&fixture_release.assets, &request.target)?;
-            let signature_asset =
-                find_fixture_signature_asset(&fixture_release.assets, &asset.name)
-                    .ok_or_else(|| Error::MissingSignatureAsset(asset.name.clone()))?;
+            let asset = select_target_asset(&fixture_release.assets, request)?;
+            let signature_asset = find_signature_asset(&fixture_release.assets, &asset.name)
+                .ok_or_else(|| Error::MissingSignatureAsset(asset.name.clone()))?;
             let download_asset = fixture_download_asset(asset, 1);
 
             return build_remote_release_from_assets(
                 &request.target,
-                &fixture_release.version,
-                None,
-                None,
+                ReleaseMetadata {
+                    version: channel_version(&fixture_release.version, &request.channel)?,
+                    id: None,
+                    html_url: None,
+                    draft: false,
+                    prerelease: false,
+                    tag_name: &fixture_release.version,
+                    notes: None,
+                    pub_date: None,
+                    assets: std::slice::from_ref(&download_asset),
+                },
                 &download_asset,
                 SignatureSource::Fixture(&signature_asset.value),
                 &HeaderMap::new(),
@@ -167,28 +476,231 @@ impl GitHubSource {
             .await;
         }
 
-        let release = self
-            .client
-            .repos(&self.owner, &self.repo)
-            .releases()
-            .get_latest()
-            .await?;
-        let pub_date = parse_pub_date(&release)?;
-        let asset = select_target_asset(&release.assets, &request.target)?;
+        // Only the latest stable release has a single endpoint to revalidate.
+        let cache = request.release_cache.as_ref().filter(|_| {
+            request.version.is_none()
+                && request.channel.tag_prefix().is_none()
+                && !request.allow_prerelease
+        });
+        let (release, validators) = match cache {
+            Some(cache) => match self.latest_release_if_modified(cache).await {
+                Ok(ConditionalRelease::NotModified(release)) => return Ok(*release),
+                Ok(ConditionalRelease::Modified {
+                    release,
+                    etag,
+                    last_modified,
+                }) => (Ok(*release), Some((etag, last_modified))),
+                Err(error) => (Err(error), None),
+            },
+            None => (self.requested_release(request).await, None),
+        };
+        let release = match release {
+            Err(Error::RateLimitExceeded { reset_at: None }) => {
+                return Err(Error::RateLimitExceeded {
+                    reset_at: self.rate_limit_reset().await,
+                });
+            }
+            result => result?,
+        };
+        let remote_release = self.adapt_release(&release, request).await?;
+        if let (Some(cache), Some((etag, last_modified))) = (cache, validators)
+            && let Ok(mut cached) = cache.write()
+        {
+            *cached = Some(CachedRelease {
+                etag,
+                last_modified,
+                release: remote_release.clone(),
+            });
+        }
+        Ok(remote_release)
+    }
+
+    /// Adapts `release` to the requested target, downloading its signature.
+    async fn adapt_release(
+        &self,
+        release: &Release,
+        request: &SourceRequest,
+    ) -> Result<RemoteRelease> {
+        let asset = select_target_asset(&release.assets, request)?;
+        check_asset_size(asset, request)?;
         let signature_asset = find_signature_asset(&release.assets, &asset.name)
             .ok_or_else(|| Error::MissingSignatureAsset(asset.name.clone()))?;
 
         build_remote_release_from_assets(
             &request.target,
-            &release.tag_name,
-            release.body.clone(),
-            pub_date,
+            ReleaseMetadata::from_release(release, &request.channel)?,
             asset,
-            SignatureSource::Download(signature_asset),
+            SignatureSource::Download(signature_asset, &self.http),
             &self.asset_headers,
         )
         .await
     }
+
+    /// Adapts up to `limit` of the most recent releases to the requested target.
+    ///
+    /// Drafts, pre-releases outside the requested channel unless the request
+    /// allows every pre-release, and releases that cannot be adapted, such as
+    /// those without an asset for the target, are skipped. At most
+    /// [`MAX_RELEASE_PAGES`] pages are listed, and the signatures of each
+    /// page's candidates are downloaded concurrently. When no release could
+    /// be adapted, the first error is returned.
+    async fn releases_impl(
+        &self,
+        request: &SourceRequest,
+        limit: usize,
+    ) -> Result<Vec<RemoteRelease>> {
+        if self.fixture_release.is_some() {
+            return match limit {
+                0 => Ok(Vec::new()),
+                _ => Ok(vec![self.release_source_impl(request).await?]),
+            };
+        }
+
+        let mut releases = Vec::new();
+        let mut first_error = None;
+        for page in (1..).take(MAX_RELEASE_PAGES) {
+            if releases.len() >= limit {
+                break;
+            }
+            let listed = self.list_releases(RELEASES_PER_PAGE, page).await?;
+            let exhausted = listed.len() < usize::from(RELEASES_PER_PAGE);
+            let mut candidates = listed
+                .iter()
+                .filter(|release| is_listable(release, request));
+            while releases.len() < limit {
+                let batch = candidates
+                    .by_ref()
+                    .take(limit - releases.len())
+                    .map(|release| self.adapt_release(release, request))
+                    .collect::<Vec<_>>();
+                if batch.is_empty() {
+                    break;
+                }
+                for result in join_all(batch).await {
+                    match result {
+                        Ok(release) => releases.push(release),
+                        Err(error) => {
+                            first_error.get_or_insert(error);
+                        }
+                    }
+                }
+            }
+            if exhausted {
+                break;
+            }
+        }
+
+        match first_error {
+            Some(error) if releases.is_empty() => Err(error),
+            _ => Ok(releases),
+        }
+    }
+
+    /// Requests the latest release, sending the validators of the release cached in `cache`.
+    async fn latest_release_if_modified(&self, cache: &ReleaseCache) -> Result<ConditionalRelease> {
+        let cached = cache.read().ok().and_then(|cached| cached.clone());
+        let mut headers = HeaderMap::new();
+        if let Some(cached) = &cached {
+            if let Some(etag) = &cached.etag {
+                headers.insert(IF_NONE_MATCH, HeaderValue::from_str(etag)?);
+            }
+            if let Some(last_modified) = &cached.last_modified {
+                headers.insert(IF_MODIFIED_SINCE, HeaderValue::from_str(last_modified)?);
+            }
+        }
+
+        let route = format!("/repos/{}/{}/releases/latest", self.owner, self.repo);
+        let response = self.client._get_with_headers(route, Some(headers)).await?;
+        if response.status() == StatusCode::NOT_MODIFIED
+            && let Some(cached) = cached
+        {
+            return Ok(ConditionalRelease::NotModified(Box::new(cached.release)));
+        }
+
+        let response = octocrab::map_github_error(response).await?;
+        let header = |name| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_owned)
+        };
+        let (etag, last_modified) = (header(ETAG), header(LAST_MODIFIED));
+        let body = self.client.body_to_string(response).await?;
+        Ok(ConditionalRelease::Modified {
+            release: Box::new(serde_json::from_str(&body)?),
+            etag,
+            last_modified,
+        })
+    }
+}
+
+/// Answer to a conditional request for the latest release.
+enum ConditionalRelease {
+    /// The cached release is still current.
+    NotModified(Box<RemoteRelease>),
+    /// The release changed; `etag` and `last_modified` validate the new response.
+    Modified {
+        release: Box<Release>,
+        etag: Option<String>,
+        last_modified: Option<String>,
+    },
+}
+
+/// Returns whether `release` belongs in a release listing for `request`.
+fn is_listable(release: &Release, request: &SourceRequest) -> bool {
+    !release.draft
+        && (request.allow_prerelease
+            || channel_release_version(release, &request.channel).is_some())
+}
+
+/// Appends releases newer than `since` to `newer`, returning `true` once an
+/// older or equal release shows the remaining pages can be skipped.
+fn take_releases_newer_than(
+    releases: Vec<Release>,
+    since: &Version,
+    newer: &mut Vec<Release>,
+) -> bool {
+    for release in releases {
+        match try_parse_version(&release.tag_name) {
+            Ok(version) if version <= *since => return true,
+            Ok(_) => newer.push(release),
+            Err(_) => {}
+        }
+    }
+    false
+}
+
+/// Picks the most recently published non-draft release.
+fn newest_release(releases: Vec<Release>) -> Option<Release> {
+    releases
+        .into_iter()
+        .filter(|release| !release.draft)
+        .max_by_key(|release| release.published_at)
+}
+
+/// Picks the non-draft release with the highest version among stable releases and those of `channel`.
+fn highest_channel_release(releases: Vec<Release>, channel: &UpdateChannel) -> Option<Release> {
+    releases
+        .into_iter()
+        .filter(|release| !release.draft)
+        .filter_map(|release| Some((channel_release_version(&release, channel)?, release)))
+        .max_by(|(a, _), (b, _)| a.cmp(b))
+        .map(|(_, release)| release)
+}
+
+/// Returns the version of `release` when it is stable or belongs to `channel`.
+fn channel_release_version(release: &Release, channel: &UpdateChannel) -> Option<Version> {
+    let version = channel_version(&release.tag_name, channel).ok()?;
+    let belongs = if release.prerelease && version.pre.is_empty() {
+        // Flagged pre-releases only count when the tag names the channel.
+        channel
+            .tag_prefix()
+            .is_some_and(|prefix| release.tag_name.starts_with(&prefix))
+    } else {
+        channel.includes(&release.tag_name, &version)
+    };
+    belongs.then_some(version)
 }
 
 fn fixture_asset(id: u64, name: &str, url: &str) -> Asset {
@@ -215,68 +727,98 @@ fn fixture_download_asset(asset: &FixtureAsset, id: u64) -> Asset {
     fixture_asset(id, &asset.name, &asset.value)
 }
 
-fn is_signature_asset(name: &str) -> bool {
-    name.ends_with(".sig") || name.ends_with(".minisig")
-}
-
-fn target_variants(target: &str) -> [String; 3] {
-    [
-        target.to_ascii_lowercase(),
-        target.replace('-', "_").to_ascii_lowercase(),
-        target.replace('_', "-").to_ascii_lowercase(),
-    ]
+fn check_asset_size(asset: &Asset, request: &SourceRequest) -> Result<()> {
+    let actual = asset.size.max(0) as u64;
+    match request.max_asset_size {
+        Some(limit) if actual > limit => Err(Error::AssetTooLarge { limit, actual }),
+        _ => Ok(()),
+    }
 }
 
-fn select_target_asset<'a>(assets: &'a [Asset], target: &str) -> Result<&'a Asset> {
-    let variants = target_variants(target);
-    assets
+/// Parses a release tag into a semantic version.
+///
+/// Accepts bare versions as well as the common `v`, `V`, `ver-` and
+/// `release-` tag prefixes.
+///
+/// ```
+/// use release_hub::try_parse_version;
+///
+/// assert_eq!(try_parse_version("1.2.3").unwrap().to_string(), "1.2.3");
+/// assert_eq!(try_parse_version("v1.2.3").unwrap().to_string(), "1.2.3");
+/// assert_eq!(try_parse_version("V1.2.3").unwrap().to_string(), "1.2.3");
+/// assert_eq!(try_parse_version("ver-1.2.3").unwrap().to_string(), "1.2.3");
+/// assert_eq!(try_parse_version("release-1.2.3-beta.1").unwrap().to_string(), "1.2.3-beta.1");
+/// assert!(try_parse_version("").is_err());
+/// ```
+pub fn try_parse_version(tag: &str) -> Result<Version> {
+    let version = ["release-", "ver-", "v", "V"]
         .iter()
-        .filter(|asset| !is_signature_asset(&asset.name))
-        .find(|asset| {
-            let name = asset.name.to_ascii_lowercase();
-            variants.iter().any(|variant| name.contains(variant))
-                && InstallerKind::from_path(Path::new(&asset.name)).is_ok()
-        })
-        .ok_or_else(|| Error::TargetNotFound(target.into()))
+        .find_map(|prefix| tag.strip_prefix(prefix))
+        .unwrap_or(tag);
+    Version::parse(version).map_err(Error::Semver)
 }
 
-fn select_fixture_target_asset<'a>(
-    assets: &'a [FixtureAsset],
-    target: &str,
-) -> Result<&'a FixtureAsset> {
-    let variants = target_variants(target);
-    assets
-        .iter()
-        .filter(|asset| !is_signature_asset(&asset.name))
-        .find(|asset| {
-            let name = asset.name.to_ascii_lowercase();
-            variants.iter().any(|variant| name.contains(variant))
-                && InstallerKind::from_path(Path::new(&asset.name)).is_ok()
-        })
-        .ok_or_else(|| Error::TargetNotFound(target.into()))
+/// Parses a release tag like [`try_parse_version`], after stripping the tag prefix of `channel`.
+fn channel_version(tag: &str, channel: &UpdateChannel) -> Result<Version> {
+    let tag = channel
+        .tag_prefix()
+        .and_then(|prefix| tag.strip_prefix(prefix.as_str()))
+        .unwrap_or(tag);
+    try_parse_version(tag)
 }
 
-fn find_signature_asset<'a>(assets: &'a [Asset], name: &str) -> Option<&'a Asset> {
-    let sig_name = format!("{name}.sig");
-    let minisig_name = format!("{name}.minisig");
-    assets
-        .iter()
-        .find(|asset| asset.name == sig_name || asset.name == minisig_name)
+/// Builds an Octocrab client that sends its requests through `client`.
+///
+/// Routing the GitHub API through the updater's own client applies its
+/// proxy, timeouts, custom roots and certificate pins there too. Octocrab's
+/// authorization and base-URL middleware are kept; `auth_header` is only sent
+/// to `base_uri` and the upload host.
+fn octocrab_over(
+    client: reqwest::Client,
+    auth_header: Option<HeaderValue>,
+    base_uri: Uri,
+) -> Octocrab {
+    let service = BaseUriLayer::new(base_uri.clone()).layer(ReqwestService(client));
+    let service = AuthHeaderLayer::new(auth_header, base_uri, Uri::from_static(GITHUB_UPLOAD_URL))
+        .layer(service);
+    match OctocrabBuilder::new_empty()
+        .with_service(service)
+        .with_auth(AuthState::None)
+        .build()
+    {
+        Ok(client) => client,
+        Err(never) => match never {},
+    }
 }
 
-fn find_fixture_signature_asset<'a>(
-    assets: &'a [FixtureAsset],
-    name: &str,
-) -> Option<&'a FixtureAsset> {
-    let sig_name = format!("{name}.sig");
-    let minisig_name = format!("{name}.minisig");
-    assets
-        .iter()
-        .find(|asset| asset.name == sig_name || asset.name == minisig_name)
-}
+/// Tower service executing Octocrab's requests with a [`reqwest::Client`].
+#[derive(Clone)]
+struct ReqwestService(reqwest::Client);
+
+impl Service<http::Request<OctoBody>> for ReqwestService {
+    type Response = http::Response<reqwest::Body>;
+    type Error = BoxError;
+    type Future =
+        Pin<Box<dyn Future<Output = std::result::Result<Self::Response, BoxError>> + Send>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<std::result::Result<(), BoxError>> {
+        Poll::Ready(Ok(()))
+    }
 
-fn parse_release_version(version: &str) -> Result<Version> {
-    Version::parse(version.trim_start_matches('v')).map_err(Error::Semver)
+    fn call(&mut self, request: http::Request<OctoBody>) -> Self::Future {
+        let client = self.0.clone();
+        Box::pin(async move {
+            let (parts, body) = request.into_parts();
+            let body = body.collect().await?.to_bytes();
+            let request = reqwest::Request::try_from(http::Request::from_parts(parts, body))?;
+            let response = client.execute(request).await?;
+            if let Some(limited) = RateLimited::from_response(response.status(), response.headers())
+            {
+                return Err(limited.into());
+            }
+            Ok(response.into())
+        })
+    }
 }
 
 fn parse_pub_date(release: &Release) -> Result<Option<OffsetDateTime>> {
@@ -295,7 +837,7 @@ fn parse_pub_date(release: &Release) -> Result<Option<OffsetDateTime>> {
 
 async fn load_signature(source: SignatureSource<'_>, asset_headers: &HeaderMap) -> Result<String> {
     match source {
-        SignatureSource::Download(signature_asset) => {
+        SignatureSource::Download(signature_asset, client) => {
             let download_url = if asset_headers.is_empty() {
                 signature_asset.browser_download_url.clone()
             } else {
@@ -305,7 +847,7 @@ async fn load_signature(source: SignatureSource<'_>, asset_headers: &HeaderMap)
             let mut headers = asset_headers.clone();
             headers.insert(ACCEPT, HeaderValue::from_static("application/octet-stream"));
 
-            Ok(reqwest::Client::new()
+            Ok(client
                 .get(download_url)
                 .headers(headers)
                 .send()
@@ -318,38 +860,208 @@ async fn load_signature(source: SignatureSource<'_>, asset_headers: &HeaderMap)
     }
 }
 
-async fn build_remote_release_from_assets(
-    target: &str,
-    version: &str,
+/// Release-level fields carried into the neutral release model.
+struct ReleaseMetadata<'a> {
+    version: Version,
+    id: Option<u64>,
+    html_url: Option<Url>,
+    draft: bool,
+    prerelease: bool,
+    tag_name: &'a str,
     notes: Option<String>,
     pub_date: Option<OffsetDateTime>,
+    assets: &'a [Asset],
+}
+
+impl<'a> ReleaseMetadata<'a> {
+    fn from_release(release: &'a Release, channel: &UpdateChannel) -> Result<Self> {
+        Ok(Self {
+            version: channel_version(&release.tag_name, channel)?,
+            id: Some(*release.id),
+            html_url: Some(release.html_url.clone()),
+            draft: release.draft,
+            prerelease: release.prerelease,
+            tag_name: &release.tag_name,
+            notes: release.body.clone(),
+            pub_date: parse_pub_date(release)?,
+            assets: &release.assets,
+        })
+    }
+}
+
+async fn build_remote_release_from_assets(
+    target: &str,
+    metadata: ReleaseMetadata<'_>,
     asset: &Asset,
     signature_source: SignatureSource<'_>,
     asset_headers: &HeaderMap,
 ) -> Result<RemoteRelease> {
     let signature = load_signature(signature_source, asset_headers).await?;
-    let download_url = if asset_headers.is_empty() {
-        asset.browser_download_url.clone()
-    } else {
-        asset.url.clone()
+    // Authenticated downloads go through the API URL so private repositories work.
+    let download_url = |asset: &Asset| {
+        if asset_headers.is_empty() {
+            asset.browser_download_url.clone()
+        } else {
+            asset.url.clone()
+        }
     };
     let platforms = HashMap::from([(
         target.to_string(),
         ReleaseManifestPlatform {
-            url: download_url,
+            url: download_url(asset),
             signature,
+            asset_id: Some(*asset.id),
+            sha256: None,
         },
     )]);
 
     Ok(RemoteRelease {
-        version: parse_release_version(version)?,
-        notes,
-        pub_date,
+        version: metadata.version,
+        tag_name: Some(metadata.tag_name.to_string()),
+        release_id: metadata.id,
+        html_url: metadata.html_url,
+        draft: metadata.draft,
+        prerelease: metadata.prerelease,
+        notes: metadata.notes,
+        pub_date: metadata.pub_date,
         data: RemoteReleaseInner::Static { platforms },
+        assets: metadata
+            .assets
+            .iter()
+            .map(|asset| ReleaseAsset {
+                name: asset.name.clone(),
+                url: download_url(asset),
+                size: u64::try_from(asset.size).ok(),
+            })
+            .collect(),
         download_headers: asset_headers.clone(),
     })
 }
 
+/// Test doubles for exercising the GitHub adapter without network access.
+///
+/// Enable the `test-utils` feature to use these helpers from downstream tests.
+#[cfg(feature = "test-utils")]
+pub mod testing {
+    use super::{
+        ReleaseMetadata, SignatureSource, build_remote_release_from_assets, check_asset_size,
+        fixture_asset,
+    };
+    use crate::source::assets::{find_signature_asset, select_target_asset};
+    use crate::{Error, ReleaseSource, ReleasesFuture, Result, SourceFuture, SourceRequest};
+    use http::HeaderMap;
+    use octocrab::models::repos::Release;
+    use serde_json::json;
+    use std::{collections::VecDeque, sync::Mutex};
+
+    /// Release source that replays queued GitHub releases in order.
+    ///
+    /// Each [`ReleaseSource::fetch`] call consumes the next queued entry. Assets
+    /// are matched exactly like [`super::GitHubSource`], but signature assets
+    /// are only paired, never downloaded, so the resulting signature is empty.
+    #[derive(Debug)]
+    pub struct FakeGitHubClient {
+        releases: Mutex<VecDeque<Result<Release>>>,
+    }
+
+    impl FakeGitHubClient {
+        /// Creates a fake client that returns `releases` in order.
+        pub fn new(releases: Vec<Release>) -> Self {
+            Self {
+                releases: Mutex::new(releases.into_iter().map(Ok).collect()),
+            }
+        }
+
+        /// Queues an error to be returned by a later fetch.
+        pub fn push_error(&self, error: Error) {
+            self.releases.lock().unwrap().push_back(Err(error));
+        }
+
+        async fn release_source_impl(
+            &self,
+            request: &SourceRequest,
+        ) -> Result<crate::RemoteRelease> {
+            let release = self
+                .releases
+                .lock()
+                .unwrap()
+                .pop_front()
+                .ok_or_else(|| Error::Network("no fake GitHub releases queued".into()))??;
+            let asset = select_target_asset(&release.assets, request)?;
+            check_asset_size(asset, request)?;
+            find_signature_asset(&release.assets, &asset.name)
+                .ok_or_else(|| Error::MissingSignatureAsset(asset.name.clone()))?;
+
+            build_remote_release_from_assets(
+                &request.target,
+                ReleaseMetadata::from_release(&release, &request.channel)?,
+                asset,
+                SignatureSource::Fixture(""),
+                &HeaderMap::new(),
+            )
+            .await
+        }
+    }
+
+    impl ReleaseSource for FakeGitHubClient {
+        fn fetch<'a>(&'a self, request: &'a SourceRequest) -> SourceFuture<'a> {
+            Box::pin(async move { self.release_source_impl(request).await })
+        }
+
+        fn fetch_releases<'a>(
+            &'a self,
+            request: &'a SourceRequest,
+            limit: usize,
+        ) -> ReleasesFuture<'a> {
+            Box::pin(async move {
+                let mut releases = Vec::new();
+                while releases.len() < limit && !self.releases.lock().unwrap().is_empty() {
+                    releases.push(self.release_source_impl(request).await?);
+                }
+                Ok(releases)
+            })
+        }
+    }
+
+    /// Builds a minimal published GitHub release with the given tag and assets.
+    ///
+    /// Each asset is described by its filename and size in bytes.
+    pub fn fake_release(tag: &str, assets: Vec<(&str, u64)>) -> Release {
+        let assets = assets
+            .into_iter()
+            .enumerate()
+            .map(|(index, (name, size))| {
+                let url = format!("https://github.com/fake/fake/releases/download/{tag}/{name}");
+                let mut asset = fixture_asset(index as u64 + 1, name, &url);
+                asset.size = size as i64;
+                asset
+            })
+            .collect::<Vec<_>>();
+
+        serde_json::from_value(json!({
+            "url": "https://api.github.com/repos/fake/fake/releases/1",
+            "html_url": format!("https://github.com/fake/fake/releases/tag/{tag}"),
+            "assets_url": "https://api.github.com/repos/fake/fake/releases/1/assets",
+            "upload_url": "https://uploads.github.com/repos/fake/fake/releases/1/assets{?name,label}",
+            "tarball_url": null,
+            "zipball_url": null,
+            "id": 1,
+            "node_id": "release-1",
+            "tag_name": tag,
+            "target_commitish": "main",
+            "name": tag,
+            "body": null,
+            "draft": false,
+            "prerelease": false,
+            "created_at": "2026-04-21T00:00:00Z",
+            "published_at": "2026-04-21T00:00:00Z",
+            "author": null,
+            "assets": assets
+        }))
+        .expect("fake release should deserialize")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -364,4 +1076,378 @@ mod tests {
         assert!(source.fixture_release.is_none());
         assert!(source.asset_headers.contains_key(AUTHORIZATION));
     }
+
+    #[tokio::test]
+    async fn octocrab_requests_go_through_the_updater_client() {
+        let server = httpmock::MockServer::start();
+        let api = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/repos/owner/repo")
+                .header("user-agent", "release-hub-test")
+                .header("authorization", "Bearer secret");
+            then.status(200).body("{}");
+        });
+        let client = reqwest::Client::builder()
+            .user_agent("release-hub-test")
+            .build()
+            .unwrap();
+        let octocrab = octocrab_over(
+            client,
+            Some(HeaderValue::from_static("Bearer secret")),
+            Uri::try_from(server.base_url()).unwrap(),
+        );
+
+        let response = octocrab._get("/repos/owner/repo").await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        api.assert();
+    }
+
+    #[cfg(feature = "test-utils")]
+    #[test]
+    fn releases_since_stop_at_first_known_version() {
+        use super::testing::fake_release;
+
+        let page = ["v1.3.0", "nightly", "v1.2.0", "v1.1.0", "v1.0.0"]
+            .into_iter()
+            .map(|tag| fake_release(tag, Vec::new()))
+            .collect();
+        let mut newer = Vec::new();
+
+        let stop = take_releases_newer_than(page, &Version::new(1, 1, 0), &mut newer);
+
+        assert!(stop);
+        let tags = newer
+            .iter()
+            .map(|release| release.tag_name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(tags, vec!["v1.3.0", "v1.2.0"]);
+    }
+
+    #[cfg(feature = "test-utils")]
+    #[test]
+    fn highest_channel_release_picks_highest_version_of_channel_or_stable() {
+        use super::testing::fake_release;
+
+        let release = |tag: &str, prerelease: bool| {
+            let mut value = serde_json::to_value(fake_release(tag, Vec::new())).unwrap();
+            value["prerelease"] = json!(prerelease);
+            serde_json::from_value::<Release>(value).unwrap()
+        };
+        let releases = vec![
+            release("v1.0.0", false),
+            release("nightly-v1.2.3-20250102", true),
+            release("nightly-v1.3.0-20250101", true),
+            release("beta-v1.4.0", false),
+            release("v1.1.0-beta.1", true),
+            release("v1.5.0-nightly.20260420", true),
+            release("v1.6.0-rc.1", true),
+        ];
+
+        let highest = |channel: UpdateChannel| {
+            highest_channel_release(releases.clone(), &channel).map(|release| release.tag_name)
+        };
+
+        assert_eq!(
+            highest(UpdateChannel::Nightly).as_deref(),
+            Some("v1.5.0-nightly.20260420")
+        );
+        assert_eq!(highest(UpdateChannel::Beta).as_deref(), Some("beta-v1.4.0"));
+        assert_eq!(highest("canary".into()).as_deref(), Some("v1.0.0"));
+        assert_eq!(highest(UpdateChannel::Stable).as_deref(), Some("v1.0.0"));
+        assert_eq!(highest(UpdateChannel::Beta), highest("beta".into()));
+        assert_eq!(
+            channel_version("nightly-v1.2.3-20250102", &UpdateChannel::Nightly).unwrap(),
+            Version::parse("1.2.3-20250102").unwrap()
+        );
+    }
+
+    /// Builds a source whose GitHub API requests go to `server`.
+    fn mock_source(server: &httpmock::MockServer) -> GitHubSource {
+        GitHubSource::with_client(
+            "owner",
+            "repo",
+            octocrab_over(
+                reqwest::Client::new(),
+                None,
+                Uri::try_from(server.base_url()).unwrap(),
+            ),
+        )
+    }
+
+    #[cfg(feature = "test-utils")]
+    #[tokio::test]
+    async fn release_asset_lookup_is_scoped_to_the_release() {
+        let server = httpmock::MockServer::start();
+        let release =
+            super::testing::fake_release("v1.0.0", vec![("app.tar.gz", 10), ("app.tar.gz.sig", 1)]);
+        let lookup = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/repos/owner/repo/releases/7");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(serde_json::to_value(&release).unwrap());
+        });
+        let source = mock_source(&server);
+
+        let asset = source.get_release_asset_by_id(7, 2).await.unwrap();
+        assert_eq!(asset.name, "app.tar.gz.sig");
+        assert!(matches!(
+            source.get_release_asset_by_id(7, 3).await,
+            Err(Error::AssetNotFound)
+        ));
+        lookup.assert_calls(2);
+    }
+
+    #[cfg(feature = "test-utils")]
+    #[tokio::test]
+    async fn pinned_version_is_fetched_by_tag() {
+        let server = httpmock::MockServer::start();
+        let release = super::testing::fake_release("v1.2.0", Vec::new());
+        let by_tag = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/repos/owner/repo/releases/tags/v1.2.0");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(serde_json::to_value(&release).unwrap());
+        });
+        let missing = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path_includes("/repos/owner/repo/releases/tags/")
+                .path_includes("9.9.9");
+            then.status(404)
+                .header("content-type", "application/json")
+                .json_body(json!({ "message": "Not Found" }));
+        });
+        let listing = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/repos/owner/repo/releases");
+            then.status(200).json_body(json!([]));
+        });
+        let source = mock_source(&server);
+
+        let found = source
+            .requested_release(&SourceRequest::new("linux-x86_64").version(Version::new(1, 2, 0)))
+            .await
+            .unwrap();
+        assert_eq!(found.tag_name, "v1.2.0");
+        assert!(matches!(
+            source
+                .requested_release(
+                    &SourceRequest::new("linux-x86_64").version(Version::new(9, 9, 9))
+                )
+                .await,
+            Err(Error::VersionNotFound(_))
+        ));
+        by_tag.assert_calls(1);
+        missing.assert_calls(5);
+        listing.assert_calls(0);
+    }
+
+    #[tokio::test]
+    async fn rate_limits_are_detected_from_status_and_headers() {
+        let server = httpmock::MockServer::start();
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/repos/owner/limited/releases/latest");
+            then.status(403)
+                .header("content-type", "application/json")
+                .header("x-ratelimit-remaining", "0")
+                .header("x-ratelimit-reset", "1800000000")
+                .json_body(json!({ "message": "Forbidden" }));
+        });
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/repos/owner/forbidden/releases/latest");
+            then.status(403)
+                .header("content-type", "application/json")
+                .header("x-ratelimit-remaining", "42")
+                .json_body(json!({ "message": "API rate limit documentation" }));
+        });
+        let base_uri = Uri::try_from(server.base_url()).unwrap();
+        let latest = |repo: &'static str| {
+            let client = octocrab_over(reqwest::Client::new(), None, base_uri.clone());
+            async move {
+                GitHubSource::with_client("owner", repo, client)
+                    .requested_release(&SourceRequest::new("linux-x86_64"))
+                    .await
+            }
+        };
+
+        assert!(matches!(
+            latest("limited").await,
+            Err(Error::RateLimitExceeded { reset_at: Some(reset_at) })
+                if reset_at == UNIX_EPOCH + Duration::from_secs(1_800_000_000)
+        ));
+        assert!(matches!(latest("forbidden").await, Err(Error::GitHub(_))));
+    }
+
+    #[tokio::test]
+    async fn empty_channel_reports_no_update() {
+        let server = httpmock::MockServer::start();
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/repos/owner/repo/releases");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!([]));
+        });
+        let updater = crate::UpdaterBuilder::new("ReleaseHub", "1.0.0", crate::Config::default())
+            .target("linux-x86_64")
+            .source(Box::new(mock_source(&server)))
+            .channel(UpdateChannel::Nightly)
+            .build()
+            .unwrap();
+
+        assert!(updater.check().await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn channel_lookup_stops_after_max_release_pages() {
+        let server = httpmock::MockServer::start();
+        let next = format!(
+            "<{}>; rel=\"next\"",
+            server.url("/repos/owner/repo/releases?page=2")
+        );
+        let listing = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/repos/owner/repo/releases");
+            then.status(200)
+                .header("content-type", "application/json")
+                .header("link", next.as_str())
+                .json_body(json!([]));
+        });
+        let request = SourceRequest::new("linux-x86_64").channel(UpdateChannel::Beta);
+
+        let error = mock_source(&server).fetch(&request).await.unwrap_err();
+
+        assert!(matches!(error, Error::NoMatchingRelease));
+        listing.assert_calls(MAX_RELEASE_PAGES);
+    }
+
+    #[cfg(feature = "publisher")]
+    #[tokio::test]
+    async fn publisher_lookups_read_contributors_and_latest_commit() {
+        let server = httpmock::MockServer::start();
+        let author = |login: &str| {
+            let url = format!("https://api.github.com/users/{login}");
+            json!({
+                "login": login,
+                "id": 1,
+                "node_id": "user-1",
+                "avatar_url": url,
+                "gravatar_id": "",
+                "url": url,
+                "html_url": url,
+                "followers_url": url,
+                "following_url": url,
+                "gists_url": url,
+                "starred_url": url,
+                "subscriptions_url": url,
+                "organizations_url": url,
+                "repos_url": url,
+                "events_url": url,
+                "received_events_url": url,
+                "type": "User",
+                "site_admin": false,
+            })
+        };
+        let contributors = [author("alice"), author("bob")]
+            .into_iter()
+            .map(|mut contributor| {
+                contributor["contributions"] = json!(1);
+                contributor
+            })
+            .collect::<Vec<_>>();
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/repos/owner/repo/contributors");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!(contributors));
+        });
+        let commit_url = "https://api.github.com/repos/owner/repo/git/commits/abc123";
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/repos/owner/repo/commits")
+                .query_param("per_page", "1");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!([{
+                    "url": commit_url,
+                    "sha": "abc123",
+                    "node_id": "commit-1",
+                    "html_url": commit_url,
+                    "comments_url": commit_url,
+                    "commit": {
+                        "url": commit_url,
+                        "author": null,
+                        "committer": null,
+                        "message": "Release",
+                        "comment_count": 0,
+                        "tree": { "sha": "def456", "url": commit_url },
+                    },
+                    "author": author("alice"),
+                    "committer": author("alice"),
+                    "parents": [],
+                }]));
+        });
+        let source = mock_source(&server);
+
+        assert_eq!(source.list_contributors().await.unwrap(), ["alice", "bob"]);
+        assert_eq!(source.latest_commit_sha().await.unwrap(), "abc123");
+    }
+
+    #[cfg(feature = "test-utils")]
+    #[tokio::test]
+    async fn release_listing_follows_the_channel() {
+        let server = httpmock::MockServer::start();
+        let release = |tag: &str, prerelease: bool| {
+            let assets = vec![
+                ("app-linux-x86_64.AppImage", 10),
+                ("app-linux-x86_64.AppImage.sig", 1),
+            ];
+            let mut release =
+                serde_json::to_value(super::testing::fake_release(tag, assets)).unwrap();
+            release["prerelease"] = json!(prerelease);
+            for asset in release["assets"].as_array_mut().unwrap() {
+                let name = asset["name"].as_str().unwrap().to_owned();
+                asset["browser_download_url"] =
+                    json!(server.url(format!("/download/{tag}/{name}")));
+            }
+            release
+        };
+        let releases = json!([
+            release("v1.3.0-nightly.1", true),
+            release("beta-v1.2.0-beta.1", true),
+            release("v1.1.0", false),
+            release("v1.0.0", false),
+        ]);
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/repos/owner/repo/releases");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(releases);
+        });
+        let signatures = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path_includes("/download/")
+                .path_includes(".sig");
+            then.status(200).body("signature");
+        });
+        let request = SourceRequest::new("linux-x86_64").channel(UpdateChannel::Beta);
+
+        let listed = mock_source(&server)
+            .releases_impl(&request, 2)
+            .await
+            .unwrap();
+
+        let tags = listed
+            .iter()
+            .map(|release| release.tag_name.as_deref().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(tags, ["beta-v1.2.0-beta.1", "v1.1.0"]);
+        signatures.assert_calls(2);
+    }
 }
@@ -0,0 +1,301 @@
+//! GitLab Release-backed source adapter.
+
+use super::assets::{NamedAsset, find_signature_asset, select_target_asset};
+use crate::{
+    Error, NotesFuture, ReleaseAsset, ReleaseManifestPlatform, ReleaseSource, RemoteRelease,
+    RemoteReleaseInner, Result, SourceFuture, SourceRequest, try_parse_version,
+};
+use http::{HeaderMap, HeaderValue};
+use semver::Version;
+use serde::{Deserialize, de::DeserializeOwned};
+use std::collections::HashMap;
+use time::OffsetDateTime;
+use url::Url;
+
+/// Header GitLab uses for personal, project and group access tokens.
+const PRIVATE_TOKEN: &str = "PRIVATE-TOKEN";
+
+/// Release as returned by the GitLab Releases API.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitLabRelease {
+    /// Git tag the release was created from.
+    pub tag_name: String,
+    /// Markdown release description.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// RFC 3339 timestamp at which the release was published.
+    #[serde(default)]
+    pub released_at: Option<String>,
+    /// Whether `released_at` lies in the future.
+    ///
+    /// GitLab has no pre-release flag; pre-releases are recognised by their
+    /// semver pre-release identifiers instead.
+    #[serde(default)]
+    pub upcoming_release: bool,
+    /// Links to the release itself.
+    #[serde(default, rename = "_links")]
+    pub links: GitLabReleaseLinks,
+    /// Files attached to the release.
+    #[serde(default)]
+    pub assets: GitLabReleaseAssets,
+}
+
+/// Web links published alongside a GitLab release.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct GitLabReleaseLinks {
+    /// Release page in the GitLab web UI.
+    #[serde(default, rename = "self")]
+    pub html_url: Option<Url>,
+}
+
+/// Asset section of a GitLab release.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct GitLabReleaseAssets {
+    /// Release links, which is where GitLab lists uploaded and external files.
+    #[serde(default)]
+    pub links: Vec<GitLabAsset>,
+}
+
+/// File linked from a GitLab release.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitLabAsset {
+    /// Link ID, unique within the project.
+    pub id: u64,
+    /// Filename shown on the release page.
+    pub name: String,
+    /// Target URL of the link.
+    pub url: Url,
+    /// Permanent redirecting URL for the link, when GitLab provides one.
+    #[serde(default)]
+    pub direct_asset_url: Option<Url>,
+}
+
+impl GitLabAsset {
+    /// Returns the URL the asset should be downloaded from.
+    pub fn download_url(&self) -> &Url {
+        self.direct_asset_url.as_ref().unwrap_or(&self.url)
+    }
+}
+
+impl NamedAsset for GitLabAsset {
+    fn asset_name(&self) -> &str {
+        &self.name
+    }
+
+    fn to_release_asset(&self) -> ReleaseAsset {
+        // GitLab release links do not report file sizes.
+        ReleaseAsset {
+            name: self.name.clone(),
+            url: self.download_url().clone(),
+            size: None,
+        }
+    }
+}
+
+/// Release source backed by the latest release of a GitLab project.
+///
+/// Assets are matched exactly like [`crate::GitHubSource`]: by target marker
+/// in the link name, with a sibling `.sig` or `.minisig` link carrying the
+/// signature.
+#[derive(Debug, Clone)]
+pub struct GitLabSource {
+    base_url: Url,
+    project_id: String,
+    headers: HeaderMap,
+    client: reqwest::Client,
+    /// Whether `client` was supplied through [`GitLabSource::client`].
+    custom_client: bool,
+}
+
+impl GitLabSource {
+    /// Creates a GitLab-backed release source for a public project.
+    ///
+    /// `base_url` is the instance root, such as `https://gitlab.com`, and
+    /// `project_id` is either the numeric project ID or the full
+    /// `group/project` path.
+    pub fn new(base_url: Url, project_id: impl Into<String>) -> Self {
+        Self {
+            base_url,
+            project_id: project_id.into(),
+            headers: HeaderMap::new(),
+            client: reqwest::Client::new(),
+            custom_client: false,
+        }
+    }
+
+    /// Creates a GitLab-backed source that authenticates with an access token.
+    ///
+    /// The token is sent as `PRIVATE-TOKEN` to the Releases API and propagated
+    /// to signature and artifact downloads on the same host as `base_url`, so
+    /// private projects work. Links to other hosts never receive it.
+    pub fn with_token(
+        base_url: Url,
+        project_id: impl Into<String>,
+        token: impl AsRef<str>,
+    ) -> Result<Self> {
+        let mut headers = HeaderMap::new();
+        headers.insert(PRIVATE_TOKEN, HeaderValue::from_str(token.as_ref())?);
+
+        Ok(Self {
+            base_url,
+            project_id: project_id.into(),
+            headers,
+            client: reqwest::Client::new(),
+            custom_client: false,
+        })
+    }
+
+    /// Sends every request of this source through `client`.
+    ///
+    /// Use this to apply proxy, timeout or TLS settings to GitLab requests;
+    /// [`crate::UpdaterBuilder::gitlab`] passes the updater's own client.
+    pub fn client(mut self, client: reqwest::Client) -> Self {
+        self.client = client;
+        self.custom_client = true;
+        self
+    }
+
+    /// Fetches the latest release of the project.
+    pub async fn latest_release(&self) -> Result<GitLabRelease> {
+        let url = self.project_url(&["releases", "permalink", "latest"])?;
+        self.get_json(url).await
+    }
+
+    /// Fetches the release whose tag parses to `version`.
+    ///
+    /// Only the 100 most recent releases are searched.
+    pub async fn release_by_version(&self, version: &Version) -> Result<GitLabRelease> {
+        let mut url = self.project_url(&["releases"])?;
+        url.query_pairs_mut().append_pair("per_page", "100");
+        let releases: Vec<GitLabRelease> = self.get_json(url).await?;
+        releases
+            .into_iter()
+            .find(|release| {
+                try_parse_version(&release.tag_name).is_ok_and(|parsed| parsed == *version)
+            })
+            .ok_or_else(|| Error::VersionNotFound(version.clone()))
+    }
+
+    /// Builds the API URL of `path` below the project, such as `releases`.
+    fn project_url(&self, path: &[&str]) -> Result<Url> {
+        let mut url = self.base_url.clone();
+        url.path_segments_mut()
+            .map_err(|()| Error::Network(format!("invalid GitLab base URL `{}`", self.base_url)))?
+            .pop_if_empty()
+            .extend(["api", "v4", "projects", &self.project_id])
+            .extend(path);
+        Ok(url)
+    }
+
+    /// Returns the token headers when `url` is on the configured GitLab instance.
+    fn headers_for(&self, url: &Url) -> HeaderMap {
+        if url.origin() == self.base_url.origin() {
+            self.headers.clone()
+        } else {
+            HeaderMap::new()
+        }
+    }
+
+    /// Fetches the release pinned by `request`, or the latest one.
+    async fn requested_release(&self, request: &SourceRequest) -> Result<GitLabRelease> {
+        match &request.version {
+            Some(version) => self.release_by_version(version).await,
+            None => self.latest_release().await,
+        }
+    }
+
+    async fn get_json<T: DeserializeOwned>(&self, url: Url) -> Result<T> {
+        Ok(self
+            .client
+            .get(url)
+            .headers(self.headers.clone())
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?)
+    }
+
+    pub(crate) async fn release_source_impl(
+        &self,
+        request: &SourceRequest,
+    ) -> Result<RemoteRelease> {
+        let release = self.requested_release(request).await?;
+        let assets = &release.assets.links;
+        let asset = select_target_asset(assets, request)?;
+        let signature_asset = find_signature_asset(assets, &asset.name)
+            .ok_or_else(|| Error::MissingSignatureAsset(asset.name.clone()))?;
+        let signature_url = signature_asset.download_url();
+        let signature = self
+            .client
+            .get(signature_url.clone())
+            .headers(self.headers_for(signature_url))
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+
+        let platforms = HashMap::from([(
+            request.target.clone(),
+            ReleaseManifestPlatform {
+                url: asset.download_url().clone(),
+                signature,
+                asset_id: Some(asset.id),
+                sha256: None,
+            },
+        )]);
+
+        let version = try_parse_version(&release.tag_name)?;
+        let prerelease = !version.pre.is_empty();
+        Ok(RemoteRelease {
+            version,
+            tag_name: Some(release.tag_name.clone()),
+            release_id: None,
+            html_url: release.links.html_url.clone(),
+            draft: false,
+            prerelease,
+            notes: release.description.clone(),
+            pub_date: release
+                .released_at
+                .as_deref()
+                .map(|released_at| {
+                    OffsetDateTime::parse(
+                        released_at,
+                        &time::format_description::well_known::Rfc3339,
+                    )
+                })
+                .transpose()?,
+            data: RemoteReleaseInner::Static { platforms },
+            assets: assets.iter().map(NamedAsset::to_release_asset).collect(),
+            download_headers: self.headers_for(asset.download_url()),
+        })
+    }
+}
+
+impl ReleaseSource for GitLabSource {
+    fn fetch<'a>(&'a self, request: &'a SourceRequest) -> SourceFuture<'a> {
+        Box::pin(async move { self.release_source_impl(request).await })
+    }
+
+    fn fetch_notes<'a>(&'a self, request: &'a SourceRequest) -> NotesFuture<'a> {
+        Box::pin(async move { Ok(self.requested_release(request).await?.description) })
+    }
+
+    fn connectivity_url(&self) -> Option<Url> {
+        Some(self.base_url.clone())
+    }
+
+    fn describe(&self) -> Option<String> {
+        Some(format!(
+            "GitLab project {} on {}",
+            self.project_id, self.base_url
+        ))
+    }
+
+    fn set_http_client(&mut self, client: reqwest::Client) {
+        if !self.custom_client {
+            self.client = client;
+        }
+    }
+}
@@ -1,22 +1,154 @@
 //! Release-source abstraction and built-in source implementations.
 //!
-//! Most applications can rely on [`EndpointSource`] or [`GitHubSource`], while
-//! advanced integrations can implement [`ReleaseSource`] to fetch release data
-//! from any service that can produce a [`crate::RemoteRelease`].
+//! Most applications can rely on [`EndpointSource`], [`GitHubSource`] or
+//! [`GitLabSource`], while advanced integrations can implement
+//! [`ReleaseSource`] to fetch release data from any service that can produce
+//! a [`crate::RemoteRelease`].
 
 /// Endpoint-backed release source implementation.
 pub mod endpoint;
 /// GitHub Release-backed source implementation.
 pub mod github;
+/// GitLab Release-backed source implementation.
+pub mod gitlab;
 
-use crate::RemoteRelease;
-use std::{future::Future, pin::Pin};
+pub(crate) mod assets;
 
-/// Parameters supplied to a release source when resolving update metadata.
+use crate::{ReleaseAsset, RemoteRelease};
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, RwLock},
+};
+
+/// Predicate choosing among the release assets that match the requested target.
+pub type AssetFilter = Arc<dyn Fn(&ReleaseAsset) -> bool + Send + Sync + 'static>;
+
+/// Shared slot holding the release cached by [`SourceRequest::release_cache`].
+pub type ReleaseCache = Arc<RwLock<Option<CachedRelease>>>;
+
+/// Release kept between checks with the validators of the response it came from.
 #[derive(Debug, Clone)]
+pub struct CachedRelease {
+    /// `ETag` header of the response, sent back as `If-None-Match`.
+    pub etag: Option<String>,
+    /// `Last-Modified` header of the response, sent back as `If-Modified-Since`.
+    pub last_modified: Option<String>,
+    /// Release adapted from the response.
+    pub release: RemoteRelease,
+}
+
+/// Named release track.
+///
+/// [`Self::Stable`] follows the regular releases. The other channels also
+/// accept their own releases: tags starting with the channel name and a dash,
+/// such as `beta-v1.2.0` or `nightly-v1.2.3-20250101`, and versions whose
+/// pre-release starts with the channel name, such as `v1.2.0-beta.1`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum UpdateChannel {
+    /// Regular releases.
+    #[default]
+    Stable,
+    /// Releases tagged `beta-<version>`.
+    Beta,
+    /// Releases tagged `nightly-<version>`.
+    Nightly,
+    /// Releases tagged `<name>-<version>`.
+    Custom(String),
+}
+
+impl UpdateChannel {
+    /// Returns the prefix tags of this channel start with, or `None` for [`Self::Stable`].
+    pub fn tag_prefix(&self) -> Option<String> {
+        match self {
+            Self::Stable => None,
+            Self::Beta => Some("beta-".into()),
+            Self::Nightly => Some("nightly-".into()),
+            Self::Custom(name) => Some(format!("{name}-")),
+        }
+    }
+
+    /// Returns the channel name, or `None` for [`Self::Stable`].
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Self::Stable => None,
+            Self::Beta => Some("beta"),
+            Self::Nightly => Some("nightly"),
+            Self::Custom(name) => Some(name),
+        }
+    }
+
+    /// Whether a release tagged `tag` with `version` belongs to this channel.
+    ///
+    /// Stable releases belong to every channel.
+    pub(crate) fn includes(&self, tag: &str, version: &Version) -> bool {
+        if version.pre.is_empty() {
+            return true;
+        }
+        let Some(name) = self.name() else {
+            return false;
+        };
+        tag.starts_with(&format!("{name}-"))
+            || version
+                .pre
+                .split('.')
+                .next()
+                .is_some_and(|identifier| identifier.eq_ignore_ascii_case(name))
+    }
+}
+
+impl From<&str> for UpdateChannel {
+    /// Maps `stable`, `beta` and `nightly` to their variants and any other name to [`Self::Custom`].
+    fn from(name: &str) -> Self {
+        match name {
+            "stable" => Self::Stable,
+            "beta" => Self::Beta,
+            "nightly" => Self::Nightly,
+            _ => Self::Custom(name.to_owned()),
+        }
+    }
+}
+
+/// Parameters supplied to a release source when resolving update metadata.
+#[derive(Clone)]
 pub struct SourceRequest {
     /// Requested platform target such as `linux-x86_64`.
     pub target: String,
+    /// Maximum artifact size in bytes; sources that know asset sizes reject larger artifacts.
+    pub max_asset_size: Option<u64>,
+    /// Whether macOS `.dmg` images are preferred over `.app.zip` archives.
+    pub prefer_dmg: bool,
+    /// Whether pre-releases of every channel are considered when picking the latest release.
+    pub allow_prerelease: bool,
+    /// When set, sources resolve exactly this version instead of the latest one.
+    pub version: Option<Version>,
+    /// When set, GitHub and GitLab sources pick the first target asset accepted by this predicate.
+    pub asset_filter: Option<AssetFilter>,
+    /// When set, GitHub and GitLab sources ignore assets whose names do not match this glob.
+    pub asset_name_glob: Option<String>,
+    /// Release track GitHub sources pick the latest release from.
+    pub channel: UpdateChannel,
+    /// When set, GitHub sources revalidate the latest release cached here instead of refetching it.
+    pub release_cache: Option<ReleaseCache>,
+}
+
+impl fmt::Debug for SourceRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SourceRequest")
+            .field("target", &self.target)
+            .field("max_asset_size", &self.max_asset_size)
+            .field("prefer_dmg", &self.prefer_dmg)
+            .field("allow_prerelease", &self.allow_prerelease)
+            .field("version", &self.version)
+            .field("asset_filter", &self.asset_filter.is_some())
+            .field("asset_name_glob", &self.asset_name_glob)
+            .field("channel", &self.channel)
+            .field("release_cache", &self.release_cache.is_some())
+            .finish()
+    }
 }
 
 impl SourceRequest {
@@ -24,8 +156,81 @@ impl SourceRequest {
     pub fn new(target: impl Into<String>) -> Self {
         Self {
             target: target.into(),
+            max_asset_size: None,
+            prefer_dmg: false,
+            allow_prerelease: false,
+            version: None,
+            asset_filter: None,
+            asset_name_glob: None,
+            channel: UpdateChannel::Stable,
+            release_cache: None,
         }
     }
+
+    /// Sets the maximum artifact size sources should accept.
+    pub fn max_asset_size(mut self, max_bytes: u64) -> Self {
+        self.max_asset_size = Some(max_bytes);
+        self
+    }
+
+    /// Sets whether sources should pick `.dmg` images over `.app.zip` archives.
+    pub fn prefer_dmg(mut self, prefer_dmg: bool) -> Self {
+        self.prefer_dmg = prefer_dmg;
+        self
+    }
+
+    /// Sets whether sources should consider pre-releases.
+    pub fn allow_prerelease(mut self, allow_prerelease: bool) -> Self {
+        self.allow_prerelease = allow_prerelease;
+        self
+    }
+
+    /// Asks sources for the release of exactly `version`.
+    ///
+    /// Sources return [`crate::Error::VersionNotFound`] when they cannot
+    /// provide that release.
+    pub fn version(mut self, version: Version) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Narrows asset selection to target assets accepted by `filter`.
+    ///
+    /// The filter runs after the OS and architecture match and replaces the
+    /// built-in preferences, such as `.dmg` versus `.app.zip` and distro
+    /// packages. Sources return [`crate::Error::AssetNotFound`] when no asset
+    /// passes.
+    pub fn asset_filter(mut self, filter: AssetFilter) -> Self {
+        self.asset_filter = Some(filter);
+        self
+    }
+
+    /// Only considers assets whose names match the shell-style glob `pattern`.
+    ///
+    /// Patterns follow [`glob::Pattern`]: `*` matches any run of characters,
+    /// `?` a single character and `[...]` a character class, so
+    /// `myapp-*-x86_64-windows.exe` accepts names carrying build numbers or
+    /// dates. Matching is case-sensitive, and fetching with a malformed
+    /// pattern fails with [`crate::Error::InvalidConfig`].
+    pub fn asset_name_glob(mut self, pattern: impl Into<String>) -> Self {
+        self.asset_name_glob = Some(pattern.into());
+        self
+    }
+
+    /// Picks the latest release of `channel` instead of the latest stable release.
+    pub fn channel(mut self, channel: UpdateChannel) -> Self {
+        self.channel = channel;
+        self
+    }
+
+    /// Caches the latest release in `cache` and revalidates it with conditional requests.
+    ///
+    /// Sources that support it answer from the cache when the server reports
+    /// the release unchanged, and store each newly fetched release there.
+    pub fn release_cache(mut self, cache: ReleaseCache) -> Self {
+        self.release_cache = Some(cache);
+        self
+    }
 }
 
 /// Boxed future returned by [`ReleaseSource::fetch`].
@@ -34,6 +239,13 @@ impl SourceRequest {
 /// sources behind trait objects such as `Box<dyn ReleaseSource>`.
 pub type SourceFuture<'a> = Pin<Box<dyn Future<Output = crate::Result<RemoteRelease>> + Send + 'a>>;
 
+/// Boxed future returned by [`ReleaseSource::fetch_notes`].
+pub type NotesFuture<'a> = Pin<Box<dyn Future<Output = crate::Result<Option<String>>> + Send + 'a>>;
+
+/// Boxed future returned by [`ReleaseSource::fetch_releases`].
+pub type ReleasesFuture<'a> =
+    Pin<Box<dyn Future<Output = crate::Result<Vec<RemoteRelease>>> + Send + 'a>>;
+
 /// Pluggable source of release metadata for the updater pipeline.
 ///
 /// Implement this trait when update metadata comes from a service other than
@@ -41,7 +253,61 @@ pub type SourceFuture<'a> = Pin<Box<dyn Future<Output = crate::Result<RemoteRele
 pub trait ReleaseSource: Send + Sync {
     /// Fetches release metadata for the requested target.
     fn fetch<'a>(&'a self, request: &'a SourceRequest) -> SourceFuture<'a>;
+
+    /// Fetches only the latest release notes, without resolving a target artifact.
+    ///
+    /// The default implementation delegates to [`Self::fetch`]. Sources whose
+    /// `fetch` fails when no artifact exists for the target should override
+    /// this so "what's new" views work on every platform.
+    fn fetch_notes<'a>(&'a self, request: &'a SourceRequest) -> NotesFuture<'a> {
+        Box::pin(async move { Ok(self.fetch(request).await?.notes) })
+    }
+
+    /// Fetches up to `limit` recent releases for the requested target, newest first.
+    ///
+    /// The default implementation returns the single release from
+    /// [`Self::fetch`]. Sources that can list release history should override
+    /// this.
+    fn fetch_releases<'a>(
+        &'a self,
+        request: &'a SourceRequest,
+        limit: usize,
+    ) -> ReleasesFuture<'a> {
+        Box::pin(async move {
+            if limit == 0 {
+                return Ok(Vec::new());
+            }
+            Ok(vec![self.fetch(request).await?])
+        })
+    }
+
+    /// Returns the URL connectivity checks probe before contacting this source.
+    ///
+    /// The default returns `None`, in which case the updater probes its first
+    /// configured endpoint. Sources talking to a fixed host should return a
+    /// URL on it.
+    fn connectivity_url(&self) -> Option<url::Url> {
+        None
+    }
+
+    /// Describes where this source fetches releases from, such as a repository or URL.
+    ///
+    /// Used in diagnostics output; the default returns `None`.
+    fn describe(&self) -> Option<String> {
+        None
+    }
+
+    /// Routes this source's HTTP requests through `client`.
+    ///
+    /// [`crate::UpdaterBuilder::build`] calls this with a client carrying the
+    /// updater's proxy, timeouts, custom root certificates and certificate
+    /// pins. Built-in sources keep a client passed to them explicitly; the
+    /// default implementation ignores `client`.
+    fn set_http_client(&mut self, client: reqwest::Client) {
+        let _ = client;
+    }
 }
 
 pub use endpoint::EndpointSource;
 pub use github::GitHubSource;
+pub use gitlab::GitLabSource;
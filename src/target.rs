@@ -1,10 +1,12 @@
 //! Platform and installer target modeling.
 
 use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
 use std::path::Path;
 
 /// Supported operating systems for release targeting.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum OS {
     /// Linux targets.
     Linux,
@@ -15,16 +17,21 @@ pub enum OS {
 }
 
 /// Supported CPU architectures for release targeting.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum Arch {
     /// 64-bit x86.
     X86_64,
     /// 64-bit ARM.
     Arm64,
+    /// 32-bit x86.
+    X86,
+    /// 32-bit ARM.
+    Arm,
 }
 
 /// Installer formats understood by the platform backends.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum InstallerKind {
     /// Linux AppImage package.
     AppImage,
@@ -32,27 +39,40 @@ pub enum InstallerKind {
     Deb,
     /// RPM package.
     Rpm,
+    /// Snap package installed with `snap install --dangerous`.
+    Snap,
+    /// Linux `.tar.gz` archive containing the application binary.
+    TarGz,
+    /// Linux `.tar.xz` archive containing the application binary.
+    TarXz,
     /// macOS `.app.tar.gz` archive.
     AppTarGz,
     /// macOS `.app.zip` archive.
     AppZip,
+    /// macOS `.dmg` disk image containing the `.app` bundle.
+    Dmg,
     /// Windows MSI installer.
     Msi,
     /// Windows EXE / NSIS-style installer.
     Nsis,
+    /// Windows portable `.zip` archive extracted over the install directory.
+    PortableZip,
 }
 
 /// Runtime platform information for target selection.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SystemInfo {
     /// Operating system component.
     pub os: OS,
     /// Architecture component.
     pub arch: Arch,
+    /// Lowercase Linux distribution ID, such as `ubuntu`, used to prefer
+    /// distro-specific artifacts. Always `None` on other platforms.
+    pub distro: Option<String>,
 }
 
 /// Fully-resolved target descriptor used for source selection.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TargetInfo {
     /// Canonical target string such as `linux-x86_64`.
     pub target: String,
@@ -73,6 +93,8 @@ impl TargetInfo {
         let arch = match system.arch {
             Arch::X86_64 => "x86_64",
             Arch::Arm64 => "aarch64",
+            Arch::X86 => "i686",
+            Arch::Arm => "armv7",
         };
         Self {
             target: format!("{os}-{arch}"),
@@ -81,6 +103,18 @@ impl TargetInfo {
     }
 }
 
+impl OS {
+    /// Parses the operating system component of a target such as `linux-x86_64`.
+    pub(crate) fn from_target(target: &str) -> Option<Self> {
+        match target.split_once('-')?.0 {
+            "linux" => Some(Self::Linux),
+            "darwin" => Some(Self::Macos),
+            "windows" => Some(Self::Windows),
+            _ => None,
+        }
+    }
+}
+
 impl SystemInfo {
     /// Detects the current host operating system and architecture.
     ///
@@ -99,10 +133,97 @@ impl SystemInfo {
             Arch::X86_64
         } else if cfg!(target_arch = "aarch64") {
             Arch::Arm64
+        } else if cfg!(target_arch = "x86") {
+            Arch::X86
+        } else if cfg!(target_arch = "arm") {
+            Arch::Arm
         } else {
             return Err(Error::UnsupportedArch);
         };
-        Ok(Self { os, arch })
+        let distro = crate::detect_linux_distro();
+        Ok(Self { os, arch, distro })
+    }
+
+    /// Parses a canonical target string such as `linux-x86_64` back into system information.
+    ///
+    /// Linux targets pick up the host distribution from [`crate::detect_linux_distro`].
+    pub(crate) fn from_target(target: &str) -> Option<Self> {
+        let (_, arch) = target.split_once('-')?;
+        let os = OS::from_target(target)?;
+        let arch = match arch {
+            "x86_64" => Arch::X86_64,
+            "aarch64" => Arch::Arm64,
+            "i686" => Arch::X86,
+            "armv7" => Arch::Arm,
+            _ => return None,
+        };
+        let distro = if os == OS::Linux {
+            crate::detect_linux_distro()
+        } else {
+            None
+        };
+        Some(Self { os, arch, distro })
+    }
+
+    /// Returns a human-readable platform name for user-facing messages.
+    ///
+    /// For example `macOS (Apple Silicon)`, `Windows x86_64`, or `Linux ARM64`.
+    pub fn display_name(&self) -> String {
+        let arch = match self.arch {
+            Arch::X86_64 => "x86_64",
+            Arch::Arm64 => "ARM64",
+            Arch::X86 => "x86",
+            Arch::Arm => "ARM",
+        };
+        match (&self.os, &self.arch) {
+            (OS::Macos, Arch::Arm64) => "macOS (Apple Silicon)".into(),
+            (OS::Macos, Arch::X86_64) => "macOS (Intel)".into(),
+            (OS::Macos, _) => format!("macOS {arch}"),
+            (OS::Windows, _) => format!("Windows {arch}"),
+            (OS::Linux, _) => format!("Linux {arch}"),
+        }
+    }
+
+    /// Returns a compact platform identifier, such as `macos-arm64`.
+    ///
+    /// Suitable as a URL path segment or filename component in custom asset
+    /// URL templates.
+    pub fn short_name(&self) -> &'static str {
+        match (&self.os, &self.arch) {
+            (OS::Macos, Arch::Arm64) => "macos-arm64",
+            (OS::Macos, Arch::X86_64) => "macos-x86_64",
+            (OS::Windows, Arch::X86_64) => "windows-x86_64",
+            (OS::Windows, Arch::Arm64) => "windows-arm64",
+            (OS::Linux, Arch::X86_64) => "linux-x86_64",
+            (OS::Linux, Arch::Arm64) => "linux-arm64",
+            (OS::Macos, Arch::X86) => "macos-x86",
+            (OS::Macos, Arch::Arm) => "macos-arm",
+            (OS::Windows, Arch::X86) => "windows-x86",
+            (OS::Windows, Arch::Arm) => "windows-arm",
+            (OS::Linux, Arch::X86) => "linux-x86",
+            (OS::Linux, Arch::Arm) => "linux-arm",
+        }
+    }
+}
+
+impl From<&SystemInfo> for String {
+    /// Returns the Rust target triple, such as `x86_64-apple-darwin`, used by
+    /// many ecosystems to name release artifacts.
+    fn from(system: &SystemInfo) -> Self {
+        let arch = match (&system.arch, &system.os) {
+            (Arch::X86_64, _) => "x86_64",
+            (Arch::Arm64, _) => "aarch64",
+            (Arch::X86, _) => "i686",
+            (Arch::Arm, OS::Windows) => "thumbv7a",
+            (Arch::Arm, _) => "armv7",
+        };
+        let vendor_os = match (&system.os, &system.arch) {
+            (OS::Linux, Arch::Arm) => "unknown-linux-gnueabihf",
+            (OS::Linux, _) => "unknown-linux-gnu",
+            (OS::Macos, _) => "apple-darwin",
+            (OS::Windows, _) => "pc-windows-msvc",
+        };
+        format!("{arch}-{vendor_os}")
     }
 }
 
@@ -122,10 +243,20 @@ impl InstallerKind {
             Ok(Self::Deb)
         } else if name.ends_with(".rpm") {
             Ok(Self::Rpm)
+        } else if name.ends_with(".snap") {
+            Ok(Self::Snap)
         } else if name.ends_with(".app.tar.gz") {
             Ok(Self::AppTarGz)
+        } else if name.ends_with(".tar.gz") {
+            Ok(Self::TarGz)
+        } else if name.ends_with(".tar.xz") {
+            Ok(Self::TarXz)
         } else if name.ends_with(".app.zip") {
             Ok(Self::AppZip)
+        } else if name.ends_with(".dmg") {
+            Ok(Self::Dmg)
+        } else if name.ends_with(".zip") {
+            Ok(Self::PortableZip)
         } else if name.ends_with(".msi") {
             Ok(Self::Msi)
         } else if name.ends_with(".exe") {
@@ -134,4 +265,29 @@ impl InstallerKind {
             Err(Error::InvalidUpdaterFormat)
         }
     }
+
+    /// Infers the installer format of an artifact published for `target`.
+    ///
+    /// Like [`Self::from_path`], but formats installed by another operating
+    /// system, such as a `.zip` or `.tar.gz` offered to a macOS target, are
+    /// rejected with [`Error::InvalidUpdaterFormat`]. Targets without a known
+    /// operating system accept every format.
+    pub fn for_target(path: &Path, target: &str) -> Result<Self> {
+        let kind = Self::from_path(path)?;
+        match OS::from_target(target) {
+            Some(os) if os != kind.os() => Err(Error::InvalidUpdaterFormat),
+            _ => Ok(kind),
+        }
+    }
+
+    /// Returns the operating system whose backend installs this format.
+    pub fn os(&self) -> OS {
+        match self {
+            Self::AppImage | Self::Deb | Self::Rpm | Self::Snap | Self::TarGz | Self::TarXz => {
+                OS::Linux
+            }
+            Self::AppTarGz | Self::AppZip | Self::Dmg => OS::Macos,
+            Self::Msi | Self::Nsis | Self::PortableZip => OS::Windows,
+        }
+    }
 }
@@ -0,0 +1,157 @@
+//! Custom roots and certificate pinning for the updater's HTTP transport.
+
+use crate::{Error, Result};
+use reqwest::{Certificate, ClientBuilder};
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::crypto::{CryptoProvider, verify_tls12_signature, verify_tls13_signature};
+use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use rustls::{CertificateError, DigitallySignedStruct, SignatureScheme};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+
+/// Parses the certificates of a PEM bundle, failing when it holds none.
+pub(crate) fn parse_pem_bundle(pem: &str) -> Result<Vec<Certificate>> {
+    let certificates = Certificate::from_pem_bundle(pem.as_bytes())?;
+    if certificates.is_empty() {
+        return Err(Error::Io(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "no PEM certificate found",
+        )));
+    }
+    Ok(certificates)
+}
+
+/// Applies the custom `root_certificates` and the pinned `certificates` and
+/// SHA-256 `fingerprints` to `builder`.
+///
+/// Pins take precedence over custom roots. Fingerprint pins take precedence
+/// over certificate pins: once any is configured, the server's leaf
+/// certificate must match one of them and CA certificates are not consulted.
+/// Pinned certificates replace the built-in roots, while custom PEM roots
+/// are trusted in addition to them.
+pub(crate) fn configure(
+    builder: ClientBuilder,
+    root_certificates: &[String],
+    certificates: &[Vec<u8>],
+    fingerprints: &[String],
+) -> Result<ClientBuilder> {
+    if !fingerprints.is_empty() {
+        let provider = Arc::new(rustls::crypto::aws_lc_rs::default_provider());
+        let verifier = FingerprintVerifier {
+            fingerprints: fingerprints.to_vec(),
+            provider: provider.clone(),
+        };
+        let mut config = rustls::ClientConfig::builder_with_provider(provider)
+            .with_safe_default_protocol_versions()
+            .map_err(|error| Error::Network(error.to_string()))?
+            .dangerous()
+            .with_custom_certificate_verifier(Arc::new(verifier))
+            .with_no_client_auth();
+        // A preconfigured backend is used as-is, so ALPN must be set here.
+        config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+        return Ok(builder.tls_backend_preconfigured(config));
+    }
+    if certificates.is_empty() {
+        if root_certificates.is_empty() {
+            return Ok(builder);
+        }
+        let mut roots = Vec::new();
+        for pem in root_certificates {
+            roots.extend(parse_pem_bundle(pem)?);
+        }
+        return Ok(builder.tls_certs_merge(roots));
+    }
+
+    let certificates = certificates
+        .iter()
+        .map(|der| Certificate::from_der(der))
+        .collect::<reqwest::Result<Vec<_>>>()?;
+    Ok(builder
+        .use_rustls_tls()
+        .tls_certs_only(certificates)
+        .danger_accept_invalid_certs(false))
+}
+
+/// Accepts servers whose leaf certificate has one of the pinned SHA-256 fingerprints.
+#[derive(Debug)]
+struct FingerprintVerifier {
+    /// Lowercase hex digests without separators.
+    fingerprints: Vec<String>,
+    provider: Arc<CryptoProvider>,
+}
+
+impl ServerCertVerifier for FingerprintVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> std::result::Result<ServerCertVerified, rustls::Error> {
+        let fingerprint = format!("{:x}", Sha256::digest(end_entity.as_ref()));
+        if self.fingerprints.contains(&fingerprint) {
+            Ok(ServerCertVerified::assertion())
+        } else {
+            Err(rustls::Error::InvalidCertificate(
+                CertificateError::ApplicationVerificationFailure,
+            ))
+        }
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> std::result::Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.provider.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> std::result::Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.provider.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.provider
+            .signature_verification_algorithms
+            .supported_schemes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fingerprint_verifier_only_accepts_pinned_leaf_certificates() {
+        let pinned = CertificateDer::from(b"pinned certificate".to_vec());
+        let other = CertificateDer::from(b"other certificate".to_vec());
+        let verifier = FingerprintVerifier {
+            fingerprints: vec![format!("{:x}", Sha256::digest(pinned.as_ref()))],
+            provider: Arc::new(rustls::crypto::aws_lc_rs::default_provider()),
+        };
+        let server_name = ServerName::try_from("updates.example.com").unwrap();
+        let verify = |cert: &CertificateDer<'_>| {
+            verifier.verify_server_cert(cert, &[], &server_name, &[], UnixTime::now())
+        };
+
+        assert!(verify(&pinned).is_ok());
+        assert!(verify(&other).is_err());
+    }
+}
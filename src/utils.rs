@@ -13,6 +13,7 @@ use std::path::{Path, PathBuf};
 
 /// Bundle types supported by the installer logic.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum BundleType {
     /// macOS `.app.zip` bundle.
     MacOSAppZip,
@@ -22,6 +23,25 @@ pub enum BundleType {
     WindowsMSI,
     /// Windows EXE / setup installer.
     WindowsSetUp,
+    /// Windows portable ZIP archive.
+    WindowsPortableZip,
+    /// Linux AppImage replaced in place.
+    LinuxAppImage,
+    /// Linux `.tar.gz` binary archive.
+    LinuxTarGz,
+    /// Linux `.tar.xz` binary archive.
+    LinuxTarXz,
+    /// Linux Snap package.
+    LinuxSnap,
+    /// Binary delta `.patch` applied to the current executable.
+    DeltaPatch,
+}
+
+impl BundleType {
+    /// Returns `true` when an asset name refers to a binary delta patch.
+    pub fn is_patch(name: &str) -> bool {
+        name.ends_with(".patch")
+    }
 }
 
 /// Derive the target extract/installation path from the current executable path.
@@ -59,3 +79,176 @@ pub fn extract_path_from_executable(executable_path: &Path) -> Result<PathBuf> {
 
     Ok(extract_path)
 }
+
+/// Returns `true` when a file can be created inside `dir`.
+///
+/// This probes with a real temporary file because permission bits alone do not
+/// account for ACLs, read-only mounts, or sandboxing.
+pub(crate) fn is_dir_writable(dir: &Path) -> bool {
+    tempfile::tempfile_in(dir).is_ok()
+}
+
+/// Creates a temporary directory named with `prefix` inside `parent`, or the system temp directory.
+///
+/// `parent` is created when it does not exist.
+pub(crate) fn temp_dir_in(prefix: &str, parent: Option<&Path>) -> Result<tempfile::TempDir> {
+    let mut builder = tempfile::Builder::new();
+    builder.prefix(prefix);
+    match parent {
+        Some(parent) => {
+            fs_err::create_dir_all(parent)?;
+            Ok(builder.tempdir_in(parent)?)
+        }
+        None => Ok(builder.tempdir()?),
+    }
+}
+
+/// Returns the bytes available to unprivileged users on the volume holding `path`.
+///
+/// `path` does not need to exist yet: its nearest existing ancestor is
+/// queried with `statvfs` on Unix and `GetDiskFreeSpaceExW` on Windows.
+pub(crate) fn available_disk_space(path: &Path) -> Result<u64> {
+    let dir = path
+        .ancestors()
+        .find(|ancestor| ancestor.is_dir())
+        .unwrap_or(Path::new("."));
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt;
+
+        let c_path = std::ffi::CString::new(dir.as_os_str().as_bytes())
+            .map_err(|error| Error::Io(error.into()))?;
+        let mut stat = std::mem::MaybeUninit::<libc::statvfs>::uninit();
+        if unsafe { libc::statvfs(c_path.as_ptr(), stat.as_mut_ptr()) } != 0 {
+            return Err(std::io::Error::last_os_error().into());
+        }
+        let stat = unsafe { stat.assume_init() };
+        // The field widths differ between platforms.
+        #[allow(clippy::unnecessary_cast)]
+        Ok(stat.f_bavail as u64 * stat.f_frsize as u64)
+    }
+    #[cfg(target_os = "windows")]
+    {
+        crate::windows::free_disk_space(dir)
+    }
+}
+
+/// Returns the per-user data directory used to persist updater state for `app_name`.
+///
+/// This resolves to `%LOCALAPPDATA%` on Windows, `~/Library/Application Support`
+/// on macOS, and `$XDG_DATA_HOME` (or `~/.local/share`) elsewhere. The
+/// directory is not created.
+pub(crate) fn app_data_dir(app_name: &str) -> Result<PathBuf> {
+    let base = if cfg!(target_os = "windows") {
+        std::env::var_os("LOCALAPPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        std::env::var_os("HOME").map(|home| PathBuf::from(home).join("Library/Application Support"))
+    } else {
+        std::env::var_os("XDG_DATA_HOME")
+            .map(PathBuf::from)
+            .or_else(|| {
+                std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share"))
+            })
+    };
+
+    base.map(|base| base.join(app_name).join("release-hub"))
+        .ok_or_else(|| {
+            Error::Io(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "failed to determine the user data directory",
+            ))
+        })
+}
+
+/// Detects the running Linux distribution from `/etc/os-release`.
+///
+/// Returns the lowercase `ID` value, such as `"ubuntu"`, `"fedora"` or
+/// `"arch"`, falling back to the first word of `PRETTY_NAME`. Returns `None`
+/// on other platforms or when the file is missing.
+pub fn detect_linux_distro() -> Option<String> {
+    linux_distro_ids().into_iter().next()
+}
+
+/// Returns the running distribution's ID followed by the IDs listed in `ID_LIKE`.
+///
+/// Empty on other platforms or when os-release is missing.
+pub(crate) fn linux_distro_ids() -> Vec<String> {
+    if !cfg!(target_os = "linux") {
+        return Vec::new();
+    }
+    std::fs::read_to_string("/etc/os-release")
+        .or_else(|_| std::fs::read_to_string("/usr/lib/os-release"))
+        .map(|contents| distro_ids(&contents))
+        .unwrap_or_default()
+}
+
+/// Extracts the lowercase `ID` and `ID_LIKE` entries from os-release `contents`.
+fn distro_ids(contents: &str) -> Vec<String> {
+    let value = |key: &str| {
+        contents.lines().find_map(|line| {
+            let (name, value) = line.trim().split_once('=')?;
+            if name != key {
+                return None;
+            }
+            let value = value.trim();
+            let value = ['"', '\'']
+                .into_iter()
+                .find_map(|quote| value.strip_prefix(quote)?.strip_suffix(quote))
+                .unwrap_or(value);
+            (!value.is_empty()).then(|| value.to_lowercase())
+        })
+    };
+
+    let id = value("ID").or_else(|| {
+        value("PRETTY_NAME")?
+            .split_whitespace()
+            .next()
+            .map(str::to_owned)
+    });
+    let like = value("ID_LIKE").unwrap_or_default();
+    id.into_iter()
+        .chain(like.split_whitespace().map(str::to_owned))
+        .collect()
+}
+
+/// Returns whether the current process runs with elevated privileges.
+///
+/// On Windows this reads the `TokenElevation` of the process token, which is
+/// set once the process has passed UAC as an administrator. On Linux the
+/// effective user from `/proc/self/status` must be root. Returns `false` on
+/// other platforms or when the query fails.
+pub fn is_elevated() -> bool {
+    #[cfg(target_os = "windows")]
+    {
+        crate::windows::token_is_elevated()
+    }
+    #[cfg(target_os = "linux")]
+    {
+        std::fs::read_to_string("/proc/self/status")
+            .ok()
+            .and_then(|status| {
+                let uids = status.lines().find_map(|line| line.strip_prefix("Uid:"))?;
+                // The fields are the real, effective, saved and filesystem user ids.
+                uids.split_whitespace().nth(1).map(|euid| euid == "0")
+            })
+            .unwrap_or(false)
+    }
+    #[cfg(not(any(target_os = "windows", target_os = "linux")))]
+    {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::distro_ids;
+
+    #[test]
+    fn distro_ids_read_exact_id_and_id_like_keys() {
+        let os_release = "NAME=\"Linux Mint\"\nVERSION_ID=\"21.3\"\nID=linuxmint\nID_LIKE=\"ubuntu debian\"\nUBUNTU_CODENAME=jammy\n";
+        assert_eq!(distro_ids(os_release), ["linuxmint", "ubuntu", "debian"]);
+
+        let os_release = "PRETTY_NAME='Arch Linux'\nBUILD_ID=rolling\n";
+        assert_eq!(distro_ids(os_release), ["arch"]);
+    }
+}
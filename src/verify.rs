@@ -1,7 +1,9 @@
 //! Signature verification helpers.
 
 use crate::Result;
+use fs_err as fs;
 use minisign_verify::{PublicKey, Signature};
+use std::{io::Read, path::Path};
 
 /// Verifies a downloaded payload against a Minisign public key and detached signature.
 ///
@@ -13,3 +15,32 @@ pub fn verify_minisign(payload: &[u8], pubkey: &str, signature: &str) -> Result<
     public_key.verify(payload, &signature, true)?;
     Ok(())
 }
+
+/// Verifies a payload stored at `path` like [`verify_minisign`], reading it in chunks.
+///
+/// Legacy Minisign signatures cover the raw payload rather than its hash, so
+/// files signed that way are still read into memory in full.
+pub fn verify_minisign_file(path: &Path, pubkey: &str, signature: &str) -> Result<()> {
+    let public_key = PublicKey::decode(pubkey)?;
+    let signature = Signature::decode(signature)?;
+    let mut verifier = match public_key.verify_stream(&signature) {
+        Ok(verifier) => verifier,
+        Err(minisign_verify::Error::UnsupportedLegacyMode) => {
+            public_key.verify(&fs::read(path)?, &signature, true)?;
+            return Ok(());
+        }
+        Err(error) => return Err(error.into()),
+    };
+
+    let mut file = fs::File::open(path)?;
+    let mut buffer = vec![0; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        verifier.update(&buffer[..read]);
+    }
+    verifier.finalize()?;
+    Ok(())
+}
@@ -6,48 +6,484 @@
 // Copyright (c) 2015 - Present - The Tauri Programme within The Commons Conservancy.
 // Licensed under MIT OR MIT/Apache-2.0
 
-use crate::{Error, Result, Update, Updater, builder::windows_installer_args_command_line};
+use crate::{
+    Error, InstallProgress, InstallSimulation, InstallerKind, Result, Update, Updater,
+    builder::{nsis_installer_args, windows_installer_args_command_line},
+    common::{extract_zip_to_dir, zip_file_entries},
+    utils::is_dir_writable,
+};
 use fs_err as fs;
 use semver::Version;
 use std::{
-    ffi::OsString,
+    ffi::{OsString, c_void},
     path::{Path, PathBuf},
     sync::{Mutex, OnceLock},
     thread,
     time::Duration,
 };
-use windows::Win32::Foundation::HWND;
-use windows::Win32::UI::Shell::ShellExecuteW;
+use windows::Win32::Foundation::{CloseHandle, ERROR_CANCELLED, HANDLE, HWND};
+use windows::Win32::Security::Cryptography::{CERT_NAME_SIMPLE_DISPLAY_TYPE, CertGetNameStringW};
+use windows::Win32::Security::WinTrust::{
+    WINTRUST_ACTION_GENERIC_VERIFY_V2, WINTRUST_DATA, WINTRUST_DATA_0, WINTRUST_FILE_INFO,
+    WTD_CHOICE_FILE, WTD_REVOKE_NONE, WTD_STATEACTION_CLOSE, WTD_STATEACTION_VERIFY, WTD_UI_NONE,
+    WTHelperGetProvSignerFromChain, WTHelperProvDataFromStateData, WinVerifyTrust,
+};
+use windows::Win32::Security::{GetTokenInformation, TOKEN_ELEVATION, TOKEN_QUERY, TokenElevation};
+use windows::Win32::Storage::FileSystem::GetDiskFreeSpaceExW;
+use windows::Win32::System::ApplicationInstallationAndServicing::{
+    INSTALLUILEVEL, INSTALLUILEVEL_BASIC, INSTALLUILEVEL_NONE, INSTALLUILEVEL_PROGRESSONLY,
+    MsiInstallProductW, MsiSetExternalUIW, MsiSetInternalUI,
+};
+use windows::Win32::System::Diagnostics::ToolHelp::{
+    CreateToolhelp32Snapshot, PROCESSENTRY32W, Process32FirstW, Process32NextW, TH32CS_SNAPPROCESS,
+};
+use windows::Win32::System::Threading::{
+    GetCurrentProcess, GetExitCodeProcess, INFINITE, OpenProcess, OpenProcessToken,
+    PROCESS_NAME_WIN32, PROCESS_QUERY_LIMITED_INFORMATION, PROCESS_TERMINATE,
+    QueryFullProcessImageNameW, TerminateProcess, WaitForSingleObject,
+};
+use windows::Win32::UI::Shell::{
+    SEE_MASK_NOCLOSEPROCESS, SHELLEXECUTEINFOW, ShellExecuteExW, ShellExecuteW,
+};
 use windows::Win32::UI::WindowsAndMessaging::SW_SHOW;
-use windows::core::{HSTRING, w};
+use windows::core::{HSTRING, PCWSTR, PWSTR, w};
 
 type WindowsUpdaterType = (PathBuf, Option<tempfile::TempPath>);
 static UPDATER_FILE: OnceLock<OsString> = OnceLock::new();
+static UPDATER_IS_NSIS: OnceLock<bool> = OnceLock::new();
 static TEMP_FILE_KEEPER: Mutex<Option<tempfile::TempPath>> = Mutex::new(None);
+const ZIP_MAGIC: &[u8] = b"PK\x03\x04";
+/// OLE compound document header used by `.msi` packages.
+const MSI_MAGIC: &[u8] = b"\xD0\xCF\x11\xE0\xA1\xB1\x1A\xE1";
+/// `ERROR_SUCCESS_REBOOT_REQUIRED`, returned with `/norestart` when a reboot is pending.
+const MSI_REBOOT_REQUIRED: i32 = 3010;
+/// `INSTALLMESSAGE_PROGRESS`, carried in the high byte of the UI handler's message type.
+const MSI_PROGRESS_MESSAGE: u32 = 0x0A00_0000;
+/// `INSTALLLOGMODE_PROGRESS`, the external UI filter selecting progress messages.
+const MSI_PROGRESS_FILTER: u32 = 1 << 10;
+
+/// Settings shared by the `.msi` and `.exe` installer paths.
+struct InstallerOptions<'a> {
+    app_name: &'a str,
+    version: &'a Version,
+    installer_args: &'a [OsString],
+    temp_dir: Option<&'a Path>,
+    verify_authenticode: bool,
+    expected_signer: Option<&'a str>,
+    silent: bool,
+}
 
 impl Update {
     pub(crate) fn install_windows(&self, bytes: &[u8]) -> Result<()> {
-        launch_windows_installer(bytes, &self.app_name, &self.version, &self.installer_args)
+        if self.auto_terminate_running {
+            running_process_ids(&self.extract_path)?
+                .into_iter()
+                .try_for_each(terminate_process)?;
+        }
+        match self.installer_kind {
+            InstallerKind::PortableZip => {
+                crate::rollback::preserve_dir(&self.extract_path)?;
+                install_portable_zip(bytes, &self.extract_path, self.temp_dir.as_deref())
+            }
+            InstallerKind::Msi => install_msi(bytes, &self.installer_options()),
+            _ => launch_windows_installer(bytes, &self.installer_options()),
+        }
+    }
+
+    /// Like [`Self::install_windows`], but installs `.msi` packages in process
+    /// to forward Windows Installer progress to `on_progress`.
+    pub(crate) fn install_windows_with_progress(
+        &self,
+        bytes: &[u8],
+        on_progress: &mut dyn FnMut(InstallProgress),
+    ) -> Result<()> {
+        if self.installer_kind != InstallerKind::Msi {
+            return self.install_windows(bytes);
+        }
+        if self.auto_terminate_running {
+            running_process_ids(&self.extract_path)?
+                .into_iter()
+                .try_for_each(terminate_process)?;
+        }
+        install_msi_with_progress(bytes, &self.installer_options(), on_progress)
+    }
+
+    fn installer_options(&self) -> InstallerOptions<'_> {
+        InstallerOptions {
+            app_name: &self.app_name,
+            version: &self.version,
+            installer_args: &self.installer_args,
+            temp_dir: self.temp_dir.as_deref(),
+            verify_authenticode: self.verify_authenticode,
+            expected_signer: self.expected_signer.as_deref(),
+            silent: self.silent_install,
+        }
     }
 }
 
 impl Updater {
     pub(crate) fn install_inner(&self, bytes: &[u8]) -> Result<()> {
-        launch_windows_installer(
-            bytes,
-            &self.app_name,
-            &self.current_version,
-            &self.installer_args,
-        )
+        if bytes.starts_with(ZIP_MAGIC) {
+            crate::rollback::preserve_dir(&self.extract_path)?;
+            return install_portable_zip(bytes, &self.extract_path, self.temp_dir.as_deref());
+        }
+        if bytes.starts_with(MSI_MAGIC) {
+            return install_msi(bytes, &self.installer_options());
+        }
+        launch_windows_installer(bytes, &self.installer_options())
+    }
+
+    /// Like [`Self::install_inner`], but installs `.msi` packages in process
+    /// to forward Windows Installer progress to `on_progress`.
+    pub(crate) fn install_inner_with_progress(
+        &self,
+        bytes: &[u8],
+        on_progress: &mut dyn FnMut(InstallProgress),
+    ) -> Result<()> {
+        if bytes.starts_with(MSI_MAGIC) {
+            return install_msi_with_progress(bytes, &self.installer_options(), on_progress);
+        }
+        self.install_inner(bytes)
+    }
+
+    fn installer_options(&self) -> InstallerOptions<'_> {
+        InstallerOptions {
+            app_name: &self.app_name,
+            version: &self.current_version,
+            installer_args: &self.installer_args,
+            temp_dir: self.temp_dir.as_deref(),
+            verify_authenticode: self.verify_authenticode,
+            expected_signer: self.expected_signer.as_deref(),
+            silent: self.silent_install,
+        }
     }
 
     pub(crate) fn relaunch_inner(&self) -> Result<()> {
-        relaunch_windows(&self.installer_args)
+        if UPDATER_FILE.get().is_none() {
+            // Portable installs have no installer to launch; restart the app itself.
+            let _ = std::process::Command::new(std::env::current_exe()?).spawn()?;
+            std::process::exit(0);
+        }
+        relaunch_windows(&self.installer_args, self.silent_install)
+    }
+
+    pub(crate) fn simulate_install_inner(&self, bytes: &[u8]) -> Result<InstallSimulation> {
+        if !bytes.starts_with(ZIP_MAGIC) {
+            // Installers run out of process with the `runas` verb.
+            return Ok(InstallSimulation {
+                bytes_to_write: bytes.len() as u64,
+                requires_elevation: true,
+                ..InstallSimulation::default()
+            });
+        }
+
+        let files = zip_file_entries(bytes)?
+            .into_iter()
+            .map(|(path, size)| (self.extract_path.join(path), size))
+            .collect();
+        let writable = is_dir_writable(&self.extract_path);
+        Ok(InstallSimulation::from_planned_files(files, !writable))
     }
+
+    pub(crate) fn relaunch_as_admin_inner(&self) -> Result<()> {
+        if UPDATER_FILE.get().is_some() {
+            // Staged installers are launched with the `runas` verb, or `open` once elevated.
+            return relaunch_windows(&self.installer_args, self.silent_install);
+        }
+
+        let exe_hstring: HSTRING = std::env::current_exe()?.into_os_string().into();
+        let result = unsafe {
+            ShellExecuteW(
+                Some(HWND::default()),
+                elevation_verb(),
+                &exe_hstring,
+                w!(""),
+                w!("."),
+                SW_SHOW,
+            )
+        }
+        .0 as i32;
+
+        if result <= 32 {
+            return Err(shell_execute_error(result));
+        }
+        std::process::exit(0);
+    }
+}
+
+fn install_portable_zip(bytes: &[u8], extract_path: &Path, temp_dir: Option<&Path>) -> Result<()> {
+    let staging_dir = crate::utils::temp_dir_in("release-hub-portable-", temp_dir)?;
+    extract_zip_to_dir(bytes, staging_dir.path())?;
+    replace_dir_contents(staging_dir.path(), extract_path)
+}
+
+pub(crate) fn replace_dir_contents(source: &Path, dest: &Path) -> Result<()> {
+    fs::create_dir_all(dest)?;
+    for entry in fs::read_dir(source)? {
+        let entry = entry?;
+        let target = dest.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            replace_dir_contents(&entry.path(), &target)?;
+            continue;
+        }
+
+        if target.exists() {
+            // Running executables cannot be overwritten, but they can be renamed.
+            let retired =
+                target.with_file_name(format!("{}.old", entry.file_name().to_string_lossy()));
+            let _ = fs::remove_file(&retired);
+            fs::rename(&target, &retired)?;
+        }
+        fs::copy(entry.path(), &target)?;
+    }
+    Ok(())
 }
 
-fn install_windows_with_label(bytes: &[u8], app_name: &str, version: &Version) -> Result<()> {
-    let (temp_path, temp_keeper) = extract_exe(bytes, app_name, version)?;
+/// Lists processes other than the current one whose executable lives inside `install_dir`.
+///
+/// The full image path of each process is compared case-insensitively, so an
+/// unrelated program that merely shares part of the app name is never matched.
+pub(crate) fn running_process_ids(install_dir: &Path) -> Result<Vec<u32>> {
+    let mut install_prefix = install_dir.to_string_lossy().to_lowercase();
+    if !install_prefix.ends_with(['\\', '/']) {
+        install_prefix.push('\\');
+    }
+    let current_pid = std::process::id();
+    let snapshot = unsafe { CreateToolhelp32Snapshot(TH32CS_SNAPPROCESS, 0) }
+        .map_err(std::io::Error::other)?;
+
+    let mut entry = PROCESSENTRY32W {
+        dwSize: std::mem::size_of::<PROCESSENTRY32W>() as u32,
+        ..Default::default()
+    };
+    let mut pids = Vec::new();
+    let mut has_entry = unsafe { Process32FirstW(snapshot, &mut entry) }.is_ok();
+    while has_entry {
+        let pid = entry.th32ProcessID;
+        if pid != current_pid
+            && process_image_path(pid).is_some_and(|image| {
+                image
+                    .to_lowercase()
+                    .replace('/', "\\")
+                    .starts_with(&install_prefix)
+            })
+        {
+            pids.push(pid);
+        }
+        has_entry = unsafe { Process32NextW(snapshot, &mut entry) }.is_ok();
+    }
+
+    let _ = unsafe { CloseHandle(snapshot) };
+    Ok(pids)
+}
+
+/// Returns the full Win32 image path of `pid`, or `None` when it cannot be queried.
+fn process_image_path(pid: u32) -> Option<String> {
+    let handle = unsafe { OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid) }.ok()?;
+    let mut buffer = [0u16; 32768];
+    let mut len = buffer.len() as u32;
+    let result = unsafe {
+        QueryFullProcessImageNameW(
+            handle,
+            PROCESS_NAME_WIN32,
+            PWSTR(buffer.as_mut_ptr()),
+            &mut len,
+        )
+    };
+    let _ = unsafe { CloseHandle(handle) };
+    result.ok()?;
+    Some(String::from_utf16_lossy(&buffer[..len as usize]))
+}
+
+pub(crate) fn terminate_process(pid: u32) -> Result<()> {
+    let handle = unsafe { OpenProcess(PROCESS_TERMINATE, false, pid) }
+        .map_err(|_| Error::ProcessTerminationFailed(pid))?;
+    let result = unsafe { TerminateProcess(handle, 1) };
+    let _ = unsafe { CloseHandle(handle) };
+    result.map_err(|_| Error::ProcessTerminationFailed(pid))
+}
+
+/// Installs an `.msi` package through the Windows Installer engine and waits for it.
+///
+/// `msiexec.exe /i <package> /quiet /norestart` runs with `installer_args`
+/// appended, leaving out `/quiet` unless the install is silent. Like the
+/// `.exe` installers it is launched with the `runas` verb, or `open` once
+/// elevated. A pending reboot is reported as [`Error::RebootRequired`].
+fn install_msi(bytes: &[u8], options: &InstallerOptions<'_>) -> Result<()> {
+    let (msi_path, _temp_keeper) = extract_exe(bytes, options, ".msi")?;
+    let mut args = vec![OsString::from("/i"), msi_path.into_os_string()];
+    if options.silent {
+        args.push(OsString::from("/quiet"));
+    }
+    args.push(OsString::from("/norestart"));
+    args.extend_from_slice(options.installer_args);
+
+    match run_elevated(w!("msiexec.exe"), &args)? {
+        0 => Ok(()),
+        MSI_REBOOT_REQUIRED => Err(Error::RebootRequired),
+        code => Err(Error::MsiExecutionFailed(code)),
+    }
+}
+
+/// Installs an `.msi` package in process, reporting its progress to `on_progress`.
+///
+/// Unless the install is silent, Windows Installer shows its passive progress
+/// UI and prompts for elevation itself. `installer_args` holding `PROPERTY=value`
+/// pairs are passed on; `msiexec` switches do not apply here. A pending reboot
+/// is reported as [`Error::RebootRequired`].
+fn install_msi_with_progress(
+    bytes: &[u8],
+    options: &InstallerOptions<'_>,
+    on_progress: &mut dyn FnMut(InstallProgress),
+) -> Result<()> {
+    let (msi_path, _temp_keeper) = extract_exe(bytes, options, ".msi")?;
+    let package = HSTRING::from(msi_path.into_os_string());
+    let mut command_line = vec![String::from("REBOOT=ReallySuppress")];
+    command_line.extend(
+        options
+            .installer_args
+            .iter()
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .filter(|arg| !arg.starts_with('/') && arg.contains('=')),
+    );
+    let command_line = HSTRING::from(command_line.join(" "));
+    let ui_level = if options.silent {
+        INSTALLUILEVEL_NONE
+    } else {
+        INSTALLUILEVEL(INSTALLUILEVEL_BASIC.0 | INSTALLUILEVEL_PROGRESSONLY.0)
+    };
+
+    let mut progress = MsiProgress {
+        on_progress,
+        total: 0,
+        done: 0,
+        forward: true,
+        reported: None,
+    };
+    let code = unsafe {
+        let previous_level = MsiSetInternalUI(ui_level, None);
+        let previous_handler = MsiSetExternalUIW(
+            Some(msi_ui_handler),
+            MSI_PROGRESS_FILTER,
+            Some(&mut progress as *mut MsiProgress<'_> as *const c_void),
+        );
+        let code = MsiInstallProductW(&package, &command_line);
+        MsiSetExternalUIW(previous_handler, 0, None);
+        MsiSetInternalUI(previous_level, None);
+        code
+    };
+
+    match code as i32 {
+        0 => {
+            (progress.on_progress)(InstallProgress::Finishing);
+            Ok(())
+        }
+        MSI_REBOOT_REQUIRED => Err(Error::RebootRequired),
+        code => Err(Error::MsiExecutionFailed(code)),
+    }
+}
+
+/// Percentage tracking for Windows Installer progress messages.
+struct MsiProgress<'a> {
+    on_progress: &'a mut dyn FnMut(InstallProgress),
+    total: u64,
+    done: u64,
+    forward: bool,
+    reported: Option<u8>,
+}
+
+impl MsiProgress<'_> {
+    /// Applies a progress message such as `1: 2 2: 25 3: 0 4: 0`.
+    ///
+    /// Type `0` resets the tick count and direction, type `2` moves it.
+    fn record(&mut self, message: &str) {
+        let fields = message
+            .split_whitespace()
+            .filter(|token| !token.ends_with(':'))
+            .filter_map(|token| token.parse::<i64>().ok())
+            .collect::<Vec<_>>();
+        match fields[..] {
+            [0, total, direction, ..] => {
+                self.total = total.max(0) as u64;
+                self.forward = direction == 0;
+                self.done = if self.forward { 0 } else { self.total };
+            }
+            [2, ticks, ..] if self.total > 0 => {
+                let ticks = ticks.max(0) as u64;
+                self.done = if self.forward {
+                    self.done.saturating_add(ticks).min(self.total)
+                } else {
+                    self.done.saturating_sub(ticks)
+                };
+            }
+            _ => return,
+        }
+        let done = if self.forward {
+            self.done
+        } else {
+            self.total - self.done
+        };
+        let percent = done
+            .saturating_mul(100)
+            .checked_div(self.total)
+            .unwrap_or(0) as u8;
+        if self.reported != Some(percent) {
+            self.reported = Some(percent);
+            (self.on_progress)(InstallProgress::Installing(percent));
+        }
+    }
+}
+
+unsafe extern "system" fn msi_ui_handler(
+    context: *mut c_void,
+    message_type: u32,
+    message: PCWSTR,
+) -> i32 {
+    if message_type & 0xFF00_0000 == MSI_PROGRESS_MESSAGE && !message.is_null() {
+        let progress = unsafe { &mut *(context as *mut MsiProgress<'_>) };
+        if let Ok(message) = unsafe { message.to_string() } {
+            progress.record(&message);
+        }
+    }
+    // Zero leaves the message to the internal UI.
+    0
+}
+
+/// Runs `file` with [`elevation_verb`] and waits for it, returning its exit code.
+fn run_elevated(file: PCWSTR, args: &[OsString]) -> Result<i32> {
+    let parameters = windows_installer_args_command_line(args).map(HSTRING::from);
+    let mut info = SHELLEXECUTEINFOW {
+        cbSize: std::mem::size_of::<SHELLEXECUTEINFOW>() as u32,
+        fMask: SEE_MASK_NOCLOSEPROCESS,
+        lpVerb: elevation_verb(),
+        lpFile: file,
+        lpParameters: parameters
+            .as_ref()
+            .map(|parameters| PCWSTR(parameters.as_ptr()))
+            .unwrap_or(w!("")),
+        nShow: SW_SHOW.0,
+        ..Default::default()
+    };
+    if let Err(error) = unsafe { ShellExecuteExW(&mut info) } {
+        return Err(if error.code() == ERROR_CANCELLED.to_hresult() {
+            Error::UserCancelledElevation
+        } else {
+            std::io::Error::other(error).into()
+        });
+    }
+
+    let mut code = 0u32;
+    let waited = unsafe {
+        WaitForSingleObject(info.hProcess, INFINITE);
+        GetExitCodeProcess(info.hProcess, &mut code)
+    };
+    let _ = unsafe { CloseHandle(info.hProcess) };
+    waited.map_err(std::io::Error::other)?;
+    Ok(code as i32)
+}
+
+fn install_windows_with_label(bytes: &[u8], options: &InstallerOptions<'_>) -> Result<()> {
+    let (temp_path, temp_keeper) = extract_exe(bytes, options, ".exe")?;
 
     if !temp_path.exists() {
         return Err(Error::InvalidUpdaterFormat);
@@ -59,21 +495,17 @@ fn install_windows_with_label(bytes: &[u8], app_name: &str, version: &Version) -
     UPDATER_FILE
         .set(file)
         .map_err(|_| Error::InvalidUpdaterFormat)?;
+    let _ = UPDATER_IS_NSIS.set(Updater::is_nsis_installer(bytes));
 
     Ok(())
 }
 
-fn launch_windows_installer(
-    bytes: &[u8],
-    app_name: &str,
-    version: &Version,
-    installer_args: &[OsString],
-) -> Result<()> {
-    install_windows_with_label(bytes, app_name, version)?;
-    relaunch_windows(installer_args)
+fn launch_windows_installer(bytes: &[u8], options: &InstallerOptions<'_>) -> Result<()> {
+    install_windows_with_label(bytes, options)?;
+    relaunch_windows(options.installer_args, options.silent)
 }
 
-fn relaunch_windows(installer_args: &[OsString]) -> Result<()> {
+fn relaunch_windows(installer_args: &[OsString], silent: bool) -> Result<()> {
     let file = UPDATER_FILE.get().ok_or(Error::InvalidUpdaterFormat)?;
 
     if !Path::new(file).exists() {
@@ -81,13 +513,16 @@ fn relaunch_windows(installer_args: &[OsString]) -> Result<()> {
     }
 
     let file_hstring: HSTRING = file.clone().into();
-    let installer_args = windows_installer_args_command_line(installer_args);
+    // Only NSIS installers have a known silent flag; others keep their UI.
+    let is_nsis = UPDATER_IS_NSIS.get().copied().unwrap_or_default();
+    let installer_args = nsis_installer_args(silent && is_nsis, installer_args);
+    let installer_args = windows_installer_args_command_line(&installer_args);
     let installer_args_hstring = installer_args.as_ref().map(HSTRING::from);
     let installer_args = installer_args_hstring.as_ref();
     let result = unsafe {
         ShellExecuteW(
             Some(HWND::default()),
-            w!("runas"),
+            elevation_verb(),
             &file_hstring,
             installer_args.map(Into::into).unwrap_or(w!("")),
             w!("."),
@@ -98,13 +533,7 @@ fn relaunch_windows(installer_args: &[OsString]) -> Result<()> {
 
     if result <= 32 {
         *TEMP_FILE_KEEPER.lock().unwrap() = None;
-        return match result {
-            2 => Err(crate::Error::InvalidUpdaterFormat),
-            5 => Err(crate::Error::InsufficientPrivileges),
-            32 => Err(crate::Error::FileInUse),
-            1223 => Err(crate::Error::UserCancelledElevation),
-            _ => Err(crate::Error::InstallerExecutionFailed(result)),
-        };
+        return Err(shell_execute_error(result));
     }
 
     *TEMP_FILE_KEEPER.lock().unwrap() = None;
@@ -112,10 +541,30 @@ fn relaunch_windows(installer_args: &[OsString]) -> Result<()> {
     std::process::exit(0);
 }
 
-fn make_temp_dir(app_name: &str, version: &Version) -> Result<PathBuf> {
-    let temp_dir = tempfile::Builder::new()
-        .prefix(&format!("{app_name}-{version}-updater-"))
-        .tempdir();
+/// Returns the `ShellExecuteW` verb for launching elevated.
+///
+/// An already elevated process uses `open`, since `runas` would show a
+/// redundant UAC prompt.
+fn elevation_verb() -> PCWSTR {
+    if crate::utils::is_elevated() {
+        w!("open")
+    } else {
+        w!("runas")
+    }
+}
+
+fn shell_execute_error(result: i32) -> Error {
+    match result {
+        2 => Error::InvalidUpdaterFormat,
+        5 => Error::InsufficientPrivileges,
+        32 => Error::FileInUse,
+        1223 => Error::UserCancelledElevation,
+        _ => Error::InstallerExecutionFailed(result),
+    }
+}
+
+fn make_temp_dir(app_name: &str, version: &Version, parent: Option<&Path>) -> Result<PathBuf> {
+    let temp_dir = crate::utils::temp_dir_in(&format!("{app_name}-{version}-updater-"), parent);
 
     match temp_dir {
         Ok(dir) => {
@@ -136,8 +585,29 @@ fn make_temp_dir(app_name: &str, version: &Version) -> Result<PathBuf> {
     }
 }
 
-fn extract_exe(bytes: &[u8], app_name: &str, version: &Version) -> Result<WindowsUpdaterType> {
-    let (path, temp) = write_to_temp(bytes, app_name, version, ".exe")?;
+/// Writes the installer to a temporary file, checking its Authenticode
+/// signature when [`InstallerOptions::verify_authenticode`] is set.
+///
+/// An installer failing the check is deleted before the error is returned.
+fn extract_exe(
+    bytes: &[u8],
+    options: &InstallerOptions<'_>,
+    ext: &str,
+) -> Result<WindowsUpdaterType> {
+    let (path, temp) = write_to_temp(
+        bytes,
+        options.app_name,
+        options.version,
+        ext,
+        options.temp_dir,
+    )?;
+    if options.verify_authenticode
+        && let Err(error) = verify_authenticode(&path, options.expected_signer)
+    {
+        drop(temp);
+        let _ = fs::remove_file(&path);
+        return Err(error);
+    }
     Ok((path, temp))
 }
 
@@ -146,10 +616,11 @@ fn write_to_temp(
     app_name: &str,
     version: &Version,
     ext: &str,
+    parent: Option<&Path>,
 ) -> Result<(PathBuf, Option<tempfile::TempPath>)> {
     use std::io::Write;
 
-    let temp_dir = make_temp_dir(app_name, version)?;
+    let temp_dir = make_temp_dir(app_name, version, parent)?;
     let mut temp_file = tempfile::Builder::new()
         .prefix(&format!("{app_name}-{version}-installer"))
         .suffix(ext)
@@ -168,3 +639,119 @@ fn write_to_temp(
 
     Ok((temp_path, Some(temp)))
 }
+
+/// Checks the Authenticode signature of the file at `path` with `WinVerifyTrust`.
+///
+/// With `expected_signer`, the simple display name of the signing
+/// certificate, usually its common name, must also equal it.
+pub(crate) fn verify_authenticode(path: &Path, expected_signer: Option<&str>) -> Result<()> {
+    let path: HSTRING = path.as_os_str().into();
+    let mut file_info = WINTRUST_FILE_INFO {
+        cbStruct: size_of::<WINTRUST_FILE_INFO>() as u32,
+        pcwszFilePath: PCWSTR(path.as_ptr()),
+        ..Default::default()
+    };
+    let mut data = WINTRUST_DATA {
+        cbStruct: size_of::<WINTRUST_DATA>() as u32,
+        dwUIChoice: WTD_UI_NONE,
+        fdwRevocationChecks: WTD_REVOKE_NONE,
+        dwUnionChoice: WTD_CHOICE_FILE,
+        Anonymous: WINTRUST_DATA_0 {
+            pFile: &mut file_info,
+        },
+        dwStateAction: WTD_STATEACTION_VERIFY,
+        ..Default::default()
+    };
+    let mut action = WINTRUST_ACTION_GENERIC_VERIFY_V2;
+    let status = unsafe {
+        WinVerifyTrust(
+            HWND::default(),
+            &mut action,
+            &mut data as *mut WINTRUST_DATA as *mut c_void,
+        )
+    };
+    let signer = match expected_signer {
+        Some(_) if status == 0 => signer_name(data.hWVTStateData),
+        _ => None,
+    };
+
+    // The verification state is held until it is released with a second call.
+    data.dwStateAction = WTD_STATEACTION_CLOSE;
+    unsafe {
+        WinVerifyTrust(
+            HWND::default(),
+            &mut action,
+            &mut data as *mut WINTRUST_DATA as *mut c_void,
+        )
+    };
+
+    if status != 0 {
+        return Err(Error::SignatureVerificationFailed);
+    }
+    match expected_signer {
+        Some(expected) if signer.as_deref() != Some(expected) => {
+            Err(Error::SignatureVerificationFailed)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Returns the simple display name of the certificate that signed the file
+/// verified with `state`.
+fn signer_name(state: HANDLE) -> Option<String> {
+    let provider = unsafe { WTHelperProvDataFromStateData(state) };
+    if provider.is_null() {
+        return None;
+    }
+    let signer = unsafe { WTHelperGetProvSignerFromChain(provider, 0, false, 0) };
+    if signer.is_null() || unsafe { (*signer).csCertChain } == 0 {
+        return None;
+    }
+    let cert = unsafe { (*(*signer).pasCertChain).pCert };
+    let len = unsafe { CertGetNameStringW(cert, CERT_NAME_SIMPLE_DISPLAY_TYPE, 0, None, None) };
+    let mut name = vec![0u16; len as usize];
+    unsafe {
+        CertGetNameStringW(
+            cert,
+            CERT_NAME_SIMPLE_DISPLAY_TYPE,
+            0,
+            None,
+            Some(&mut name),
+        )
+    };
+    Some(
+        String::from_utf16_lossy(&name)
+            .trim_end_matches('\0')
+            .to_owned(),
+    )
+}
+
+/// Returns whether the token of the current process is elevated.
+pub(crate) fn token_is_elevated() -> bool {
+    let mut token = HANDLE::default();
+    if unsafe { OpenProcessToken(GetCurrentProcess(), TOKEN_QUERY, &mut token) }.is_err() {
+        return false;
+    }
+    let mut elevation = TOKEN_ELEVATION::default();
+    let mut size = 0;
+    let result = unsafe {
+        GetTokenInformation(
+            token,
+            TokenElevation,
+            Some(&mut elevation as *mut TOKEN_ELEVATION as *mut c_void),
+            size_of::<TOKEN_ELEVATION>() as u32,
+            &mut size,
+        )
+    };
+    let _ = unsafe { CloseHandle(token) };
+    result.is_ok() && elevation.TokenIsElevated != 0
+}
+
+/// Returns the bytes available to the current user on the volume holding the directory `path`.
+pub(crate) fn free_disk_space(path: &Path) -> Result<u64> {
+    let path: HSTRING = path.as_os_str().into();
+    let mut available = 0u64;
+    unsafe { GetDiskFreeSpaceExW(&path, Some(&mut available), None, None) }
+        .map_err(std::io::Error::from)?;
+    Ok(available)
+}
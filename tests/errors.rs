@@ -0,0 +1,39 @@
+use release_hub::Error;
+use std::io::{self, ErrorKind};
+use std::time::{Duration, UNIX_EPOCH};
+
+#[test]
+fn updater_errors_convert_into_io_errors() {
+    fn io_pipeline(error: Error) -> io::Result<()> {
+        Err(error)?;
+        Ok(())
+    }
+
+    let original = io::Error::new(ErrorKind::TimedOut, "slow disk");
+    let io_error = io_pipeline(Error::Io(original)).unwrap_err();
+    assert_eq!(io_error.kind(), ErrorKind::TimedOut);
+    assert_eq!(io_error.to_string(), "slow disk");
+
+    let kind = |error| io_pipeline(error).unwrap_err().kind();
+    assert_eq!(
+        kind(Error::InsufficientPrivileges),
+        ErrorKind::PermissionDenied
+    );
+    assert_eq!(kind(Error::FileInUse), ErrorKind::WouldBlock);
+    assert_eq!(kind(Error::AssetNotFound), ErrorKind::NotFound);
+
+    let other = io_pipeline(Error::TargetNotFound("linux-x86_64".into())).unwrap_err();
+    assert_eq!(other.kind(), ErrorKind::Other);
+    assert!(other.to_string().contains("linux-x86_64"));
+}
+
+#[test]
+fn rate_limit_error_reports_reset_time() {
+    let error = Error::RateLimitExceeded {
+        reset_at: Some(UNIX_EPOCH + Duration::from_secs(1_700_000_000)),
+    };
+    assert!(error.to_string().contains("until 2023-11-14T22:13:20Z"));
+
+    let error = Error::RateLimitExceeded { reset_at: None };
+    assert!(!error.to_string().contains("until"));
+}
@@ -0,0 +1,401 @@
+#![cfg(feature = "test-utils")]
+
+use release_hub::github::testing::{FakeGitHubClient, fake_release};
+use release_hub::{Config, ReleaseSource, SourceRequest, UpdaterBuilder};
+use semver::Version;
+
+#[tokio::test]
+async fn fake_client_replays_queued_releases_in_order() {
+    let source = FakeGitHubClient::new(vec![
+        fake_release(
+            "v1.2.3",
+            vec![
+                ("app-linux-x86_64.AppImage", 1024),
+                ("app-linux-x86_64.AppImage.sig", 64),
+            ],
+        ),
+        fake_release(
+            "v1.3.0",
+            vec![
+                ("app-linux-x86_64.AppImage", 2048),
+                ("app-linux-x86_64.AppImage.sig", 64),
+            ],
+        ),
+    ]);
+    let request = SourceRequest::new("linux-x86_64");
+
+    let first = source.fetch(&request).await.unwrap();
+    let second = source.fetch(&request).await.unwrap();
+
+    assert_eq!(first.version, Version::parse("1.2.3").unwrap());
+    assert_eq!(second.version, Version::parse("1.3.0").unwrap());
+    assert!(source.fetch(&request).await.is_err());
+}
+
+#[tokio::test]
+async fn fake_client_surfaces_queued_errors() {
+    let source = FakeGitHubClient::new(Vec::new());
+    source.push_error(release_hub::Error::AssetNotFound);
+
+    let err = source
+        .fetch(&SourceRequest::new("linux-x86_64"))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, release_hub::Error::AssetNotFound));
+}
+
+#[tokio::test]
+async fn updater_exposes_raw_tag_of_latest_release() {
+    let source = FakeGitHubClient::new(vec![fake_release(
+        "v1.2.3",
+        vec![
+            ("app-linux-x86_64.AppImage", 1024),
+            ("app-linux-x86_64.AppImage.sig", 64),
+        ],
+    )]);
+    let updater = UpdaterBuilder::new("ReleaseHub", "1.0.0", Config::default())
+        .target("linux-x86_64")
+        .source(Box::new(source))
+        .build()
+        .unwrap();
+
+    assert_eq!(updater.latest_version_tag(), None);
+    updater.check().await.unwrap();
+    assert_eq!(updater.latest_version_tag().as_deref(), Some("v1.2.3"));
+    assert_eq!(
+        updater.latest_version(),
+        Some(Version::parse("1.2.3").unwrap())
+    );
+}
+
+#[tokio::test]
+async fn fake_client_matches_assets_named_by_rust_target_triple() {
+    let source = FakeGitHubClient::new(vec![fake_release(
+        "v2.0.0",
+        vec![
+            ("app-x86_64-unknown-linux-gnu.AppImage", 1024),
+            ("app-x86_64-unknown-linux-gnu.AppImage.sig", 64),
+        ],
+    )]);
+
+    let release = source
+        .fetch(&SourceRequest::new("linux-x86_64"))
+        .await
+        .unwrap();
+
+    assert!(
+        release
+            .download_url("linux-x86_64")
+            .unwrap()
+            .as_str()
+            .ends_with("app-x86_64-unknown-linux-gnu.AppImage")
+    );
+}
+
+#[tokio::test]
+async fn fake_client_falls_back_to_universal_macos_assets() {
+    let universal = [
+        ("app-macos-universal.app.zip", 4096),
+        ("app-macos-universal.app.zip.sig", 64),
+    ];
+    let source = FakeGitHubClient::new(vec![
+        fake_release("v2.0.0", universal.to_vec()),
+        fake_release(
+            "v2.0.1",
+            [
+                universal.as_slice(),
+                &[
+                    ("app-darwin-aarch64.app.zip", 2048),
+                    ("app-darwin-aarch64.app.zip.sig", 64),
+                ],
+            ]
+            .concat(),
+        ),
+    ]);
+    let request = SourceRequest::new("darwin-aarch64");
+    let asset_url = |release: release_hub::RemoteRelease| {
+        release
+            .download_url("darwin-aarch64")
+            .unwrap()
+            .as_str()
+            .to_owned()
+    };
+
+    let fallback = asset_url(source.fetch(&request).await.unwrap());
+    let specific = asset_url(source.fetch(&request).await.unwrap());
+
+    assert!(fallback.ends_with("app-macos-universal.app.zip"));
+    assert!(specific.ends_with("app-darwin-aarch64.app.zip"));
+}
+
+#[tokio::test]
+async fn updater_rejects_assets_over_size_limit() {
+    let source = FakeGitHubClient::new(vec![fake_release(
+        "v2.0.0",
+        vec![
+            ("app-linux-x86_64.AppImage", 5 * 1024),
+            ("app-linux-x86_64.AppImage.sig", 64),
+        ],
+    )]);
+    let updater = UpdaterBuilder::new("ReleaseHub", "1.0.0", Config::default())
+        .target("linux-x86_64")
+        .source(Box::new(source))
+        .max_asset_size(1024)
+        .build()
+        .unwrap();
+
+    let err = updater.check().await.unwrap_err();
+
+    assert!(matches!(
+        err,
+        release_hub::Error::AssetTooLarge {
+            limit: 1024,
+            actual: 5120
+        }
+    ));
+}
+
+#[tokio::test]
+async fn fake_client_records_release_and_asset_ids() {
+    let source = FakeGitHubClient::new(vec![fake_release(
+        "v2.0.0",
+        vec![
+            ("app-linux-x86_64.AppImage", 1024),
+            ("app-linux-x86_64.AppImage.sig", 64),
+        ],
+    )]);
+
+    let release = source
+        .fetch(&SourceRequest::new("linux-x86_64"))
+        .await
+        .unwrap();
+
+    assert_eq!(release.release_id, Some(1));
+    let release_hub::RemoteReleaseInner::Static { platforms } = &release.data else {
+        panic!("GitHub releases are keyed by target");
+    };
+    assert_eq!(platforms["linux-x86_64"].asset_id, Some(1));
+}
+
+#[tokio::test]
+async fn fake_client_prefers_assets_for_the_host_distro() {
+    let Some(distro) = release_hub::detect_linux_distro() else {
+        return;
+    };
+    let preferred = format!("app-{distro}-linux-x86_64.deb");
+    let source = FakeGitHubClient::new(vec![fake_release(
+        "v2.0.0",
+        vec![
+            ("app-otherdistro-linux-x86_64.deb", 1024),
+            ("app-otherdistro-linux-x86_64.deb.sig", 64),
+            (&preferred, 1024),
+            (&format!("{preferred}.sig"), 64),
+        ],
+    )]);
+
+    let release = source
+        .fetch(&SourceRequest::new("linux-x86_64"))
+        .await
+        .unwrap();
+
+    assert!(
+        release
+            .download_url("linux-x86_64")
+            .unwrap()
+            .as_str()
+            .ends_with(&preferred)
+    );
+}
+
+#[tokio::test]
+async fn fake_client_records_release_page_url() {
+    let source = FakeGitHubClient::new(vec![fake_release(
+        "v2.0.0",
+        vec![
+            ("app-linux-x86_64.AppImage", 1024),
+            ("app-linux-x86_64.AppImage.sig", 64),
+        ],
+    )]);
+
+    let release = source
+        .fetch(&SourceRequest::new("linux-x86_64"))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        release.html_url.unwrap().as_str(),
+        "https://github.com/fake/fake/releases/tag/v2.0.0"
+    );
+}
+
+#[tokio::test]
+async fn fake_client_lists_every_release_asset() {
+    let source = FakeGitHubClient::new(vec![fake_release(
+        "v2.0.0",
+        vec![
+            ("app-linux-x86_64.AppImage", 1024),
+            ("app-linux-x86_64.AppImage.sig", 64),
+            ("app-source.tar.gz", 4096),
+        ],
+    )]);
+
+    let release = source
+        .fetch(&SourceRequest::new("linux-x86_64"))
+        .await
+        .unwrap();
+
+    let source_archive = release.find_asset_by_name("app-source.tar.gz").unwrap();
+    assert_eq!(source_archive.size, Some(4096));
+    assert_eq!(release.assets.len(), 3);
+}
+
+#[tokio::test]
+async fn fake_client_matches_appimages_named_after_linux_distros() {
+    let source = FakeGitHubClient::new(vec![fake_release(
+        "v2.0.0",
+        vec![
+            ("app-ubuntu-x86_64.AppImage", 1024),
+            ("app-ubuntu-x86_64.AppImage.sig", 64),
+        ],
+    )]);
+
+    let release = source
+        .fetch(&SourceRequest::new("linux-x86_64"))
+        .await
+        .unwrap();
+
+    assert!(
+        release
+            .download_url("linux-x86_64")
+            .unwrap()
+            .as_str()
+            .ends_with("app-ubuntu-x86_64.AppImage")
+    );
+}
+
+#[tokio::test]
+async fn fake_client_skips_zip_archives_for_other_platforms() {
+    let source = FakeGitHubClient::new(vec![
+        fake_release(
+            "v2.0.0",
+            vec![
+                ("app-darwin-aarch64.zip", 1024),
+                ("app-darwin-aarch64.zip.sig", 64),
+                ("app-darwin-aarch64.app.zip", 1024),
+                ("app-darwin-aarch64.app.zip.sig", 64),
+            ],
+        ),
+        fake_release(
+            "v2.0.0",
+            vec![
+                ("app-linux-x86_64.zip", 1024),
+                ("app-linux-x86_64.zip.sig", 64),
+            ],
+        ),
+    ]);
+
+    let macos = source
+        .fetch(&SourceRequest::new("darwin-aarch64"))
+        .await
+        .unwrap();
+    let linux = source.fetch(&SourceRequest::new("linux-x86_64")).await;
+
+    assert!(
+        macos
+            .download_url("darwin-aarch64")
+            .unwrap()
+            .as_str()
+            .ends_with("app-darwin-aarch64.app.zip")
+    );
+    assert!(matches!(linux, Err(release_hub::Error::TargetNotFound(_))));
+}
+
+#[tokio::test]
+async fn fake_client_skips_binary_tarballs_for_macos() {
+    let source = FakeGitHubClient::new(vec![fake_release(
+        "v2.0.0",
+        vec![
+            ("app-darwin-aarch64.tar.gz", 1024),
+            ("app-darwin-aarch64.tar.gz.sig", 64),
+            ("app-darwin-aarch64.app.tar.gz", 1024),
+            ("app-darwin-aarch64.app.tar.gz.sig", 64),
+        ],
+    )]);
+
+    let release = source
+        .fetch(&SourceRequest::new("darwin-aarch64"))
+        .await
+        .unwrap();
+
+    assert!(
+        release
+            .download_url("darwin-aarch64")
+            .unwrap()
+            .as_str()
+            .ends_with("app-darwin-aarch64.app.tar.gz")
+    );
+}
+
+#[tokio::test]
+async fn fake_client_prefers_app_zip_over_dmg_unless_requested() {
+    let assets = vec![
+        ("app-darwin-aarch64.dmg", 1024),
+        ("app-darwin-aarch64.dmg.sig", 64),
+        ("app-darwin-aarch64.app.zip", 1024),
+        ("app-darwin-aarch64.app.zip.sig", 64),
+    ];
+    let source = FakeGitHubClient::new(vec![
+        fake_release("v2.0.0", assets.clone()),
+        fake_release("v2.0.0", assets),
+    ]);
+
+    let default = source
+        .fetch(&SourceRequest::new("darwin-aarch64"))
+        .await
+        .unwrap();
+    let dmg = source
+        .fetch(&SourceRequest::new("darwin-aarch64").prefer_dmg(true))
+        .await
+        .unwrap();
+
+    let url = |release: &release_hub::RemoteRelease| {
+        release.download_url("darwin-aarch64").unwrap().to_string()
+    };
+    assert!(url(&default).ends_with(".app.zip"));
+    assert!(url(&dmg).ends_with(".dmg"));
+}
+
+#[tokio::test]
+async fn updater_lists_at_most_the_requested_number_of_releases() {
+    let release = |tag| {
+        fake_release(
+            tag,
+            vec![
+                ("app-linux-x86_64.AppImage", 1024),
+                ("app-linux-x86_64.AppImage.sig", 64),
+            ],
+        )
+    };
+    let source = FakeGitHubClient::new(vec![
+        release("v1.3.0"),
+        release("v1.2.0"),
+        release("v1.1.0"),
+    ]);
+    let updater = UpdaterBuilder::new("ReleaseHub", "1.0.0", Config::default())
+        .target("linux-x86_64")
+        .source(Box::new(source))
+        .build()
+        .unwrap();
+
+    let versions = updater
+        .list_releases(2)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|release| release.version.to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(versions, ["1.3.0", "1.2.0"]);
+    assert_eq!(updater.list_releases(5).await.unwrap().len(), 1);
+}
@@ -0,0 +1,287 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use release_hub::{AssetFilter, GitLabSource, ReleaseAsset, ReleaseSource, SourceRequest};
+use std::sync::Arc;
+use url::Url;
+
+fn release_body(server: &MockServer) -> String {
+    format!(
+        r#"{{
+            "tag_name": "v1.2.3",
+            "description": "Stable",
+            "released_at": "2026-04-21T08:00:00Z",
+            "upcoming_release": false,
+            "_links": {{ "self": "https://gitlab.example/group/app/-/releases/v1.2.3" }},
+            "assets": {{
+                "links": [
+                    {{ "id": 1, "name": "app-linux-x86_64.AppImage", "url": "{0}" }},
+                    {{ "id": 2, "name": "app-linux-x86_64.AppImage.sig", "url": "{1}" }},
+                    {{ "id": 3, "name": "app-windows-x86_64.msi", "url": "{2}" }}
+                ]
+            }}
+        }}"#,
+        server.url("/files/app.AppImage"),
+        server.url("/files/app.AppImage.sig"),
+        server.url("/files/app.msi"),
+    )
+}
+
+#[tokio::test]
+async fn gitlab_source_pairs_latest_release_asset_with_signature() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/api/v4/projects/group%2Fapp/releases/permalink/latest");
+        then.status(200).body(release_body(&server));
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/files/app.AppImage.sig");
+        then.status(200).body("sig-linux");
+    });
+
+    let source = GitLabSource::new(Url::parse(&server.base_url()).unwrap(), "group/app");
+    let release = source
+        .fetch(&SourceRequest::new("linux-x86_64"))
+        .await
+        .unwrap();
+
+    assert_eq!(release.version.to_string(), "1.2.3");
+    assert_eq!(release.signature("linux-x86_64").unwrap(), "sig-linux");
+    assert_eq!(
+        release.download_url("linux-x86_64").unwrap().as_str(),
+        server.url("/files/app.AppImage")
+    );
+    assert_eq!(release.notes.as_deref(), Some("Stable"));
+    assert_eq!(release.assets.len(), 3);
+}
+
+#[tokio::test]
+async fn gitlab_source_sends_private_token() {
+    let server = MockServer::start();
+    let api = server.mock(|when, then| {
+        when.method(GET)
+            .path("/api/v4/projects/42/releases/permalink/latest")
+            .header("PRIVATE-TOKEN", "secret");
+        then.status(200).body(release_body(&server));
+    });
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/files/app.AppImage.sig")
+            .header("PRIVATE-TOKEN", "secret");
+        then.status(200).body("sig-linux");
+    });
+
+    let source =
+        GitLabSource::with_token(Url::parse(&server.base_url()).unwrap(), "42", "secret").unwrap();
+    let release = source
+        .fetch(&SourceRequest::new("linux-x86_64"))
+        .await
+        .unwrap();
+
+    api.assert();
+    assert_eq!(release.download_headers["PRIVATE-TOKEN"], "secret");
+}
+
+#[tokio::test]
+async fn gitlab_source_requires_matching_signature_asset() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/api/v4/projects/42/releases/permalink/latest");
+        then.status(200).body(release_body(&server));
+    });
+
+    let source = GitLabSource::new(Url::parse(&server.base_url()).unwrap(), "42");
+    let err = source
+        .fetch(&SourceRequest::new("windows-x86_64"))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        release_hub::Error::MissingSignatureAsset(name) if name == "app-windows-x86_64.msi"
+    ));
+}
+
+#[tokio::test]
+async fn gitlab_source_resolves_pinned_version_from_release_list() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/api/v4/projects/42/releases")
+            .query_param("per_page", "100");
+        then.status(200)
+            .body(format!("[{}]", release_body(&server)));
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/files/app.AppImage.sig");
+        then.status(200).body("sig-linux");
+    });
+
+    let source = GitLabSource::new(Url::parse(&server.base_url()).unwrap(), "42");
+    let release = source
+        .fetch(&SourceRequest::new("linux-x86_64").version(semver::Version::new(1, 2, 3)))
+        .await
+        .unwrap();
+    assert_eq!(release.version.to_string(), "1.2.3");
+
+    let err = source
+        .fetch(&SourceRequest::new("linux-x86_64").version(semver::Version::new(1, 0, 0)))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, release_hub::Error::VersionNotFound(_)));
+}
+
+#[tokio::test]
+async fn gitlab_source_applies_asset_filter_after_target_match() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/api/v4/projects/42/releases/permalink/latest");
+        then.status(200).body(format!(
+            r#"{{
+                "tag_name": "v1.2.3",
+                "assets": {{
+                    "links": [
+                        {{ "id": 1, "name": "app-linux-x86_64.AppImage", "url": "{0}" }},
+                        {{ "id": 2, "name": "app-linux-x86_64.AppImage.sig", "url": "{1}" }},
+                        {{ "id": 3, "name": "app-linux-x86_64-static.AppImage", "url": "{2}" }},
+                        {{ "id": 4, "name": "app-linux-x86_64-static.AppImage.sig", "url": "{3}" }},
+                        {{ "id": 5, "name": "app-windows-x86_64-static.msi", "url": "{4}" }}
+                    ]
+                }}
+            }}"#,
+            server.url("/files/app.AppImage"),
+            server.url("/files/app.AppImage.sig"),
+            server.url("/files/app-static.AppImage"),
+            server.url("/files/app-static.AppImage.sig"),
+            server.url("/files/app-static.msi"),
+        ));
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/files/app-static.AppImage.sig");
+        then.status(200).body("sig-static");
+    });
+
+    let source = GitLabSource::new(Url::parse(&server.base_url()).unwrap(), "42");
+    let static_only: AssetFilter = Arc::new(|asset: &ReleaseAsset| asset.name.contains("static"));
+    let release = source
+        .fetch(&SourceRequest::new("linux-x86_64").asset_filter(static_only))
+        .await
+        .unwrap();
+    assert_eq!(release.signature("linux-x86_64").unwrap(), "sig-static");
+    assert_eq!(
+        release.download_url("linux-x86_64").unwrap().as_str(),
+        server.url("/files/app-static.AppImage")
+    );
+
+    let fips_only: AssetFilter = Arc::new(|asset: &ReleaseAsset| asset.name.contains("fips"));
+    let err = source
+        .fetch(&SourceRequest::new("linux-x86_64").asset_filter(fips_only))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, release_hub::Error::AssetNotFound));
+}
+
+#[tokio::test]
+async fn gitlab_source_skips_assets_not_matching_name_glob() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/api/v4/projects/42/releases/permalink/latest");
+        then.status(200).body(format!(
+            r#"{{
+                "tag_name": "v1.2.3",
+                "assets": {{
+                    "links": [
+                        {{ "id": 1, "name": "tools-linux-x86_64.AppImage", "url": "{0}" }},
+                        {{ "id": 2, "name": "tools-linux-x86_64.AppImage.sig", "url": "{1}" }},
+                        {{ "id": 3, "name": "app-1.2.3-20250101-linux-x86_64.AppImage", "url": "{2}" }},
+                        {{ "id": 4, "name": "app-1.2.3-20250101-linux-x86_64.AppImage.sig", "url": "{3}" }}
+                    ]
+                }}
+            }}"#,
+            server.url("/files/tools.AppImage"),
+            server.url("/files/tools.AppImage.sig"),
+            server.url("/files/app.AppImage"),
+            server.url("/files/app.AppImage.sig"),
+        ));
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/files/app.AppImage.sig");
+        then.status(200).body("sig-app");
+    });
+
+    let source = GitLabSource::new(Url::parse(&server.base_url()).unwrap(), "42");
+    let release = source
+        .fetch(&SourceRequest::new("linux-x86_64").asset_name_glob("app-*-linux-*.AppImage"))
+        .await
+        .unwrap();
+
+    assert_eq!(release.signature("linux-x86_64").unwrap(), "sig-app");
+    assert_eq!(
+        release.download_url("linux-x86_64").unwrap().as_str(),
+        server.url("/files/app.AppImage")
+    );
+}
+
+#[tokio::test]
+async fn gitlab_source_keeps_private_token_on_the_configured_host() {
+    let server = MockServer::start();
+    let off_host = format!("http://localhost:{}", server.port());
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/api/v4/projects/42/releases/permalink/latest")
+            .header("PRIVATE-TOKEN", "secret");
+        then.status(200).body(format!(
+            r#"{{
+                "tag_name": "v1.3.0-beta.1",
+                "assets": {{
+                    "links": [
+                        {{ "id": 1, "name": "app-linux-x86_64.AppImage", "url": "{off_host}/files/app.AppImage" }},
+                        {{ "id": 2, "name": "app-linux-x86_64.AppImage.sig", "url": "{off_host}/files/app.AppImage.sig" }}
+                    ]
+                }}
+            }}"#
+        ));
+    });
+    let signature = server.mock(|when, then| {
+        when.method(GET)
+            .path("/files/app.AppImage.sig")
+            .header_missing("PRIVATE-TOKEN");
+        then.status(200).body("sig-linux");
+    });
+
+    let source =
+        GitLabSource::with_token(Url::parse(&server.base_url()).unwrap(), "42", "secret").unwrap();
+    let release = source
+        .fetch(&SourceRequest::new("linux-x86_64"))
+        .await
+        .unwrap();
+
+    signature.assert();
+    assert!(release.download_headers.is_empty());
+    assert!(release.prerelease);
+}
+
+#[tokio::test]
+async fn gitlab_source_fetches_notes_of_the_pinned_version() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/api/v4/projects/42/releases")
+            .query_param("per_page", "100");
+        then.status(200).body(format!(
+            r#"[{}, {{ "tag_name": "v1.0.0", "description": "Old" }}]"#,
+            release_body(&server)
+        ));
+    });
+
+    let source = GitLabSource::new(Url::parse(&server.base_url()).unwrap(), "42");
+    let notes = source
+        .fetch_notes(&SourceRequest::new("linux-x86_64").version(semver::Version::new(1, 0, 0)))
+        .await
+        .unwrap();
+
+    assert_eq!(notes.as_deref(), Some("Old"));
+}
@@ -1,5 +1,4 @@
-use http::HeaderMap;
-use release_hub::{InstallerKind, LinuxInstallCommand, Update};
+use release_hub::{InstallProgress, InstallerKind, LinuxInstallCommand, Update};
 use semver::Version;
 use std::path::PathBuf;
 use url::Url;
@@ -14,34 +13,391 @@ fn linux_deb_backend_builds_expected_install_command() {
     assert_eq!(command.args, vec!["dpkg", "-i", "/tmp/release-hub.deb"]);
 }
 
+#[test]
+fn linux_package_install_command_can_elevate_through_sudo() {
+    let rpm = LinuxInstallCommand::for_kind(InstallerKind::Rpm, PathBuf::from("/tmp/app.rpm"))
+        .unwrap()
+        .with_sudo();
+    let snap = LinuxInstallCommand::for_kind(InstallerKind::Snap, PathBuf::from("/tmp/app.snap"))
+        .unwrap()
+        .with_sudo();
+
+    assert_eq!(rpm.program, "sudo");
+    assert_eq!(rpm.args, vec!["-A", "rpm", "-U", "/tmp/app.rpm"]);
+    assert_eq!(snap.program, "sudo");
+    assert_eq!(
+        snap.args,
+        vec!["-A", "snap", "install", "--dangerous", "/tmp/app.snap"]
+    );
+}
+
 #[test]
 fn linux_appimage_install_writes_real_target_path() {
     let temp_dir = tempfile::tempdir().unwrap();
     let target_path = temp_dir.path().join("ReleaseHub.AppImage");
-    let update = Update {
-        current_version: Version::parse("1.0.0").unwrap(),
-        version: Version::parse("1.0.1").unwrap(),
-        date: None,
-        body: None,
-        raw_json: serde_json::json!({}),
-        download_url: Url::parse("https://example.com/ReleaseHub.AppImage").unwrap(),
-        signature: String::new(),
-        pubkey: String::new(),
-        target: "linux-x86_64".into(),
-        installer_kind: InstallerKind::AppImage,
-        headers: HeaderMap::new(),
-        timeout: None,
-        proxy: None,
-        no_proxy: false,
-        dangerous_accept_invalid_certs: false,
-        dangerous_accept_invalid_hostnames: false,
-        extract_path: target_path.clone(),
-        app_name: "ReleaseHub".into(),
-        installer_args: Vec::new(),
-    };
+    let mut update = Update::new(
+        Version::parse("1.0.0").unwrap(),
+        Version::parse("1.0.1").unwrap(),
+        Url::parse("https://example.com/ReleaseHub.AppImage").unwrap(),
+        InstallerKind::AppImage,
+        target_path.clone(),
+    );
+    update.target = "linux-x86_64".into();
+    update.app_name = "ReleaseHub".into();
 
     update.install(b"payload").unwrap();
 
     assert_eq!(std::fs::read(&target_path).unwrap(), b"payload");
     assert!(!PathBuf::from(format!("{}.new", target_path.display())).exists());
 }
+
+#[test]
+fn linux_snap_backend_installs_local_package() {
+    assert_eq!(
+        InstallerKind::from_path(std::path::Path::new("/tmp/release-hub_1.0.1_amd64.snap"))
+            .unwrap(),
+        InstallerKind::Snap
+    );
+
+    let command =
+        LinuxInstallCommand::for_kind(InstallerKind::Snap, PathBuf::from("/tmp/release-hub.snap"))
+            .unwrap();
+
+    assert_eq!(command.program, "pkexec");
+    assert_eq!(
+        command.args,
+        vec!["snap", "install", "--dangerous", "/tmp/release-hub.snap"]
+    );
+}
+
+#[test]
+fn linux_simulated_install_reports_replaced_executable_without_writing() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let executable = temp_dir.path().join("ReleaseHub.AppImage");
+    std::fs::write(&executable, b"old").unwrap();
+    let config = release_hub::Config {
+        endpoints: vec![Url::parse("https://example.com/latest.json").unwrap()],
+        ..Default::default()
+    };
+    let updater = release_hub::UpdaterBuilder::new("ReleaseHub", "1.0.0", config)
+        .target("linux-x86_64")
+        .executable_path(&executable)
+        .build()
+        .unwrap();
+
+    let simulation = updater.simulate_install(b"new bytes").unwrap();
+
+    assert_eq!(simulation.files_to_replace, vec![executable.clone()]);
+    assert!(simulation.files_to_create.is_empty());
+    assert_eq!(simulation.bytes_to_write, 9);
+    assert!(!simulation.requires_elevation);
+    assert_eq!(std::fs::read(&executable).unwrap(), b"old");
+}
+
+#[test]
+fn linux_install_with_progress_reports_start_and_finish() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let executable = temp_dir.path().join("ReleaseHub.AppImage");
+    let config = release_hub::Config {
+        endpoints: vec![Url::parse("https://example.com/latest.json").unwrap()],
+        ..Default::default()
+    };
+    let updater = release_hub::UpdaterBuilder::new("ReleaseHub", "1.0.0", config)
+        .target("linux-x86_64")
+        .executable_path(&executable)
+        .build()
+        .unwrap();
+
+    let mut events = Vec::new();
+    updater
+        .install_with_progress(b"payload", |event| events.push(event))
+        .unwrap();
+
+    assert_eq!(
+        events,
+        vec![InstallProgress::Starting, InstallProgress::Done]
+    );
+    assert_eq!(std::fs::read(&executable).unwrap(), b"payload");
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn linux_updater_install_replaces_appimage_at_executable_path() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let executable = temp_dir.path().join("ReleaseHub.AppImage");
+    std::fs::write(&executable, b"old").unwrap();
+    let config = release_hub::Config {
+        endpoints: vec![Url::parse("https://example.com/latest.json").unwrap()],
+        ..Default::default()
+    };
+    let updater = release_hub::UpdaterBuilder::new("ReleaseHub", "1.0.0", config)
+        .target("linux-x86_64")
+        .executable_path(&executable)
+        .build()
+        .unwrap();
+
+    updater.install(b"new").unwrap();
+
+    assert_eq!(std::fs::read(&executable).unwrap(), b"new");
+    let mode = std::fs::metadata(&executable).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o755);
+}
+
+#[test]
+fn linux_tar_gz_install_finds_binary_inside_app_directory() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let source_dir = temp_dir.path().join("source");
+    std::fs::create_dir_all(source_dir.join("releasehub")).unwrap();
+    std::fs::write(source_dir.join("releasehub").join("ReleaseHub"), b"binary").unwrap();
+    std::fs::write(source_dir.join("releasehub").join("README"), b"docs").unwrap();
+    let archive_path = temp_dir.path().join("ReleaseHub-x86_64-linux.tar.gz");
+    let status = std::process::Command::new("tar")
+        .arg("-czf")
+        .arg(&archive_path)
+        .arg("-C")
+        .arg(&source_dir)
+        .arg("releasehub")
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let target_path = temp_dir.path().join("bin").join("releasehub");
+    let mut update = Update::new(
+        Version::parse("1.0.0").unwrap(),
+        Version::parse("1.0.1").unwrap(),
+        Url::parse("https://example.com/ReleaseHub-x86_64-linux.tar.gz").unwrap(),
+        InstallerKind::TarGz,
+        target_path.clone(),
+    );
+    update.target = "linux-x86_64".into();
+    update.app_name = "ReleaseHub".into();
+
+    update
+        .install(&std::fs::read(&archive_path).unwrap())
+        .unwrap();
+
+    assert_eq!(std::fs::read(&target_path).unwrap(), b"binary");
+}
+
+#[test]
+fn linux_updater_install_from_file_routes_archives_by_kind() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let source_dir = temp_dir.path().join("source");
+    std::fs::create_dir_all(source_dir.join("releasehub")).unwrap();
+    std::fs::write(source_dir.join("releasehub").join("ReleaseHub"), b"binary").unwrap();
+    let archive_path = temp_dir.path().join("ReleaseHub-x86_64-linux.tar.gz");
+    let status = std::process::Command::new("tar")
+        .arg("-czf")
+        .arg(&archive_path)
+        .arg("-C")
+        .arg(&source_dir)
+        .arg("releasehub")
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let executable = temp_dir.path().join("releasehub");
+    std::fs::write(&executable, b"old").unwrap();
+    let config = release_hub::Config {
+        endpoints: vec![Url::parse("https://example.com/latest.json").unwrap()],
+        ..Default::default()
+    };
+    let updater = release_hub::UpdaterBuilder::new("ReleaseHub", "1.0.0", config)
+        .target("linux-x86_64")
+        .executable_path(&executable)
+        .build()
+        .unwrap();
+
+    updater.install_from_file(&archive_path).unwrap();
+
+    assert_eq!(std::fs::read(&executable).unwrap(), b"binary");
+}
+
+#[test]
+fn linux_updater_rolls_back_to_replaced_appimage() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let executable = temp_dir.path().join("ReleaseHub.AppImage");
+    std::fs::write(&executable, b"old").unwrap();
+    let config = release_hub::Config {
+        endpoints: vec![Url::parse("https://example.com/latest.json").unwrap()],
+        ..Default::default()
+    };
+    let updater = release_hub::UpdaterBuilder::new("ReleaseHub", "1.0.0", config)
+        .target("linux-x86_64")
+        .executable_path(&executable)
+        .build()
+        .unwrap();
+    assert!(!updater.has_rollback());
+    assert!(matches!(
+        updater.rollback().unwrap_err(),
+        release_hub::Error::NoRollbackAvailable
+    ));
+
+    updater.install(b"new").unwrap();
+    assert_eq!(std::fs::read(&executable).unwrap(), b"new");
+    assert!(updater.has_rollback());
+
+    updater.rollback().unwrap();
+
+    assert_eq!(std::fs::read(&executable).unwrap(), b"old");
+    assert!(!updater.has_rollback());
+    assert_eq!(
+        std::fs::read_dir(temp_dir.path().join(".release-hub-backup"))
+            .unwrap()
+            .count(),
+        0
+    );
+}
+
+#[test]
+fn linux_updater_prunes_expired_rollback_backup() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let executable = temp_dir.path().join("ReleaseHub.AppImage");
+    std::fs::write(&executable, b"old").unwrap();
+    let config = release_hub::Config {
+        endpoints: vec![Url::parse("https://example.com/latest.json").unwrap()],
+        ..Default::default()
+    };
+    let build = |retention| {
+        release_hub::UpdaterBuilder::new("ReleaseHub", "1.0.0", config.clone())
+            .target("linux-x86_64")
+            .executable_path(&executable)
+            .backup_retention(retention)
+            .build()
+            .unwrap()
+    };
+
+    let updater = build(std::time::Duration::from_secs(24 * 60 * 60));
+    updater.install(b"new").unwrap();
+    assert!(build(std::time::Duration::from_secs(24 * 60 * 60)).has_rollback());
+
+    std::thread::sleep(std::time::Duration::from_millis(20));
+    assert!(!build(std::time::Duration::ZERO).has_rollback());
+}
+
+#[test]
+fn linux_updater_install_runs_hooks_and_aborts_on_before_install_error() {
+    use std::sync::{Arc, Mutex};
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let executable = temp_dir.path().join("ReleaseHub.AppImage");
+    std::fs::write(&executable, b"old").unwrap();
+    let config = release_hub::Config {
+        endpoints: vec![Url::parse("https://example.com/latest.json").unwrap()],
+        ..Default::default()
+    };
+    let installed = Arc::new(Mutex::new(Vec::new()));
+    let recorded = installed.clone();
+    let updater = release_hub::UpdaterBuilder::new("ReleaseHub", "1.0.0", config)
+        .target("linux-x86_64")
+        .executable_path(&executable)
+        .before_install(|bytes| {
+            if bytes == b"rejected" {
+                return Err(release_hub::Error::Io(std::io::Error::other("rejected")));
+            }
+            Ok(())
+        })
+        .after_install(move |path| recorded.lock().unwrap().push(path.to_path_buf()))
+        .build()
+        .unwrap();
+
+    assert!(updater.install(b"rejected").is_err());
+    assert_eq!(std::fs::read(&executable).unwrap(), b"old");
+    assert!(installed.lock().unwrap().is_empty());
+
+    updater.install(b"new").unwrap();
+    assert_eq!(std::fs::read(&executable).unwrap(), b"new");
+    assert_eq!(*installed.lock().unwrap(), vec![executable.clone()]);
+}
+
+#[test]
+fn linux_install_from_file_passes_the_artifact_path_to_the_hook() {
+    use std::sync::{Arc, Mutex};
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let executable = temp_dir.path().join("ReleaseHub.AppImage");
+    std::fs::write(&executable, b"old").unwrap();
+    let artifact = temp_dir.path().join("download").join("ReleaseHub.AppImage");
+    std::fs::create_dir_all(artifact.parent().unwrap()).unwrap();
+    std::fs::write(&artifact, b"new").unwrap();
+    let config = release_hub::Config {
+        endpoints: vec![Url::parse("https://example.com/latest.json").unwrap()],
+        ..Default::default()
+    };
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let recorded = seen.clone();
+    let updater = release_hub::UpdaterBuilder::new("ReleaseHub", "1.0.0", config)
+        .target("linux-x86_64")
+        .executable_path(&executable)
+        .before_install(|_| panic!("streamed artifacts are not read into memory"))
+        .before_install_file(move |path| {
+            recorded.lock().unwrap().push(path.to_path_buf());
+            Ok(())
+        })
+        .build()
+        .unwrap();
+
+    updater.install_from_file(&artifact).unwrap();
+    assert_eq!(*seen.lock().unwrap(), vec![artifact.clone()]);
+    assert_eq!(std::fs::read(&executable).unwrap(), b"new");
+}
+
+#[test]
+fn linux_install_stages_archive_in_configured_temp_dir() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let staging_root = temp_dir.path().join("staging");
+    let executable = temp_dir.path().join("ReleaseHub.AppImage");
+    let config = release_hub::Config {
+        endpoints: vec![Url::parse("https://example.com/latest.json").unwrap()],
+        ..Default::default()
+    };
+    let updater = release_hub::UpdaterBuilder::new("ReleaseHub", "1.0.0", config)
+        .target("linux-x86_64")
+        .executable_path(&executable)
+        .temp_dir(staging_root.clone())
+        .build()
+        .unwrap();
+    assert_eq!(updater.temp_dir.as_deref(), Some(staging_root.as_path()));
+    assert!(staging_root.is_dir());
+
+    let source_dir = temp_dir.path().join("source");
+    std::fs::create_dir_all(&source_dir).unwrap();
+    std::fs::write(source_dir.join("ReleaseHub"), b"binary").unwrap();
+    let archive_path = temp_dir.path().join("ReleaseHub-x86_64-linux.tar.gz");
+    let status = std::process::Command::new("tar")
+        .arg("-czf")
+        .arg(&archive_path)
+        .arg("-C")
+        .arg(&source_dir)
+        .arg("ReleaseHub")
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let target_path = temp_dir.path().join("bin").join("releasehub");
+    let mut update = Update::new(
+        Version::parse("1.0.0").unwrap(),
+        Version::parse("1.0.1").unwrap(),
+        Url::parse("https://example.com/ReleaseHub-x86_64-linux.tar.gz").unwrap(),
+        InstallerKind::TarGz,
+        target_path.clone(),
+    );
+    update.target = "linux-x86_64".into();
+    update.app_name = "ReleaseHub".into();
+    update.temp_dir = Some(staging_root.join("nested"));
+
+    update
+        .install(&std::fs::read(&archive_path).unwrap())
+        .unwrap();
+
+    assert_eq!(std::fs::read(&target_path).unwrap(), b"binary");
+    // The staging directory is created inside the configured path and removed afterwards.
+    assert_eq!(
+        std::fs::read_dir(staging_root.join("nested"))
+            .unwrap()
+            .count(),
+        0
+    );
+}
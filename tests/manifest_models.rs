@@ -1,4 +1,4 @@
-use release_hub::{Config, RemoteRelease, RemoteReleaseInner};
+use release_hub::{Config, ReleaseTier, RemoteRelease, RemoteReleaseInner};
 use semver::Version;
 use url::Url;
 
@@ -94,3 +94,81 @@ fn missing_static_target_returns_target_not_found() {
     let err = release.signature("linux-x86_64").unwrap_err();
     assert!(matches!(err, release_hub::Error::TargetNotFound(target) if target == "linux-x86_64"));
 }
+
+#[test]
+fn release_notes_heuristics_flag_security_and_breaking_changes() {
+    let json = r#"{
+        "version": "1.2.4",
+        "notes": "Fixes CVE-2026-1234. BREAKING: removed the legacy API.",
+        "url": "https://example.com/app.AppImage",
+        "signature": "sig"
+    }"#;
+
+    let release: RemoteRelease = serde_json::from_str(json).unwrap();
+    assert!(release.mentions_security_fix());
+    assert!(release.mentions_breaking_change());
+
+    let json = r#"{
+        "version": "1.2.5",
+        "notes": "Improved startup time.",
+        "url": "https://example.com/app.AppImage",
+        "signature": "sig"
+    }"#;
+
+    let release: RemoteRelease = serde_json::from_str(json).unwrap();
+    assert!(!release.mentions_security_fix());
+    assert!(!release.mentions_breaking_change());
+}
+
+#[test]
+fn release_stability_combines_flags_and_prerelease_segment() {
+    let release = |version: &str, extra: &str| -> RemoteRelease {
+        serde_json::from_str(&format!(
+            r#"{{"version": "{version}", "url": "https://example.com/app.AppImage", "signature": "sig"{extra}}}"#
+        ))
+        .unwrap()
+    };
+
+    assert!(release("1.0.0", "").is_stable());
+    assert!(release("1.0.0", r#", "prerelease": true"#).is_prerelease());
+    assert!(release("1.0.0", r#", "draft": true"#).is_prerelease());
+    assert!(release("1.0.0-beta.1", "").is_prerelease());
+
+    let tier = |version: &str| release(version, "").stability_tier();
+    assert_eq!(tier("1.0.0"), ReleaseTier::Stable);
+    assert_eq!(tier("1.0.0-rc.1"), ReleaseTier::ReleaseCandidate);
+    assert_eq!(tier("1.0.0-BETA.2"), ReleaseTier::Beta);
+    assert_eq!(tier("1.0.0-alpha"), ReleaseTier::Alpha);
+    assert_eq!(tier("1.0.0-nightly.20261015"), ReleaseTier::Nightly);
+    assert_eq!(tier("1.0.0-x.7"), ReleaseTier::Unknown);
+    assert_eq!(tier("1.0.0-rc2"), ReleaseTier::ReleaseCandidate);
+    assert_eq!(tier("1.0.0-src.1"), ReleaseTier::Unknown);
+    assert_eq!(tier("1.0.0-alpha.rc"), ReleaseTier::Alpha);
+    assert_eq!(
+        release("1.0.0", r#", "prerelease": true"#).stability_tier(),
+        ReleaseTier::Unknown
+    );
+}
+
+#[test]
+fn remote_release_round_trips_through_json() {
+    let json = r#"{
+        "version": "1.2.3",
+        "tag_name": "v1.2.3",
+        "notes": "Bug fixes",
+        "pub_date": "2026-04-21T08:00:00Z",
+        "url": "https://example.com/app.AppImage",
+        "signature": "sig-linux",
+        "assets": [{"name": "app.AppImage", "url": "https://example.com/app.AppImage", "size": 1024}]
+    }"#;
+    let release: RemoteRelease = serde_json::from_str(json).unwrap();
+
+    let restored: RemoteRelease =
+        serde_json::from_str(&serde_json::to_string(&release).unwrap()).unwrap();
+
+    assert_eq!(restored.version, release.version);
+    assert_eq!(restored.tag_name, release.tag_name);
+    assert_eq!(restored.pub_date, release.pub_date);
+    assert_eq!(restored.assets, release.assets);
+    assert_eq!(restored.signature("linux-x86_64").unwrap(), "sig-linux");
+}
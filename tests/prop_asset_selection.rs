@@ -0,0 +1,84 @@
+#![cfg(feature = "proptest")]
+
+use proptest::prelude::*;
+use release_hub::github::testing::{FakeGitHubClient, fake_release};
+use release_hub::{Error, InstallerKind, ReleaseSource, SourceRequest};
+use std::path::Path;
+
+const TARGETS: &[&str] = &[
+    "linux-x86_64",
+    "linux-aarch64",
+    "darwin-x86_64",
+    "darwin-aarch64",
+    "windows-x86_64",
+    "windows-aarch64",
+];
+const LINUX_EXTENSIONS: &[&str] = &["AppImage", "deb", "rpm"];
+const MACOS_EXTENSIONS: &[&str] = &["app.tar.gz", "app.zip"];
+const WINDOWS_EXTENSIONS: &[&str] = &["msi", "exe"];
+
+/// Returns the installer extensions the platform of `target` can install.
+fn extensions(target: &str) -> &'static [&'static str] {
+    match target.split_once('-').map(|(os, _)| os) {
+        Some("linux") => LINUX_EXTENSIONS,
+        Some("darwin") => MACOS_EXTENSIONS,
+        _ => WINDOWS_EXTENSIONS,
+    }
+}
+
+/// Generates `(target, name)` pairs such as `("linux-x86_64", "app-linux-x86_64.deb")`.
+fn conventional_asset() -> impl Strategy<Value = (&'static str, String)> {
+    prop::sample::select(TARGETS).prop_flat_map(|target| {
+        (
+            Just(target),
+            "[a-z0-9_-]{1,32}",
+            prop::sample::select(extensions(target)),
+        )
+            .prop_map(|(target, app, extension)| (target, format!("{app}-{target}.{extension}")))
+    })
+}
+
+fn fetch(assets: Vec<(&str, u64)>, target: &str) -> release_hub::Result<Option<String>> {
+    let source = FakeGitHubClient::new(vec![fake_release("v1.0.0", assets)]);
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    let release = runtime.block_on(source.fetch(&SourceRequest::new(target)))?;
+    Ok(release
+        .download_url(target)?
+        .path_segments()
+        .and_then(|mut segments| segments.next_back())
+        .map(str::to_owned))
+}
+
+proptest! {
+    #[test]
+    fn arbitrary_asset_names_select_a_valid_asset_or_report_not_found(
+        target in prop::sample::select(TARGETS),
+        names in prop::collection::vec("[a-z0-9_\\-\\.]{1,32}", 1..7),
+    ) {
+        let assets = names.iter().map(|name| (name.as_str(), 1)).collect();
+
+        match fetch(assets, target) {
+            Ok(Some(name)) => {
+                prop_assert!(names.contains(&name), "{} was not offered", name);
+                prop_assert!(InstallerKind::for_target(Path::new(&name), target).is_ok());
+            }
+            Ok(None) => prop_assert!(false, "selected asset without a file name for {:?}", names),
+            Err(Error::TargetNotFound(found)) => prop_assert_eq!(found, target),
+            Err(Error::MissingSignatureAsset(_)) => {}
+            Err(error) => prop_assert!(false, "unexpected error {:?} for {:?}", error, names),
+        }
+    }
+
+    #[test]
+    fn conventional_asset_names_are_selected_for_their_target(
+        (target, name) in conventional_asset(),
+    ) {
+        let signature = format!("{name}.sig");
+
+        let selected = fetch(vec![(&name, 1024), (&signature, 64)], target);
+
+        prop_assert_eq!(selected.ok().flatten(), Some(name));
+    }
+}
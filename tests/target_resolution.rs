@@ -6,6 +6,7 @@ fn target_string_covers_linux_aarch64() {
     let info = SystemInfo {
         os: OS::Linux,
         arch: Arch::Arm64,
+        distro: None,
     };
 
     assert_eq!(TargetInfo::from_system(info).target, "linux-aarch64");
@@ -16,3 +17,168 @@ fn installer_kind_detects_appimage() {
     let kind = InstallerKind::from_path(Path::new("/tmp/release-hub.AppImage")).unwrap();
     assert_eq!(kind, InstallerKind::AppImage);
 }
+
+#[test]
+fn installer_kind_distinguishes_app_zip_from_portable_zip() {
+    assert_eq!(
+        InstallerKind::from_path(Path::new("/tmp/ReleaseHub.app.zip")).unwrap(),
+        InstallerKind::AppZip
+    );
+    assert_eq!(
+        InstallerKind::from_path(Path::new("/tmp/ReleaseHub-windows-x86_64.zip")).unwrap(),
+        InstallerKind::PortableZip
+    );
+}
+
+#[test]
+fn installer_kind_for_target_rejects_zip_archives_of_other_platforms() {
+    let zip = Path::new("/tmp/ReleaseHub.zip");
+
+    assert_eq!(
+        InstallerKind::for_target(zip, "windows-x86_64").unwrap(),
+        InstallerKind::PortableZip
+    );
+    assert!(InstallerKind::for_target(zip, "linux-x86_64").is_err());
+    assert!(InstallerKind::for_target(zip, "darwin-aarch64").is_err());
+    assert_eq!(
+        InstallerKind::for_target(Path::new("/tmp/ReleaseHub.app.zip"), "darwin-aarch64").unwrap(),
+        InstallerKind::AppZip
+    );
+}
+
+#[test]
+fn system_info_converts_to_rust_target_triple() {
+    let triple = |os, arch| {
+        String::from(&SystemInfo {
+            os,
+            arch,
+            distro: None,
+        })
+    };
+
+    assert_eq!(triple(OS::Macos, Arch::X86_64), "x86_64-apple-darwin");
+    assert_eq!(triple(OS::Macos, Arch::Arm64), "aarch64-apple-darwin");
+    assert_eq!(triple(OS::Windows, Arch::X86_64), "x86_64-pc-windows-msvc");
+    assert_eq!(triple(OS::Windows, Arch::Arm64), "aarch64-pc-windows-msvc");
+    assert_eq!(triple(OS::Linux, Arch::X86_64), "x86_64-unknown-linux-gnu");
+    assert_eq!(triple(OS::Windows, Arch::X86), "i686-pc-windows-msvc");
+    assert_eq!(
+        triple(OS::Linux, Arch::Arm),
+        "armv7-unknown-linux-gnueabihf"
+    );
+}
+
+#[test]
+fn target_string_covers_32_bit_architectures() {
+    let target = |os, arch| {
+        TargetInfo::from_system(SystemInfo {
+            os,
+            arch,
+            distro: None,
+        })
+        .target
+    };
+    let linux_arm = SystemInfo {
+        os: OS::Linux,
+        arch: Arch::Arm,
+        distro: None,
+    };
+
+    assert_eq!(target(OS::Windows, Arch::X86), "windows-i686");
+    assert_eq!(target(OS::Linux, Arch::Arm), "linux-armv7");
+    assert_eq!(linux_arm.display_name(), "Linux ARM");
+    assert_eq!(linux_arm.short_name(), "linux-arm");
+}
+
+#[test]
+fn linux_distro_is_detected_from_os_release() {
+    let distro = release_hub::detect_linux_distro();
+
+    if cfg!(target_os = "linux") && Path::new("/etc/os-release").exists() {
+        let distro = distro.expect("os-release should provide an ID");
+        assert!(!distro.is_empty());
+        assert_eq!(distro, distro.to_lowercase());
+    } else if !cfg!(target_os = "linux") {
+        assert_eq!(distro, None);
+    }
+}
+
+#[test]
+fn is_elevated_matches_effective_user() {
+    if !cfg!(target_os = "linux") {
+        return;
+    }
+    let output = std::process::Command::new("id").arg("-u").output().unwrap();
+    let is_root = String::from_utf8_lossy(&output.stdout).trim() == "0";
+
+    assert_eq!(release_hub::is_elevated(), is_root);
+}
+
+#[test]
+fn system_info_reports_display_and_short_names() {
+    let apple_silicon = SystemInfo {
+        os: OS::Macos,
+        arch: Arch::Arm64,
+        distro: None,
+    };
+    let windows = SystemInfo {
+        os: OS::Windows,
+        arch: Arch::X86_64,
+        distro: None,
+    };
+
+    assert_eq!(apple_silicon.display_name(), "macOS (Apple Silicon)");
+    assert_eq!(apple_silicon.short_name(), "macos-arm64");
+    assert_eq!(windows.display_name(), "Windows x86_64");
+    assert_eq!(windows.short_name(), "windows-x86_64");
+}
+
+#[test]
+fn installer_kind_detects_linux_binary_archives() {
+    assert_eq!(
+        InstallerKind::from_path(Path::new("/tmp/myapp-x86_64-linux.tar.gz")).unwrap(),
+        InstallerKind::TarGz
+    );
+    assert_eq!(
+        InstallerKind::from_path(Path::new("/tmp/myapp-x86_64-linux.tar.xz")).unwrap(),
+        InstallerKind::TarXz
+    );
+    assert_eq!(
+        InstallerKind::from_path(Path::new("/tmp/MyApp.app.tar.gz")).unwrap(),
+        InstallerKind::AppTarGz
+    );
+}
+
+#[test]
+fn installer_kind_for_target_keeps_binary_archives_on_linux() {
+    for archive in ["/tmp/myapp.tar.gz", "/tmp/myapp.tar.xz"] {
+        let archive = Path::new(archive);
+        assert_eq!(
+            InstallerKind::for_target(archive, "linux-aarch64")
+                .unwrap()
+                .os(),
+            OS::Linux
+        );
+        assert!(InstallerKind::for_target(archive, "darwin-aarch64").is_err());
+        assert!(InstallerKind::for_target(archive, "windows-x86_64").is_err());
+    }
+    assert_eq!(
+        InstallerKind::for_target(Path::new("/tmp/MyApp.app.tar.gz"), "darwin-x86_64").unwrap(),
+        InstallerKind::AppTarGz
+    );
+}
+
+#[test]
+fn target_and_installer_kind_round_trip_through_json() {
+    let target = TargetInfo::from_system(SystemInfo {
+        os: OS::Linux,
+        arch: Arch::X86_64,
+        distro: Some("ubuntu".into()),
+    });
+    let json = serde_json::to_string(&(&target, InstallerKind::AppImage)).unwrap();
+
+    let (restored, kind): (TargetInfo, InstallerKind) = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(restored, target);
+    assert_eq!(kind, InstallerKind::AppImage);
+}